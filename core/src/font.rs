@@ -109,3 +109,75 @@ pub enum Style {
     Italic,
     Oblique,
 }
+
+/// A writing system with its own chain of fallback font families.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Chinese, Japanese and Korean characters; including kana and hangul.
+    Cjk,
+    /// Emoji and pictographic symbols.
+    Emoji,
+    /// The Arabic script.
+    Arabic,
+    /// The Hebrew script.
+    Hebrew,
+    /// The Devanagari script.
+    Devanagari,
+    /// The Thai script.
+    Thai,
+}
+
+impl Script {
+    /// Returns the [`Script`] of the given character, if it belongs to one.
+    pub fn of(c: char) -> Option<Self> {
+        match u32::from(c) {
+            0x1100..=0x11FF
+            | 0x2E80..=0x2FDF
+            | 0x3000..=0x30FF
+            | 0x3130..=0x318F
+            | 0x31F0..=0x9FFF
+            | 0xAC00..=0xD7AF
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFFEF
+            | 0x20000..=0x3134F => Some(Self::Cjk),
+            0x2600..=0x27BF | 0x1F000..=0x1FAFF | 0xFE0F | 0x200D => {
+                Some(Self::Emoji)
+            }
+            0x0600..=0x06FF
+            | 0x0750..=0x077F
+            | 0x08A0..=0x08FF
+            | 0xFB50..=0xFDFF
+            | 0xFE70..=0xFEFF => Some(Self::Arabic),
+            0x0590..=0x05FF | 0xFB1D..=0xFB4F => Some(Self::Hebrew),
+            0x0900..=0x097F | 0xA8E0..=0xA8FF => Some(Self::Devanagari),
+            0x0E00..=0x0E7F => Some(Self::Thai),
+            _ => None,
+        }
+    }
+}
+
+/// An error that occurred while loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The font data is invalid or unsupported.
+    #[error("the font data is invalid")]
+    Invalid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_of_characters() {
+        assert_eq!(Script::of('a'), None);
+        assert_eq!(Script::of('漢'), Some(Script::Cjk));
+        assert_eq!(Script::of('か'), Some(Script::Cjk));
+        assert_eq!(Script::of('한'), Some(Script::Cjk));
+        assert_eq!(Script::of('😀'), Some(Script::Emoji));
+        assert_eq!(Script::of('ع'), Some(Script::Arabic));
+        assert_eq!(Script::of('ש'), Some(Script::Hebrew));
+        assert_eq!(Script::of('क'), Some(Script::Devanagari));
+        assert_eq!(Script::of('ก'), Some(Script::Thai));
+    }
+}
@@ -29,6 +29,12 @@ pub struct Image<H = Handle> {
     /// This can avoid graphical glitches, specially when using
     /// [`FilterMethod::Nearest`].
     pub snap: bool,
+
+    /// The region of the image to draw, in pixels.
+    ///
+    /// If `None`, the whole image is drawn. This is useful to draw a single
+    /// sprite of a sprite sheet.
+    pub source: Option<Rectangle<u32>>,
 }
 
 impl Image<Handle> {
@@ -40,6 +46,7 @@ impl Image<Handle> {
             rotation: Radians(0.0),
             opacity: 1.0,
             snap: false,
+            source: None,
         }
     }
 
@@ -66,6 +73,12 @@ impl Image<Handle> {
         self.snap = snap;
         self
     }
+
+    /// Sets the region of the [`Image`] to draw, in pixels.
+    pub fn source(mut self, source: Rectangle<u32>) -> Self {
+        self.source = Some(source);
+        self
+    }
 }
 
 impl From<&Handle> for Image {
@@ -227,6 +240,71 @@ pub enum FilterMethod {
     Nearest,
 }
 
+/// The loading state of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// The image is being decoded in the background.
+    Loading,
+    /// The image is ready to be drawn.
+    Loaded,
+    /// The image could not be loaded.
+    Failed(Error),
+}
+
+/// An error that occurred while loading an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The image file could not be found.
+    #[error("the image could not be found")]
+    NotFound,
+    /// The image data is invalid or unsupported.
+    #[error("the image data is invalid")]
+    Invalid,
+}
+
+/// The memory usage of an image cache.
+///
+/// Only raster images are accounted for; the rasterizations of vector
+/// graphics are not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Metrics {
+    /// The amount of bytes used by the cached images.
+    pub bytes: u64,
+    /// The amount of images in the cache.
+    pub entries: usize,
+    /// The amount of lookups that found the image in the cache.
+    pub hits: u64,
+    /// The amount of lookups that had to load the image.
+    pub misses: u64,
+}
+
+impl Metrics {
+    /// Returns the ratio of lookups that found the image in the cache,
+    /// from `0.0` to `1.0`.
+    pub fn hit_rate(&self) -> f32 {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 {
+            return 0.0;
+        }
+
+        self.hits as f32 / lookups as f32
+    }
+}
+
+impl std::ops::Add for Metrics {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            entries: self.entries + other.entries,
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -239,6 +317,12 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn measure_image(&self, handle: &Self::Handle) -> Size<u32>;
 
+    /// Returns the loading [`State`] of the image for the given [`Handle`].
+    ///
+    /// Images may be decoded in the background; in which case they will
+    /// not be drawn until loaded.
+    fn image_state(&self, handle: &Self::Handle) -> State;
+
     /// Draws an [`Image`] inside the provided `bounds`.
     fn draw_image(&mut self, image: Image<Self::Handle>, bounds: Rectangle);
 }
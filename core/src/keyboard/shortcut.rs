@@ -0,0 +1,624 @@
+//! Declare keyboard shortcuts and bind them to messages.
+use crate::keyboard::key::{Key, Named};
+use crate::keyboard::{Event, Modifiers};
+use crate::SmolStr;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A [`Key`] pressed together with some [`Modifiers`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// The [`Modifiers`] of the [`Chord`].
+    pub modifiers: Modifiers,
+
+    /// The [`Key`] of the [`Chord`].
+    pub key: Key,
+}
+
+impl Chord {
+    /// Creates a new [`Chord`] with the given [`Modifiers`] and [`Key`].
+    ///
+    /// Character keys are case-insensitive; use [`Modifiers::SHIFT`]
+    /// to distinguish uppercase letters.
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        let key = match key {
+            Key::Character(c) => Key::Character(SmolStr::new(c.to_lowercase())),
+            key => key,
+        };
+
+        Self { modifiers, key }
+    }
+
+    /// Creates the [`Chord`] of a keyboard [`Event`], if it is a key press
+    /// of a key other than a modifier.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        let Event::KeyPressed { key, modifiers, .. } = event else {
+            return None;
+        };
+
+        match key {
+            Key::Named(
+                Named::Alt
+                | Named::AltGraph
+                | Named::Control
+                | Named::Shift
+                | Named::Super
+                | Named::Meta
+                | Named::Hyper
+                | Named::Fn,
+            )
+            | Key::Unidentified => None,
+            key => Some(Self::new(*modifiers, key.clone())),
+        }
+    }
+
+    /// Returns a human-readable label of the [`Chord`], following the
+    /// conventions of the current platform.
+    pub fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_label(
+        &self,
+        f: &mut impl fmt::Write,
+        platform: Platform,
+    ) -> fmt::Result {
+        let modifiers = [
+            (Modifiers::CTRL, "⌃", "Ctrl"),
+            (Modifiers::ALT, "⌥", "Alt"),
+            (Modifiers::SHIFT, "⇧", "Shift"),
+            (Modifiers::LOGO, "⌘", "Super"),
+        ];
+
+        for (modifier, symbol, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                match platform {
+                    Platform::MacOS => f.write_str(symbol)?,
+                    Platform::Other => write!(f, "{name}+")?,
+                }
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) => f.write_str(&c.to_uppercase()),
+            Key::Named(named) => {
+                let name = match (platform, named) {
+                    (Platform::MacOS, Named::Enter) => "↩",
+                    (Platform::MacOS, Named::Escape) => "⎋",
+                    (Platform::MacOS, Named::Tab) => "⇥",
+                    (Platform::MacOS, Named::Backspace) => "⌫",
+                    (Platform::MacOS, Named::Delete) => "⌦",
+                    (Platform::MacOS, Named::ArrowUp) => "↑",
+                    (Platform::MacOS, Named::ArrowDown) => "↓",
+                    (Platform::MacOS, Named::ArrowLeft) => "←",
+                    (Platform::MacOS, Named::ArrowRight) => "→",
+                    (Platform::MacOS, Named::PageUp) => "⇞",
+                    (Platform::MacOS, Named::PageDown) => "⇟",
+                    (Platform::MacOS, Named::Home) => "↖",
+                    (Platform::MacOS, Named::End) => "↘",
+                    (Platform::Other, Named::Escape) => "Esc",
+                    (Platform::Other, Named::Delete) => "Del",
+                    (Platform::Other, Named::Insert) => "Ins",
+                    (Platform::Other, Named::ArrowUp) => "Up",
+                    (Platform::Other, Named::ArrowDown) => "Down",
+                    (Platform::Other, Named::ArrowLeft) => "Left",
+                    (Platform::Other, Named::ArrowRight) => "Right",
+                    (_, Named::Space) => "Space",
+                    _ => return write!(f, "{named:?}"),
+                };
+
+                f.write_str(name)
+            }
+            Key::Unidentified => f.write_str("?"),
+        }
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_label(f, Platform::CURRENT)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ParseError;
+
+    fn from_str(chord: &str) -> Result<Self, Self::Err> {
+        let chord = chord.trim();
+
+        if chord.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        // The plus key itself may be the last key of the chord
+        let (modifiers, key) = match chord.strip_suffix("++") {
+            Some(modifiers) => (Some(modifiers), "+"),
+            None if chord == "+" => (None, "+"),
+            None => match chord.rsplit_once('+') {
+                Some((modifiers, key)) => (Some(modifiers), key),
+                None => (None, chord),
+            },
+        };
+
+        let modifiers = modifiers
+            .into_iter()
+            .flat_map(|modifiers| modifiers.split('+'))
+            .try_fold(Modifiers::empty(), |modifiers, name| {
+                let modifier = match name.trim().to_lowercase().as_str() {
+                    "ctrl" | "control" => Modifiers::CTRL,
+                    "alt" | "option" => Modifiers::ALT,
+                    "shift" => Modifiers::SHIFT,
+                    "super" | "logo" | "meta" | "win" => Modifiers::LOGO,
+                    "cmd" | "command" => Modifiers::COMMAND,
+                    _ => {
+                        return Err(ParseError::UnknownModifier(
+                            name.to_owned(),
+                        ))
+                    }
+                };
+
+                Ok(modifiers | modifier)
+            })?;
+
+        Ok(Self::new(modifiers, parse_key(key.trim())?))
+    }
+}
+
+fn parse_key(key: &str) -> Result<Key, ParseError> {
+    if key.chars().count() == 1 {
+        return Ok(Key::Character(SmolStr::new(key)));
+    }
+
+    let named = match key.to_lowercase().as_str() {
+        "enter" | "return" => Named::Enter,
+        "esc" | "escape" => Named::Escape,
+        "tab" => Named::Tab,
+        "space" => Named::Space,
+        "backspace" => Named::Backspace,
+        "del" | "delete" => Named::Delete,
+        "ins" | "insert" => Named::Insert,
+        "home" => Named::Home,
+        "end" => Named::End,
+        "pageup" => Named::PageUp,
+        "pagedown" => Named::PageDown,
+        "up" => Named::ArrowUp,
+        "down" => Named::ArrowDown,
+        "left" => Named::ArrowLeft,
+        "right" => Named::ArrowRight,
+        "f1" => Named::F1,
+        "f2" => Named::F2,
+        "f3" => Named::F3,
+        "f4" => Named::F4,
+        "f5" => Named::F5,
+        "f6" => Named::F6,
+        "f7" => Named::F7,
+        "f8" => Named::F8,
+        "f9" => Named::F9,
+        "f10" => Named::F10,
+        "f11" => Named::F11,
+        "f12" => Named::F12,
+        _ => return Err(ParseError::UnknownKey(key.to_owned())),
+    };
+
+    Ok(Key::Named(named))
+}
+
+/// A keyboard shortcut; a sequence of one or more [`Chord`]s.
+///
+/// A [`Shortcut`] can be parsed from a string, with chords separated by
+/// whitespace and keys separated by `+`:
+///
+/// ```
+/// # use iced_core::keyboard::Shortcut;
+/// let save = Shortcut::parse("Cmd+S").unwrap();
+/// let save_all = Shortcut::parse("Ctrl+K Ctrl+S").unwrap();
+///
+/// assert_eq!(save.chords().len(), 1);
+/// assert_eq!(save_all.chords().len(), 2);
+/// ```
+///
+/// The `Cmd` modifier maps to [`Modifiers::COMMAND`]; that is, the
+/// command key (⌘) on macOS and the control key on other platforms.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    chords: Vec<Chord>,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] made of a single [`Chord`].
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self::from(Chord::new(modifiers, key))
+    }
+
+    /// Parses a [`Shortcut`] from a string.
+    pub fn parse(shortcut: &str) -> Result<Self, ParseError> {
+        shortcut.parse()
+    }
+
+    /// Adds a [`Chord`] to be pressed after the current ones.
+    pub fn then(mut self, chord: Chord) -> Self {
+        self.chords.push(chord);
+        self
+    }
+
+    /// Returns the [`Chord`]s of the [`Shortcut`].
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    /// Returns true if the given [`Chord`]s are the beginning of the
+    /// [`Shortcut`].
+    pub fn starts_with(&self, chords: &[Chord]) -> bool {
+        self.chords.starts_with(chords)
+    }
+
+    /// Returns a human-readable label of the [`Shortcut`], following the
+    /// conventions of the current platform.
+    ///
+    /// This is useful to display shortcuts in menus.
+    pub fn label(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_label(
+        &self,
+        f: &mut impl fmt::Write,
+        platform: Platform,
+    ) -> fmt::Result {
+        for (i, chord) in self.chords.iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+
+            chord.write_label(f, platform)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Chord> for Shortcut {
+    fn from(chord: Chord) -> Self {
+        Self {
+            chords: vec![chord],
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_label(f, Platform::CURRENT)
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let chords = shortcut
+            .split_whitespace()
+            .map(Chord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+/// An error produced when parsing a [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The shortcut is empty.
+    #[error("the shortcut is empty")]
+    Empty,
+
+    /// The shortcut contains an unknown modifier.
+    #[error("unknown modifier: {0}")]
+    UnknownModifier(String),
+
+    /// The shortcut contains an unknown key.
+    #[error("unknown key: {0}")]
+    UnknownKey(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Platform {
+    MacOS,
+    Other,
+}
+
+impl Platform {
+    const CURRENT: Self = if cfg!(target_os = "macos") {
+        Self::MacOS
+    } else {
+        Self::Other
+    };
+}
+
+/// How a [`Binding`] behaves when a widget has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    /// The [`Binding`] is only triggered if the focused widget ignores the
+    /// key press; text inputs will get to type the key first.
+    #[default]
+    Defer,
+
+    /// The [`Binding`] is triggered before the focused widget gets to see
+    /// the key press, even if it is a text input.
+    Override,
+}
+
+/// A [`Shortcut`] bound to a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding<Message> {
+    /// The [`Shortcut`] of the [`Binding`].
+    pub shortcut: Shortcut,
+
+    /// The message produced when the [`Shortcut`] is triggered.
+    pub message: Message,
+
+    /// The [`Priority`] of the [`Binding`].
+    pub priority: Priority,
+}
+
+impl<Message> Binding<Message> {
+    /// Creates a new [`Binding`] of the given [`Shortcut`] to a message.
+    pub fn new(shortcut: impl Into<Shortcut>, message: Message) -> Self {
+        Self {
+            shortcut: shortcut.into(),
+            message,
+            priority: Priority::default(),
+        }
+    }
+
+    /// Sets the [`Priority`] of the [`Binding`].
+    pub fn priority(self, priority: Priority) -> Self {
+        Self { priority, ..self }
+    }
+}
+
+/// A list of [`Binding`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Registry<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+impl<Message> Registry<Message> {
+    /// Creates an empty [`Registry`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a [`Shortcut`] to a message with the default [`Priority`].
+    pub fn bind(self, shortcut: impl Into<Shortcut>, message: Message) -> Self {
+        self.with(Binding::new(shortcut, message))
+    }
+
+    /// Adds a [`Binding`] to the [`Registry`].
+    pub fn with(mut self, binding: Binding<Message>) -> Self {
+        self.push(binding);
+        self
+    }
+
+    /// Adds a [`Binding`] to the [`Registry`].
+    pub fn push(&mut self, binding: Binding<Message>) {
+        self.bindings.push(binding);
+    }
+
+    /// Returns the [`Binding`]s of the [`Registry`].
+    pub fn bindings(&self) -> &[Binding<Message>] {
+        &self.bindings
+    }
+
+    /// Returns the first [`Shortcut`] bound to the given message, if any.
+    ///
+    /// Combined with [`Shortcut::label`], this is useful to display the
+    /// shortcuts of the actions of a menu.
+    pub fn shortcut(&self, message: &Message) -> Option<&Shortcut>
+    where
+        Message: PartialEq,
+    {
+        self.bindings
+            .iter()
+            .find(|binding| &binding.message == message)
+            .map(|binding| &binding.shortcut)
+    }
+
+    /// Finds the [`Match`] of the given sequence of [`Chord`]s among the
+    /// [`Binding`]s that satisfy the given filter.
+    pub fn find(
+        &self,
+        sequence: &[Chord],
+        filter: impl Fn(&Binding<Message>) -> bool,
+    ) -> Match<'_, Message> {
+        let mut is_partial = false;
+
+        for binding in self.bindings.iter().filter(|binding| filter(binding)) {
+            if binding.shortcut.chords() == sequence {
+                return Match::Exact(&binding.message);
+            }
+
+            is_partial = is_partial || binding.shortcut.starts_with(sequence);
+        }
+
+        if is_partial {
+            Match::Partial
+        } else {
+            Match::None
+        }
+    }
+
+    /// Detects the conflicting [`Binding`]s of the [`Registry`].
+    ///
+    /// The indices of a [`Conflict`] refer to the [`Registry::bindings`].
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        for (i, a) in self.bindings.iter().enumerate() {
+            for (j, b) in self.bindings.iter().enumerate().skip(i + 1) {
+                let (a, b) = (a.shortcut.chords(), b.shortcut.chords());
+
+                if a == b {
+                    conflicts.push(Conflict::Duplicate {
+                        first: i,
+                        second: j,
+                    });
+                } else if b.starts_with(a) {
+                    conflicts.push(Conflict::Prefix {
+                        prefix: i,
+                        sequence: j,
+                    });
+                } else if a.starts_with(b) {
+                    conflicts.push(Conflict::Prefix {
+                        prefix: j,
+                        sequence: i,
+                    });
+                }
+            }
+        }
+
+        conflicts
+    }
+}
+
+impl<Message> Default for Registry<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The result of looking for a sequence of [`Chord`]s in a [`Registry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Match<'a, Message> {
+    /// No [`Binding`] starts with the sequence.
+    None,
+
+    /// Some [`Binding`] starts with the sequence, but more chords are
+    /// needed to trigger it.
+    Partial,
+
+    /// A [`Binding`] matches the sequence exactly.
+    Exact(&'a Message),
+}
+
+/// A conflict between two [`Binding`]s of a [`Registry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conflict {
+    /// Both [`Binding`]s have the same [`Shortcut`]; only the first one
+    /// will ever be triggered.
+    Duplicate {
+        /// The index of the first [`Binding`].
+        first: usize,
+        /// The index of the second [`Binding`].
+        second: usize,
+    },
+
+    /// The [`Shortcut`] of a [`Binding`] is the beginning of the sequence
+    /// of another one; the longer sequence will never be triggered.
+    Prefix {
+        /// The index of the [`Binding`] with the shorter [`Shortcut`].
+        prefix: usize,
+        /// The index of the [`Binding`] with the longer [`Shortcut`].
+        sequence: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(shortcut: &str, platform: Platform) -> String {
+        let mut label = String::new();
+
+        Shortcut::parse(shortcut)
+            .expect("Parse shortcut")
+            .write_label(&mut label, platform)
+            .expect("Write label");
+
+        label
+    }
+
+    #[test]
+    fn parse() {
+        let shortcut = Shortcut::parse("Ctrl+Shift+P").unwrap();
+
+        assert_eq!(
+            shortcut.chords(),
+            &[Chord::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                Key::Character("p".into())
+            )]
+        );
+
+        assert_eq!(Shortcut::parse("Ctrl+K Ctrl+S").unwrap().chords().len(), 2);
+        assert_eq!(
+            Shortcut::parse("Ctrl++").unwrap().chords()[0].key,
+            Key::Character("+".into())
+        );
+        assert_eq!(Shortcut::parse(" "), Err(ParseError::Empty));
+        assert_eq!(
+            Shortcut::parse("Hyper+K"),
+            Err(ParseError::UnknownModifier("Hyper".to_owned()))
+        );
+        assert_eq!(
+            Shortcut::parse("Ctrl+Banana"),
+            Err(ParseError::UnknownKey("Banana".to_owned()))
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(label("Ctrl+Shift+p", Platform::Other), "Ctrl+Shift+P");
+        assert_eq!(label("Ctrl+Shift+p", Platform::MacOS), "⌃⇧P");
+        assert_eq!(label("Alt+Enter", Platform::MacOS), "⌥↩");
+        assert_eq!(label("Ctrl+K Ctrl+S", Platform::Other), "Ctrl+K Ctrl+S");
+        assert_eq!(label("Super+Left", Platform::Other), "Super+Left");
+    }
+
+    #[test]
+    fn find_and_conflicts() {
+        let registry = Registry::new()
+            .bind(Shortcut::parse("Ctrl+K Ctrl+S").unwrap(), 0)
+            .bind(Shortcut::parse("Ctrl+S").unwrap(), 1)
+            .bind(Shortcut::parse("Ctrl+K").unwrap(), 2)
+            .bind(Shortcut::parse("Ctrl+S").unwrap(), 3);
+
+        let ctrl_k = Chord::new(Modifiers::CTRL, Key::Character("k".into()));
+        let ctrl_s = Chord::new(Modifiers::CTRL, Key::Character("S".into()));
+
+        assert_eq!(
+            registry.find(std::slice::from_ref(&ctrl_s), |_| true),
+            Match::Exact(&1)
+        );
+        assert_eq!(
+            registry
+                .find(std::slice::from_ref(&ctrl_k), |binding| binding.message
+                    == 0),
+            Match::Partial
+        );
+        assert_eq!(
+            registry.find(&[ctrl_k, ctrl_s], |_| true),
+            Match::Exact(&0)
+        );
+
+        assert_eq!(
+            registry.conflicts(),
+            vec![
+                Conflict::Prefix {
+                    prefix: 2,
+                    sequence: 0
+                },
+                Conflict::Duplicate {
+                    first: 1,
+                    second: 3
+                },
+            ]
+        );
+    }
+}
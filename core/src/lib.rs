@@ -67,7 +67,7 @@ pub use point::Point;
 pub use rectangle::Rectangle;
 pub use renderer::Renderer;
 pub use rotation::Rotation;
-pub use shadow::Shadow;
+pub use shadow::{Shadow, Shadows};
 pub use shell::Shell;
 pub use size::Size;
 pub use svg::Svg;
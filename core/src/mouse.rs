@@ -3,11 +3,13 @@ pub mod click;
 
 mod button;
 mod cursor;
+mod custom_cursor;
 mod event;
 mod interaction;
 
 pub use button::Button;
 pub use click::Click;
 pub use cursor::Cursor;
+pub use custom_cursor::CustomCursor;
 pub use event::{Event, ScrollDelta};
 pub use interaction::Interaction;
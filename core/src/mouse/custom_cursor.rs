@@ -0,0 +1,54 @@
+use crate::image;
+use crate::Point;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// A custom mouse cursor made of an image.
+///
+/// Only [`image::Handle::Rgba`] handles are currently supported by the
+/// windowing shell; other kinds of handles will fall back to the default
+/// cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCursor {
+    /// The image of the cursor.
+    pub image: image::Handle,
+
+    /// The position of the hotspot of the cursor, in physical pixels
+    /// relative to the top-left corner of the image.
+    pub hotspot: Point<u16>,
+}
+
+impl CustomCursor {
+    /// Creates a new [`CustomCursor`] with the given image and hotspot.
+    pub fn new(image: impl Into<image::Handle>, hotspot: Point<u16>) -> Self {
+        Self {
+            image: image.into(),
+            hotspot,
+        }
+    }
+}
+
+impl PartialOrd for CustomCursor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CustomCursor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.image.id(), self.hotspot.x, self.hotspot.y).cmp(&(
+            other.image.id(),
+            other.hotspot.x,
+            other.hotspot.y,
+        ))
+    }
+}
+
+impl Hash for CustomCursor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.image.id().hash(state);
+        self.hotspot.x.hash(state);
+        self.hotspot.y.hash(state);
+    }
+}
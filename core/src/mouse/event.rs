@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -33,6 +33,20 @@ pub enum Event {
         /// The scroll movement.
         delta: ScrollDelta,
     },
+
+    /// The mouse was moved while the cursor was locked.
+    ///
+    /// The delta is raw and unfiltered; it is not affected by pointer
+    /// acceleration and it is not bound by the edges of the screen.
+    ///
+    /// This event is only produced while the cursor of the window is
+    /// [`Locked`].
+    ///
+    /// [`Locked`]: crate::window::CursorGrab::Locked
+    RawMotion {
+        /// The raw movement of the mouse.
+        delta: Vector,
+    },
 }
 
 /// A scroll movement.
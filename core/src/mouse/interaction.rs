@@ -1,5 +1,13 @@
+use crate::image;
+use crate::mouse::CustomCursor;
+use crate::Point;
+
 /// The interaction of a mouse cursor.
-#[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Default)]
+///
+/// An [`Interaction`] is not `Copy`, since an [`Interaction::Custom`] holds
+/// the [`image::Handle`] of its cursor. Cloning one is cheap, because the
+/// pixels of a handle are shared.
+#[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Ord, Default)]
 #[allow(missing_docs)]
 pub enum Interaction {
     #[default]
@@ -22,4 +30,18 @@ pub enum Interaction {
     Move,
     Copy,
     Help,
+    /// The mouse cursor is hidden.
+    Hidden,
+    /// The mouse cursor is a [`CustomCursor`].
+    Custom(CustomCursor),
+}
+
+impl Interaction {
+    /// Creates a custom [`Interaction`] with the given image and hotspot.
+    pub fn custom(
+        image: impl Into<image::Handle>,
+        hotspot: Point<u16>,
+    ) -> Self {
+        Self::Custom(CustomCursor::new(image, hotspot))
+    }
 }
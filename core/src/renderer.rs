@@ -3,7 +3,8 @@
 mod null;
 
 use crate::{
-    Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
+    Background, Border, Color, Pixels, Rectangle, Shadow, Shadows, Size,
+    Transformation, Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -25,6 +26,35 @@ pub trait Renderer {
         self.end_layer();
     }
 
+    /// Starts recording a new layer with the given [`Effect`].
+    ///
+    /// The contents of the layer—including any nested layers—will be
+    /// composited together before applying the [`Effect`] once the
+    /// layer ends.
+    ///
+    /// By default, the [`Effect`] is ignored and a plain layer is started;
+    /// renderers supporting effects should override this method.
+    fn start_layer_with_effect(&mut self, bounds: Rectangle, effect: Effect) {
+        let _ = effect;
+
+        self.start_layer(bounds);
+    }
+
+    /// Draws the primitives recorded in the given closure in a new layer
+    /// with the given [`Effect`].
+    ///
+    /// The layer will clip its contents to the provided `bounds`.
+    fn with_layer_effect(
+        &mut self,
+        bounds: Rectangle,
+        effect: Effect,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_layer_with_effect(bounds, effect);
+        f(self);
+        self.end_layer();
+    }
+
     /// Starts recording with a new [`Transformation`].
     fn start_transformation(&mut self, transformation: Transformation);
 
@@ -72,8 +102,18 @@ pub struct Quad {
     /// The [`Border`] of the [`Quad`]. The border is drawn on the inside of the [`Quad`].
     pub border: Border,
 
-    /// The [`Shadow`] of the [`Quad`].
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the [`Quad`].
+    pub shadows: Shadows,
+}
+
+impl Quad {
+    /// Returns the [`Quad`] with the given single [`Shadow`].
+    pub fn with_shadow(self, shadow: impl Into<Shadow>) -> Self {
+        Self {
+            shadows: Shadows::from(shadow.into()),
+            ..self
+        }
+    }
 }
 
 impl Default for Quad {
@@ -81,7 +121,7 @@ impl Default for Quad {
         Self {
             bounds: Rectangle::with_size(Size::ZERO),
             border: Border::default(),
-            shadow: Shadow::default(),
+            shadows: Shadows::NONE,
         }
     }
 }
@@ -100,3 +140,68 @@ impl Default for Style {
         }
     }
 }
+
+/// The visual effects of a layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effect {
+    /// The opacity of the whole layer, from `0.0` to `1.0`.
+    ///
+    /// Unlike the opacity of individual primitives, this opacity is
+    /// applied once the contents of the layer have been composited
+    /// together. Therefore, overlapping children will not show
+    /// through each other.
+    pub opacity: f32,
+
+    /// The radius of the blur applied to the contents beneath the
+    /// bounds of the layer.
+    pub backdrop_blur: f32,
+}
+
+impl Effect {
+    /// An [`Effect`] that does nothing.
+    pub const NONE: Self = Self {
+        opacity: 1.0,
+        backdrop_blur: 0.0,
+    };
+
+    /// Creates a new [`Effect`] with the given opacity.
+    pub fn opacity(opacity: f32) -> Self {
+        Self {
+            opacity,
+            ..Self::NONE
+        }
+    }
+
+    /// Creates a new [`Effect`] that blurs the contents beneath a layer
+    /// with the given radius.
+    pub fn backdrop_blur(radius: impl Into<Pixels>) -> Self {
+        Self {
+            backdrop_blur: radius.into().0,
+            ..Self::NONE
+        }
+    }
+
+    /// Sets the opacity of the [`Effect`].
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        Self { opacity, ..self }
+    }
+
+    /// Sets the radius of the backdrop blur of the [`Effect`].
+    pub fn with_backdrop_blur(self, radius: impl Into<Pixels>) -> Self {
+        Self {
+            backdrop_blur: radius.into().0,
+            ..self
+        }
+    }
+
+    /// Returns true if the [`Effect`] does not alter the contents of a layer.
+    pub fn is_none(&self) -> bool {
+        self.opacity >= 1.0 && self.backdrop_blur <= 0.0
+    }
+}
+
+impl Default for Effect {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
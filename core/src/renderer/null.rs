@@ -10,6 +10,13 @@ use crate::{
 impl Renderer for () {
     fn start_layer(&mut self, _bounds: Rectangle) {}
 
+    fn start_layer_with_effect(
+        &mut self,
+        _bounds: Rectangle,
+        _effect: renderer::Effect,
+    ) {
+    }
+
     fn end_layer(&mut self) {}
 
     fn start_transformation(&mut self, _transformation: Transformation) {}
@@ -103,6 +110,10 @@ impl text::Paragraph for () {
         Size::ZERO
     }
 
+    fn is_truncated(&self) -> bool {
+        false
+    }
+
     fn hit_test(&self, _point: Point) -> Option<text::Hit> {
         None
     }
@@ -114,6 +125,17 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn selection_bounds(
+        &self,
+        _range: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -137,6 +159,24 @@ impl text::Editor for () {
         None
     }
 
+    fn range_bounds(
+        &self,
+        _line: usize,
+        _range: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn hit(&self, _point: Point) -> Option<(usize, usize)> {
+        None
+    }
+
+    fn decorations(
+        &self,
+    ) -> Vec<(Rectangle, text::decoration::Line, text::Decoration)> {
+        vec![]
+    }
+
     fn line(&self, _index: usize) -> Option<&str> {
         None
     }
@@ -184,6 +224,10 @@ impl image::Renderer for () {
         Size::default()
     }
 
+    fn image_state(&self, _handle: &Self::Handle) -> image::State {
+        image::State::Loaded
+    }
+
     fn draw_image(&mut self, _image: Image, _bounds: Rectangle) {}
 }
 
@@ -11,4 +11,220 @@ pub struct Shadow {
 
     /// The blur radius of the shadow.
     pub blur_radius: f32,
+
+    /// Whether the shadow is drawn inside the bounds of its primitive,
+    /// on top of its background, instead of outside.
+    pub inset: bool,
+}
+
+impl Shadow {
+    /// Turns the [`Shadow`] into an inner shadow.
+    pub fn inset(self) -> Self {
+        Self {
+            inset: true,
+            ..self
+        }
+    }
+}
+
+/// A list of shadows, drawn from back to front in reverse order.
+///
+/// Like in CSS, the first [`Shadow`] of the list is drawn on top.
+///
+/// A [`Shadows`] list can hold up to [`Shadows::MAX`] shadows. It is `Copy`
+/// and does not allocate, so it can be used in graphics primitives freely.
+#[derive(Clone, Copy, Default)]
+pub struct Shadows {
+    shadows: [Shadow; Shadows::MAX],
+    len: usize,
+}
+
+impl Shadows {
+    /// The maximum amount of shadows in a [`Shadows`] list.
+    pub const MAX: usize = 4;
+
+    /// An empty list of [`Shadows`].
+    pub const NONE: Self = Self {
+        shadows: [Shadow {
+            color: Color::TRANSPARENT,
+            offset: Vector::ZERO,
+            blur_radius: 0.0,
+            inset: false,
+        }; Self::MAX],
+        len: 0,
+    };
+
+    /// Adds a [`Shadow`] at the end of the list.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the list is full. In release builds, shadows
+    /// beyond [`Shadows::MAX`] are dropped with a warning. Use
+    /// [`Shadows::try_push`] to handle a full list.
+    pub fn push(&mut self, shadow: Shadow) {
+        if self.try_push(shadow).is_err() {
+            debug_assert!(
+                false,
+                "a list can contain up to {} shadows",
+                Self::MAX
+            );
+
+            log::warn!(
+                "Ignoring shadow; a list can contain up to {} shadows",
+                Self::MAX
+            );
+        }
+    }
+
+    /// Adds a [`Shadow`] at the end of the list, if it is not full.
+    ///
+    /// Returns the [`Shadow`] back if the list already contains
+    /// [`Shadows::MAX`] shadows.
+    pub fn try_push(&mut self, shadow: Shadow) -> Result<(), Shadow> {
+        if self.is_full() {
+            return Err(shadow);
+        }
+
+        self.shadows[self.len] = shadow;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Returns the list of [`Shadows`] with the given [`Shadow`] added at
+    /// the end.
+    pub fn with(mut self, shadow: Shadow) -> Self {
+        self.push(shadow);
+        self
+    }
+
+    /// Returns the shadows in the list as a slice.
+    pub fn as_slice(&self) -> &[Shadow] {
+        &self.shadows[..self.len]
+    }
+
+    /// Returns an iterator over the shadows in the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, Shadow> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the amount of shadows in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list has no shadows.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the list contains [`Shadows::MAX`] shadows.
+    pub fn is_full(&self) -> bool {
+        self.len == Self::MAX
+    }
+
+    /// Returns true if any of the shadows in the list is visible.
+    pub fn is_visible(&self) -> bool {
+        self.iter().any(|shadow| shadow.color.a > 0.0)
+    }
+}
+
+impl std::fmt::Debug for Shadows {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for Shadows {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl From<Shadow> for Shadows {
+    fn from(shadow: Shadow) -> Self {
+        Self::NONE.with(shadow)
+    }
+}
+
+impl<const N: usize> From<[Shadow; N]> for Shadows {
+    fn from(shadows: [Shadow; N]) -> Self {
+        const {
+            assert!(N <= Shadows::MAX, "too many shadows in the array");
+        }
+
+        shadows.into_iter().collect()
+    }
+}
+
+impl FromIterator<Shadow> for Shadows {
+    fn from_iter<T: IntoIterator<Item = Shadow>>(iter: T) -> Self {
+        let mut shadows = Self::NONE;
+
+        for shadow in iter {
+            shadows.push(shadow);
+        }
+
+        shadows
+    }
+}
+
+impl<'a> IntoIterator for &'a Shadows {
+    type Item = &'a Shadow;
+    type IntoIter = std::slice::Iter<'a, Shadow>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shadow(blur_radius: f32) -> Shadow {
+        Shadow {
+            color: Color::BLACK,
+            blur_radius,
+            ..Shadow::default()
+        }
+    }
+
+    #[test]
+    fn shadows_keep_their_order() {
+        let shadows = Shadows::from([shadow(1.0), shadow(2.0).inset()]);
+
+        assert_eq!(shadows.len(), 2);
+        assert_eq!(shadows.as_slice(), &[shadow(1.0), shadow(2.0).inset()]);
+        assert!(shadows.is_visible());
+    }
+
+    #[test]
+    fn full_lists_reject_shadows() {
+        let mut shadows: Shadows =
+            (0..Shadows::MAX).map(|i| shadow(i as f32)).collect();
+
+        assert!(shadows.is_full());
+        assert_eq!(shadows.try_push(shadow(10.0)), Err(shadow(10.0)));
+        assert_eq!(shadows.len(), Shadows::MAX);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "a list can contain up to 4 shadows")]
+    fn pushing_to_full_lists_panics() {
+        let mut shadows = Shadows::from([shadow(0.0); Shadows::MAX]);
+
+        shadows.push(shadow(1.0));
+    }
+
+    #[test]
+    fn transparent_shadows_are_not_visible() {
+        let transparent = Shadow {
+            color: Color::TRANSPARENT,
+            ..shadow(1.0)
+        };
+
+        assert!(!Shadows::NONE.is_visible());
+        assert!(!Shadows::from(transparent).is_visible());
+    }
 }
@@ -1,8 +1,11 @@
 //! Draw and interact with text.
+pub mod decoration;
 pub mod editor;
 pub mod highlighter;
 pub mod paragraph;
+pub mod spell;
 
+pub use decoration::Decoration;
 pub use editor::Editor;
 pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
@@ -44,6 +47,14 @@ pub struct Text<Content = String, Font = crate::Font> {
 
     /// The [`Wrapping`] strategy of the [`Text`].
     pub wrapping: Wrapping,
+
+    /// The [`Ellipsis`] strategy of the [`Text`].
+    pub ellipsis: Ellipsis,
+
+    /// The maximum amount of lines of the [`Text`], if any.
+    ///
+    /// Any lines past this limit are cut off.
+    pub max_lines: Option<usize>,
 }
 
 /// The shaping strategy of some text.
@@ -86,6 +97,27 @@ pub enum Wrapping {
     WordOrGlyph,
 }
 
+/// The ellipsis strategy of some text that does not fit its bounds.
+///
+/// Text is truncated when it needs more lines than allowed or, when an
+/// ellipsis is set, when it overflows its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsis {
+    /// No ellipsis; overflowing text is clipped.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// Cuts off the end of the text: `"Hello, wo…"`.
+    End,
+    /// Cuts off the middle of the text: `"/home/…/main.rs"`.
+    ///
+    /// Useful for file paths.
+    Middle,
+    /// Cuts off the start of the text: `"…lo, world!"`.
+    Start,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -264,10 +296,12 @@ pub struct Span<'a, Link = (), Font = crate::Font> {
     ///
     /// Currently, it only affects the bounds of the [`Highlight`].
     pub padding: Padding,
-    /// Whether the [`Span`] should be underlined or not.
-    pub underline: bool,
-    /// Whether the [`Span`] should be struck through or not.
-    pub strikethrough: bool,
+    /// The underline [`Decoration`] of the [`Span`], if any.
+    pub underline: Option<Decoration>,
+    /// The strikethrough [`Decoration`] of the [`Span`], if any.
+    pub strikethrough: Option<Decoration>,
+    /// The overline [`Decoration`] of the [`Span`], if any.
+    pub overline: Option<Decoration>,
 }
 
 /// A text highlight.
@@ -291,8 +325,9 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             highlight: None,
             link: None,
             padding: Padding::ZERO,
-            underline: false,
-            strikethrough: false,
+            underline: None,
+            strikethrough: None,
+            overline: None,
         }
     }
 
@@ -413,16 +448,63 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
 
     /// Sets whether the [`Span`] should be underlined or not.
     pub fn underline(mut self, underline: bool) -> Self {
-        self.underline = underline;
+        self.underline = underline.then(Decoration::default);
+        self
+    }
+
+    /// Underlines the [`Span`] with the given [`Decoration`].
+    pub fn underline_decoration(
+        mut self,
+        decoration: impl Into<Decoration>,
+    ) -> Self {
+        self.underline = Some(decoration.into());
         self
     }
 
     /// Sets whether the [`Span`] should be struck through or not.
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
-        self.strikethrough = strikethrough;
+        self.strikethrough = strikethrough.then(Decoration::default);
+        self
+    }
+
+    /// Strikes the [`Span`] through with the given [`Decoration`].
+    pub fn strikethrough_decoration(
+        mut self,
+        decoration: impl Into<Decoration>,
+    ) -> Self {
+        self.strikethrough = Some(decoration.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] should be overlined or not.
+    pub fn overline(mut self, overline: bool) -> Self {
+        self.overline = overline.then(Decoration::default);
+        self
+    }
+
+    /// Overlines the [`Span`] with the given [`Decoration`].
+    pub fn overline_decoration(
+        mut self,
+        decoration: impl Into<Decoration>,
+    ) -> Self {
+        self.overline = Some(decoration.into());
         self
     }
 
+    /// Returns the [`Decoration`]s of the [`Span`], along with their
+    /// [`decoration::Line`].
+    pub fn decorations(
+        &self,
+    ) -> impl Iterator<Item = (decoration::Line, Decoration)> {
+        [
+            (decoration::Line::Underline, self.underline),
+            (decoration::Line::Strikethrough, self.strikethrough),
+            (decoration::Line::Overline, self.overline),
+        ]
+        .into_iter()
+        .filter_map(|(line, decoration)| Some((line, decoration?)))
+    }
+
     /// Turns the [`Span`] into a static one.
     pub fn to_static(self) -> Span<'static, Link, Font> {
         Span {
@@ -436,6 +518,7 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             padding: self.padding,
             underline: self.underline,
             strikethrough: self.strikethrough,
+            overline: self.overline,
         }
     }
 }
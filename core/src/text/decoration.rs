@@ -0,0 +1,257 @@
+//! Draw lines under, over, or through text.
+use crate::{Color, Pixels, Rectangle};
+
+/// A line drawn under, over, or through some text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Decoration {
+    /// The [`Style`] of the [`Decoration`].
+    pub style: Style,
+    /// The [`Color`] of the [`Decoration`].
+    ///
+    /// If `None`, the color of the text is used.
+    pub color: Option<Color>,
+    /// The thickness of the [`Decoration`].
+    ///
+    /// If `None`, the thickness is derived from the size of the text; a
+    /// sixteenth of it, but never thinner than `1.0`.
+    pub thickness: Option<Pixels>,
+}
+
+impl Decoration {
+    /// Creates a new [`Decoration`] with the given [`Style`].
+    pub const fn new(style: Style) -> Self {
+        Self {
+            style,
+            color: None,
+            thickness: None,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Decoration`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the thickness of the [`Decoration`].
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = Some(thickness.into());
+        self
+    }
+
+    /// Returns the rectangles to fill in order to draw the [`Decoration`]
+    /// as the given [`Line`] of some text.
+    ///
+    /// The `bounds` must contain a single line of text, with its height
+    /// being the line height, and `size` must be the size of the text.
+    ///
+    /// A [`Decoration`] with a thickness that is not positive has no
+    /// segments.
+    pub fn segments(
+        &self,
+        line: Line,
+        bounds: Rectangle,
+        size: Pixels,
+    ) -> Vec<Rectangle> {
+        let thickness = self
+            .thickness
+            .map_or_else(|| (size.0 / 16.0).max(1.0), f32::from);
+
+        if !(thickness > 0.0 && thickness.is_finite()) {
+            return Vec::new();
+        }
+
+        let baseline =
+            bounds.y + size.0 + (bounds.height - size.0).max(0.0) / 2.0;
+
+        let y = match line {
+            Line::Underline => baseline - size.0 * 0.08,
+            Line::Overline => baseline - size.0,
+            Line::Strikethrough => baseline - size.0 / 2.0,
+        };
+
+        let solid = |y: f32| Rectangle {
+            x: bounds.x,
+            y,
+            width: bounds.width,
+            height: thickness,
+        };
+
+        let dashes = |length: f32, gap: f32| {
+            let count = (bounds.width / (length + gap)).ceil() as usize;
+
+            (0..count)
+                .map(|i| {
+                    let x = i as f32 * (length + gap);
+
+                    Rectangle {
+                        x: bounds.x + x,
+                        y,
+                        width: length.min(bounds.width - x),
+                        height: thickness,
+                    }
+                })
+                .collect()
+        };
+
+        match self.style {
+            Style::Solid => vec![solid(y)],
+            Style::Double => {
+                let offset = thickness * 2.0;
+
+                match line {
+                    Line::Overline => vec![solid(y), solid(y - offset)],
+                    _ => vec![solid(y), solid(y + offset)],
+                }
+            }
+            Style::Dotted => dashes(thickness, thickness),
+            Style::Dashed => dashes(thickness * 3.0, thickness * 2.0),
+            Style::Wavy => {
+                // A triangle wave made of small steps
+                let period = thickness * 4.0;
+                let count = (bounds.width / thickness).ceil() as usize;
+
+                (0..count)
+                    .map(|i| {
+                        let x = i as f32 * thickness;
+                        let phase = (x % period) / period;
+                        let offset = if phase < 0.5 {
+                            phase * 4.0 - 1.0
+                        } else {
+                            3.0 - phase * 4.0
+                        };
+
+                        Rectangle {
+                            x: bounds.x + x,
+                            y: y + offset * thickness,
+                            width: thickness.min(bounds.width - x),
+                            height: thickness,
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl From<Style> for Decoration {
+    fn from(style: Style) -> Self {
+        Self::new(style)
+    }
+}
+
+/// The style of a [`Decoration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// A single solid line.
+    #[default]
+    Solid,
+    /// Two parallel solid lines.
+    Double,
+    /// A line of dots.
+    Dotted,
+    /// A line of dashes.
+    Dashed,
+    /// A wavy line; like the ones used to mark spelling errors.
+    Wavy,
+}
+
+/// The placement of a [`Decoration`] relative to some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Line {
+    /// Under the baseline of the text.
+    Underline,
+    /// Over the text.
+    Overline,
+    /// Through the middle of the text.
+    Strikethrough,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Size;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 0.0,
+        width: 20.0,
+        height: 20.0,
+    };
+
+    #[test]
+    fn solid_spans_the_bounds() {
+        let segments = Decoration::default().segments(
+            Line::Underline,
+            BOUNDS,
+            Pixels(16.0),
+        );
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].position().x, BOUNDS.x);
+        assert_eq!(segments[0].size(), Size::new(BOUNDS.width, 1.0));
+    }
+
+    #[test]
+    fn thickness_follows_the_size() {
+        let thickness = |decoration: Decoration, size: f32| {
+            decoration.segments(Line::Underline, BOUNDS, Pixels(size))[0].height
+        };
+
+        assert_eq!(thickness(Decoration::default(), 8.0), 1.0);
+        assert_eq!(thickness(Decoration::default(), 32.0), 2.0);
+        assert_eq!(thickness(Decoration::default().thickness(1.0), 32.0), 1.0);
+    }
+
+    #[test]
+    fn non_positive_thickness_has_no_segments() {
+        for style in [
+            Style::Solid,
+            Style::Double,
+            Style::Dotted,
+            Style::Dashed,
+            Style::Wavy,
+        ] {
+            for thickness in [0.0, -1.0, f32::NAN] {
+                let segments = Decoration::new(style)
+                    .thickness(thickness)
+                    .segments(Line::Underline, BOUNDS, Pixels(16.0));
+
+                assert!(segments.is_empty(), "{style:?} {thickness}");
+            }
+        }
+    }
+
+    #[test]
+    fn double_places_lines_apart() {
+        let segments = Decoration::new(Style::Double).thickness(2.0).segments(
+            Line::Underline,
+            BOUNDS,
+            Pixels(16.0),
+        );
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].y - segments[0].y, 4.0);
+    }
+
+    #[test]
+    fn patterns_stay_within_the_bounds() {
+        for style in [Style::Dotted, Style::Dashed, Style::Wavy] {
+            let segments = Decoration::new(style).thickness(1.5).segments(
+                Line::Strikethrough,
+                BOUNDS,
+                Pixels(16.0),
+            );
+
+            assert!(segments.len() > 1);
+
+            for segment in segments {
+                assert!(segment.x >= BOUNDS.x);
+                assert!(
+                    segment.x + segment.width
+                        <= BOUNDS.x + BOUNDS.width + f32::EPSILON * 100.0
+                );
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Edit text.
+use crate::text::decoration::{self, Decoration};
 use crate::text::highlighter::{self, Highlighter};
 use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
+use std::ops::Range;
 use std::sync::Arc;
 
 /// A component that can be used by widgets to edit multi-line text.
@@ -27,6 +29,27 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the bounds of the given byte range of the line with the
+    /// given index; one for each visual line the range spans.
+    fn range_bounds(&self, line: usize, range: Range<usize>) -> Vec<Rectangle>;
+
+    /// Returns the range of lines of the [`Editor`] that are currently
+    /// visible.
+    ///
+    /// By default, every line is considered visible.
+    fn visible_lines(&self) -> Range<usize> {
+        0..self.line_count()
+    }
+
+    /// Returns the line and byte index of the text under the given
+    /// [`Point`], if any.
+    fn hit(&self, point: Point) -> Option<(usize, usize)>;
+
+    /// Returns the visible [`Decoration`]s of the highlighted text of the
+    /// [`Editor`], along with their [`decoration::Line`] and the bounds of
+    /// the text they decorate.
+    fn decorations(&self) -> Vec<(Rectangle, decoration::Line, Decoration)>;
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
@@ -109,6 +132,15 @@ pub enum Edit {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Replace the given byte range of a line with some text.
+    Replace {
+        /// The index of the line.
+        line: usize,
+        /// The byte range of the line to replace.
+        range: Range<usize>,
+        /// The replacement text.
+        text: Arc<String>,
+    },
 }
 
 /// A cursor movement.
@@ -1,4 +1,5 @@
 //! Highlight text.
+use crate::text::Decoration;
 use crate::Color;
 
 use std::ops::Range;
@@ -76,6 +77,10 @@ pub struct Format<Font> {
     pub color: Option<Color>,
     /// The `Font` of the text.
     pub font: Option<Font>,
+    /// The underline [`Decoration`] of the text.
+    pub underline: Option<Decoration>,
+    /// The strikethrough [`Decoration`] of the text.
+    pub strikethrough: Option<Decoration>,
 }
 
 impl<Font> Default for Format<Font> {
@@ -83,6 +88,8 @@ impl<Font> Default for Format<Font> {
         Self {
             color: None,
             font: None,
+            underline: None,
+            strikethrough: None,
         }
     }
 }
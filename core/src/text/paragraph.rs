@@ -3,6 +3,8 @@ use crate::alignment;
 use crate::text::{Difference, Hit, Span, Text};
 use crate::{Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -33,6 +35,10 @@ pub trait Paragraph: Sized + Default {
     /// [`Paragraph`].
     fn min_bounds(&self) -> Size;
 
+    /// Returns true if the contents of the [`Paragraph`] were truncated to
+    /// fit its bounds or maximum amount of lines.
+    fn is_truncated(&self) -> bool;
+
     /// Tests whether the provided point is within the boundaries of the
     /// [`Paragraph`], returning information about the nearest character.
     fn hit_test(&self, point: Point) -> Option<Hit>;
@@ -46,6 +52,14 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Returns the byte offset in the contents of the [`Paragraph`] that is
+    /// nearest to the provided point, if any.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds covering the given byte range of the contents of
+    /// the [`Paragraph`]; one for each visual line the range spans.
+    fn selection_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
@@ -96,6 +110,8 @@ impl<P: Paragraph> Plain<P> {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
         }) {
             Difference::None => {}
             Difference::Bounds => {
@@ -129,6 +145,11 @@ impl<P: Paragraph> Plain<P> {
         self.raw.min_width()
     }
 
+    /// Returns true if the contents of the [`Paragraph`] were truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.raw.is_truncated()
+    }
+
     /// Returns the cached [`Paragraph`].
     pub fn raw(&self) -> &P {
         &self.raw
@@ -0,0 +1,249 @@
+//! Check the spelling of text.
+use crate::text::decoration::{self, Decoration};
+use crate::Color;
+
+use std::collections::HashSet;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+/// The [`Decoration`] used by default to underline misspelled words.
+pub const DECORATION: Decoration = Decoration {
+    style: decoration::Style::Wavy,
+    color: Some(Color::from_rgb(0.87, 0.24, 0.24)),
+    thickness: None,
+};
+
+/// A type capable of checking the spelling of text.
+///
+/// Like a [`Highlighter`], a [`Checker`] processes text line by line.
+///
+/// [`Highlighter`]: crate::text::Highlighter
+pub trait Checker {
+    /// Returns the byte ranges of the misspelled words in the given line.
+    fn check(&self, line: &str) -> Vec<Range<usize>>;
+
+    /// Returns the suggested replacements for the given misspelled word,
+    /// from best to worst.
+    fn suggest(&self, word: &str) -> Vec<String>;
+}
+
+/// A [`Checker`] backed by a list of known words.
+///
+/// Words are compared case-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    words: HashSet<String>,
+}
+
+impl Dictionary {
+    /// The maximum amount of suggestions returned by [`Checker::suggest`].
+    pub const MAX_SUGGESTIONS: usize = 5;
+
+    /// Creates a new [`Dictionary`] with the given words.
+    pub fn new<T: AsRef<str>>(words: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| word.as_ref().trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Parses a [`Dictionary`] from the contents of a dictionary file.
+    ///
+    /// The file must contain a word per line. Hunspell `.dic` files are
+    /// supported too; their word count and affix flags are ignored.
+    pub fn parse(contents: &str) -> Self {
+        Self::new(contents.lines().filter_map(|line| {
+            let word = line.split('/').next()?.trim();
+
+            if word.starts_with('#') || word.chars().all(|c| c.is_ascii_digit())
+            {
+                None
+            } else {
+                Some(word)
+            }
+        }))
+    }
+
+    /// Loads a [`Dictionary`] from the dictionary file at the given path.
+    ///
+    /// See [`Dictionary::parse`] for the supported formats.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Returns true if the [`Dictionary`] contains the given word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// Returns the amount of words in the [`Dictionary`].
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns true if the [`Dictionary`] has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Checker for Dictionary {
+    fn check(&self, line: &str) -> Vec<Range<usize>> {
+        words(line)
+            .filter(|range| {
+                let word = &line[range.clone()];
+
+                // Numbers and identifiers are not words
+                !word.chars().any(char::is_numeric) && !self.contains(word)
+            })
+            .collect()
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let lowercase = word.to_lowercase();
+        let is_capitalized =
+            word.chars().next().is_some_and(char::is_uppercase);
+
+        let mut candidates: Vec<_> = self
+            .words
+            .iter()
+            .filter_map(|candidate| {
+                let distance = distance(&lowercase, candidate)?;
+
+                Some((distance, candidate))
+            })
+            .collect();
+
+        candidates.sort();
+
+        candidates
+            .into_iter()
+            .take(Self::MAX_SUGGESTIONS)
+            .map(|(_, candidate)| {
+                if is_capitalized {
+                    let mut chars = candidate.chars();
+
+                    chars
+                        .next()
+                        .map(|first| {
+                            first.to_uppercase().chain(chars).collect()
+                        })
+                        .unwrap_or_default()
+                } else {
+                    candidate.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the byte ranges of the words in the given line.
+///
+/// A word is a sequence of alphanumeric characters, which may contain
+/// apostrophes between them; like `don't`.
+pub fn words(line: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut chars = line.char_indices().peekable();
+
+    std::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| c.is_alphanumeric())?;
+        let mut end = line.len();
+
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_alphanumeric() {
+                let _ = chars.next();
+            } else if c == '\'' || c == '’' {
+                let _ = chars.next();
+
+                if !chars.peek().is_some_and(|(_, c)| c.is_alphanumeric()) {
+                    end = i;
+                    break;
+                }
+            } else {
+                end = i;
+                break;
+            }
+        }
+
+        Some(start..end)
+    })
+}
+
+/// The maximum edit distance of a suggestion.
+const MAX_DISTANCE: usize = 2;
+
+/// Computes the Levenshtein distance between two words, if it does not
+/// exceed [`MAX_DISTANCE`].
+fn distance(a: &str, b: &str) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > MAX_DISTANCE {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+
+            current[j + 1] =
+                substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        if current.iter().all(|distance| *distance > MAX_DISTANCE) {
+            return None;
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+
+    (distance <= MAX_DISTANCE).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_split() {
+        let line = "Don't panic, it's 42 o'clock—'quoted'";
+
+        let words: Vec<_> = words(line).map(|range| &line[range]).collect();
+
+        assert_eq!(
+            words,
+            ["Don't", "panic", "it's", "42", "o'clock", "quoted"]
+        );
+    }
+
+    #[test]
+    fn dictionary_checks_and_suggests() {
+        let dictionary =
+            Dictionary::parse("3\nhello/S\nworld\nword\n# comment\n");
+
+        assert_eq!(dictionary.len(), 3);
+
+        let line = "Hello wrld, 2024 worlds";
+
+        let misspelled: Vec<_> = dictionary
+            .check(line)
+            .into_iter()
+            .map(|range| &line[range])
+            .collect();
+
+        assert_eq!(misspelled, ["wrld", "worlds"]);
+        assert_eq!(dictionary.suggest("wrld"), ["world", "word"]);
+        assert_eq!(dictionary.suggest("Wrld"), ["World", "Word"]);
+        assert!(dictionary.suggest("xyzzy").is_empty());
+    }
+}
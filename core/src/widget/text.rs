@@ -20,7 +20,12 @@
 //!         .into()
 //! }
 //! ```
+mod selection;
+
+pub use selection::Selection;
+
 use crate::alignment;
+use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -28,11 +33,11 @@ use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
-    Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Widget,
 };
 
-pub use text::{LineHeight, Shaping, Wrapping};
+pub use text::{Ellipsis, LineHeight, Shaping, Wrapping};
 
 /// A bunch of text.
 ///
@@ -72,6 +77,9 @@ where
     font: Option<Renderer::Font>,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -93,6 +101,9 @@ where
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            is_selectable: false,
             class: Theme::default(),
         }
     }
@@ -165,6 +176,32 @@ where
         self
     }
 
+    /// Sets the [`Ellipsis`] strategy of the [`Text`].
+    ///
+    /// The [`Text`] will be truncated with an ellipsis when it overflows
+    /// its bounds or its maximum amount of lines.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// Any lines past this limit are cut off.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected and
+    /// copied by the user.
+    ///
+    /// Truncated contents cannot be selected.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -190,7 +227,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -204,7 +244,14 @@ where
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(pub paragraph::Plain<P>);
+pub struct State<P: Paragraph>(pub paragraph::Plain<P>, Selection);
+
+impl<P: Paragraph> State<P> {
+    /// Returns true if the contents of the [`Text`] were truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.0.is_truncated()
+    }
+}
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -217,9 +264,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>(
-            paragraph::Plain::default(),
-        ))
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -249,9 +294,60 @@ where
             self.vertical_alignment,
             self.shaping,
             self.wrapping,
+            self.ellipsis,
+            self.max_lines,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.is_selectable {
+            return event::Status::Ignored;
+        }
+
+        let State(paragraph, selection) =
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        selection.update(
+            &event,
+            layout.bounds(),
+            cursor,
+            paragraph.raw(),
+            &self.fragment,
+            clipboard,
         )
     }
 
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let State(paragraph, _) =
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.is_selectable
+            && !paragraph.is_truncated()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -262,10 +358,20 @@ where
         _cursor_position: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let State(paragraph, selection) =
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
-        draw(renderer, defaults, layout, state.0.raw(), style, viewport);
+        if self.is_selectable {
+            selection.draw(
+                renderer,
+                paragraph.raw(),
+                layout.bounds(),
+                style.selection_color(defaults),
+            );
+        }
+
+        draw(renderer, defaults, layout, paragraph.raw(), style, viewport);
     }
 }
 
@@ -284,6 +390,8 @@ pub fn layout<Renderer>(
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -294,7 +402,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
+        let paragraph = &mut state.0;
 
         paragraph.update(text::Text {
             content,
@@ -306,6 +414,8 @@ where
             vertical_alignment,
             shaping,
             wrapping,
+            ellipsis,
+            max_lines,
         });
 
         paragraph.min_bounds()
@@ -395,6 +505,20 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+    /// The [`Color`] of the highlight of selected text.
+    ///
+    /// The default, `None`, means using a translucent text color.
+    pub selection: Option<Color>,
+}
+
+impl Style {
+    /// Returns the [`Color`] of the highlight of selected text, given the
+    /// inherited [`renderer::Style`].
+    pub fn selection_color(&self, defaults: &renderer::Style) -> Color {
+        self.selection.unwrap_or_else(|| {
+            self.color.unwrap_or(defaults.text_color).scale_alpha(0.3)
+        })
+    }
 }
 
 /// The theme catalog of a [`Text`].
@@ -427,14 +551,18 @@ impl Catalog for Theme {
 }
 
 /// The default text styling; color is inherited.
-pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+pub fn default(theme: &Theme) -> Style {
+    Style {
+        color: None,
+        selection: Some(theme.extended_palette().primary.weak.color),
+    }
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().text),
+        selection: Some(theme.extended_palette().primary.weak.color),
     }
 }
 
@@ -442,6 +570,7 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().primary),
+        selection: Some(theme.extended_palette().primary.weak.color),
     }
 }
 
@@ -449,6 +578,7 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.extended_palette().secondary.strong.color),
+        selection: Some(theme.extended_palette().primary.weak.color),
     }
 }
 
@@ -456,6 +586,7 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().success),
+        selection: Some(theme.extended_palette().primary.weak.color),
     }
 }
 
@@ -463,5 +594,6 @@ pub fn success(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().danger),
+        selection: Some(theme.extended_palette().primary.weak.color),
     }
 }
@@ -0,0 +1,298 @@
+use crate::alignment;
+use crate::clipboard::{self, Clipboard};
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Paragraph};
+use crate::{Color, Point, Rectangle};
+
+use std::ops::Range;
+
+/// The text selection of a selectable text widget.
+///
+/// Offsets are byte indices into the contents of the text. Truncated text
+/// cannot be selected, since its laid out contents differ from the original.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    anchor: usize,
+    head: usize,
+    is_dragging: bool,
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl Selection {
+    /// Returns the selected byte range of the contents.
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+
+    /// Returns true if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Returns the selected part of the given contents, if any.
+    pub fn text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        if self.is_empty() {
+            return None;
+        }
+
+        content.get(self.range())
+    }
+
+    /// Selects all of the given contents.
+    pub fn select_all(&mut self, content: &str) {
+        self.anchor = 0;
+        self.head = content.len();
+    }
+
+    /// Clears the [`Selection`].
+    pub fn clear(&mut self) {
+        self.anchor = 0;
+        self.head = 0;
+        self.is_dragging = false;
+    }
+
+    /// Processes an [`Event`] for the text with the given contents laid out
+    /// in the given [`Paragraph`] and drawn inside the provided bounds.
+    ///
+    /// Dragging the mouse selects text, double clicking selects a word and
+    /// triple clicking selects a line. Once focused, the contents can be
+    /// selected entirely with `Ctrl+A` and copied with `Ctrl+C`.
+    pub fn update<P: Paragraph>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        paragraph: &P,
+        content: &str,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        if paragraph.is_truncated() {
+            self.clear();
+            self.is_focused = false;
+
+            return event::Status::Ignored;
+        }
+
+        let translation = origin(paragraph, bounds) - Point::ORIGIN;
+
+        // The contents may have changed since the last event
+        if !content.is_char_boundary(self.anchor)
+            || !content.is_char_boundary(self.head)
+        {
+            self.clear();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    self.clear();
+                    self.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let Some(offset) = paragraph
+                    .hit_offset(position - translation)
+                    .filter(|offset| content.is_char_boundary(*offset))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(
+                    position,
+                    mouse::Button::Left,
+                    self.last_click,
+                );
+
+                let range = match click.kind() {
+                    mouse::click::Kind::Single => offset..offset,
+                    mouse::click::Kind::Double => word(content, offset),
+                    mouse::click::Kind::Triple => line(content, offset),
+                };
+
+                self.anchor = range.start;
+                self.head = range.end;
+                self.is_dragging =
+                    matches!(click.kind(), mouse::click::Kind::Single);
+                self.is_focused = true;
+                self.last_click = Some(click);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.is_dragging =>
+            {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                let position = Point::new(
+                    position.x.clamp(bounds.x, bounds.x + bounds.width),
+                    position.y.clamp(bounds.y, bounds.y + bounds.height),
+                );
+
+                if let Some(offset) = paragraph
+                    .hit_offset(position - translation)
+                    .filter(|offset| content.is_char_boundary(*offset))
+                {
+                    self.head = offset;
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.is_dragging = false;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if self.is_focused && modifiers.command() => {
+                match c.as_str() {
+                    "a" => {
+                        self.select_all(content);
+                    }
+                    "c" => {
+                        if let Some(selection) = self.text(content) {
+                            clipboard.write(
+                                clipboard::Kind::Standard,
+                                selection.to_owned(),
+                            );
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Draws the highlight of the [`Selection`] of the given [`Paragraph`]
+    /// drawn inside the provided bounds.
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        bounds: Rectangle,
+        color: Color,
+    ) where
+        Renderer: text::Renderer,
+    {
+        if self.is_empty() || paragraph.is_truncated() {
+            return;
+        }
+
+        let translation = origin(paragraph, bounds) - Point::ORIGIN;
+
+        for bounds in paragraph.selection_bounds(self.range()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + translation,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+/// Returns the position of the top left corner of the given [`Paragraph`]
+/// when drawn inside the provided bounds; just like [`super::draw`] does.
+fn origin<P: Paragraph>(paragraph: &P, bounds: Rectangle) -> Point {
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    Point::new(x, y)
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the range of the word around the given offset.
+fn word(content: &str, offset: usize) -> Range<usize> {
+    let Some(current) = content[offset..].chars().next() else {
+        return offset..offset;
+    };
+
+    if !is_word(current) {
+        return offset..offset + current.len_utf8();
+    }
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = content[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(content.len(), |(i, _)| offset + i);
+
+    start..end
+}
+
+/// Returns the range of the line around the given offset.
+fn line(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+
+    let end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_boundaries() {
+        let content = "Hello, wörld_1 !";
+
+        assert_eq!(word(content, 0), 0..5);
+        assert_eq!(word(content, 3), 0..5);
+        assert_eq!(word(content, 5), 5..6);
+        assert_eq!(word(content, 10), 7..15);
+        assert_eq!(word(content, content.len()), 17..17);
+    }
+
+    #[test]
+    fn line_boundaries() {
+        let content = "first\nsecond\n";
+
+        assert_eq!(line(content, 2), 0..5);
+        assert_eq!(line(content, 6), 6..12);
+        assert_eq!(line(content, content.len()), 13..13);
+    }
+}
@@ -2,14 +2,17 @@
 pub mod icon;
 pub mod settings;
 
+mod cursor_grab;
 mod event;
 mod id;
 mod level;
 mod mode;
 mod position;
 mod redraw_request;
+mod snapshot;
 mod user_attention;
 
+pub use cursor_grab::CursorGrab;
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
@@ -18,4 +21,5 @@ pub use mode::Mode;
 pub use position::Position;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
+pub use snapshot::Snapshot;
 pub use user_attention::UserAttention;
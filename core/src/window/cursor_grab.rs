@@ -0,0 +1,21 @@
+/// The grab mode of the mouse cursor of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorGrab {
+    /// The cursor can move freely.
+    #[default]
+    None,
+
+    /// The cursor is confined to the window area.
+    Confined,
+
+    /// The cursor is locked in place.
+    ///
+    /// While locked, the movements of the mouse are reported as
+    /// [`mouse::Event::RawMotion`] events.
+    ///
+    /// If locking is not supported by the platform, the cursor will be
+    /// confined instead.
+    ///
+    /// [`mouse::Event::RawMotion`]: crate::mouse::Event::RawMotion
+    Locked,
+}
@@ -1,5 +1,10 @@
 /// The mode of a window-based application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Mode {
     /// The application appears in its own window.
     Windowed,
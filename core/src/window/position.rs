@@ -21,6 +21,15 @@ pub enum Position {
     ///
     /// [`Specific`]: Self::Specific
     SpecificWith(fn(Size, Size) -> Point),
+    /// Like [`Specific`], but the coordinates are only used as long as the
+    /// window stays visible in one of the available monitors. Otherwise, the
+    /// window is centered.
+    ///
+    /// This is the position of a window restored from a [`Snapshot`].
+    ///
+    /// [`Specific`]: Self::Specific
+    /// [`Snapshot`]: super::Snapshot
+    Restored(Point),
 }
 
 impl Default for Position {
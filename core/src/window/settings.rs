@@ -43,6 +43,12 @@ pub struct Settings {
     /// The maximum size of the window.
     pub max_size: Option<Size>,
 
+    /// Whether the window should start maximized or not.
+    pub maximized: bool,
+
+    /// Whether the window should start in fullscreen mode or not.
+    pub fullscreen: bool,
+
     /// Whether the window should be visible or not.
     pub visible: bool,
 
@@ -73,6 +79,15 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// The key used to persist the [`Snapshot`] of the window, if any.
+    ///
+    /// If the application has some persistence storage, the window will be
+    /// restored from the entry with this key when opened, and a new
+    /// [`Snapshot`] will be saved under it when closed.
+    ///
+    /// [`Snapshot`]: crate::window::Snapshot
+    pub persistence_key: Option<String>,
 }
 
 impl Default for Settings {
@@ -82,6 +97,8 @@ impl Default for Settings {
             position: Position::default(),
             min_size: None,
             max_size: None,
+            maximized: false,
+            fullscreen: false,
             visible: true,
             resizable: true,
             decorations: true,
@@ -89,6 +106,7 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
+            persistence_key: None,
             platform_specific: PlatformSpecific::default(),
         }
     }
@@ -0,0 +1,116 @@
+use crate::window::{Mode, Position, Settings};
+use crate::{Point, Rectangle, Size};
+
+/// The state of a window at a given point in time, which can be used to
+/// restore the window later on; usually, after the application restarts.
+///
+/// A [`Snapshot`] only keeps track of the geometry of a window. It can be
+/// applied to some [`Settings`] to open a window right where it was.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub struct Snapshot {
+    /// The logical position of the window, if known.
+    ///
+    /// Some platforms—like Wayland—do not expose the position of windows.
+    pub position: Option<Point>,
+
+    /// The logical size of the window.
+    pub size: Size,
+
+    /// Whether the window was maximized.
+    pub maximized: bool,
+
+    /// The [`Mode`] of the window.
+    pub mode: Mode,
+}
+
+impl Snapshot {
+    /// The minimum amount of logical pixels of a window that must be visible
+    /// in a monitor for its position to be restored.
+    pub const MIN_VISIBLE: f32 = 64.0;
+
+    /// Applies the [`Snapshot`] to the given [`Settings`].
+    ///
+    /// The position of the [`Snapshot`] is applied as a
+    /// [`Position::Restored`]; so the window will be centered if it
+    /// does not fit the available monitors anymore.
+    pub fn apply(self, settings: Settings) -> Settings {
+        let size = match (settings.min_size, settings.max_size) {
+            (Some(min), Some(max)) => self.size.max(min).min(max),
+            (Some(min), None) => self.size.max(min),
+            (None, Some(max)) => self.size.min(max),
+            (None, None) => self.size,
+        };
+
+        Settings {
+            size,
+            position: self
+                .position
+                .map(Position::Restored)
+                .unwrap_or(settings.position),
+            maximized: self.maximized,
+            fullscreen: self.mode == Mode::Fullscreen,
+            ..settings
+        }
+    }
+
+    /// Returns true if a window with the given position and size would be
+    /// visible in any of the given monitor bounds.
+    ///
+    /// The top edge of the window must be inside a monitor, so the title
+    /// bar of the window can be grabbed.
+    pub fn is_visible(
+        position: Point,
+        size: Size,
+        monitors: impl IntoIterator<Item = Rectangle>,
+    ) -> bool {
+        let window = Rectangle::new(position, size);
+
+        let min_width = Self::MIN_VISIBLE.min(size.width);
+        let min_height = Self::MIN_VISIBLE.min(size.height);
+
+        monitors.into_iter().any(|monitor| {
+            let top = position.y >= monitor.y
+                && position.y < monitor.y + monitor.height;
+
+            top && window.intersection(&monitor).is_some_and(|visible| {
+                visible.width >= min_width && visible.height >= min_height
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_visible() {
+        let monitors = [
+            Rectangle::new(Point::ORIGIN, Size::new(1920.0, 1080.0)),
+            Rectangle::new(Point::new(1920.0, 0.0), Size::new(1280.0, 720.0)),
+        ];
+
+        let size = Size::new(800.0, 600.0);
+        let is_visible =
+            |x, y| Snapshot::is_visible(Point::new(x, y), size, monitors);
+
+        assert!(is_visible(100.0, 100.0));
+        assert!(is_visible(2000.0, 100.0));
+        assert!(is_visible(-700.0, 100.0));
+
+        // Title bar above the monitors
+        assert!(!is_visible(100.0, -10.0));
+
+        // Monitor disconnected
+        assert!(!is_visible(3500.0, 100.0));
+
+        // Barely visible
+        assert!(!is_visible(-780.0, 100.0));
+        assert!(!is_visible(2000.0, 700.0));
+    }
+}
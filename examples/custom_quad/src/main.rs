@@ -68,7 +68,7 @@ mod quad {
                         width: self.border_width,
                         color: Color::from_rgb(1.0, 0.0, 0.0),
                     },
-                    shadow: self.shadow,
+                    shadows: self.shadow.into(),
                 },
                 Color::BLACK,
             );
@@ -118,6 +118,7 @@ impl Example {
                 color: Color::from_rgba(0.0, 0.0, 0.0, 0.8),
                 offset: Vector::new(0.0, 8.0),
                 blur_radius: 16.0,
+                inset: false,
             },
         }
     }
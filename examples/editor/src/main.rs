@@ -155,7 +155,7 @@ impl Editor {
                 .on_toggle(Message::WordWrapToggled),
             pick_list(
                 highlighter::Theme::ALL,
-                Some(self.theme),
+                Some(self.theme.clone()),
                 Message::ThemeSelected
             )
             .text_size(14)
@@ -201,7 +201,7 @@ impl Editor {
                         .and_then(Path::extension)
                         .and_then(ffi::OsStr::to_str)
                         .unwrap_or("rs"),
-                    self.theme,
+                    self.theme.clone(),
                 )
                 .key_binding(|key_press| {
                     match key_press.key.as_ref() {
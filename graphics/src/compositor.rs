@@ -1,5 +1,7 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::core::font::{self, Font};
+use crate::core::image;
 use crate::core::Color;
 use crate::futures::{MaybeSend, MaybeSync};
 use crate::{Error, Settings, Viewport};
@@ -63,11 +65,81 @@ pub trait Compositor: Sized {
     fn fetch_information(&self) -> Information;
 
     /// Loads a font from its bytes.
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .load_font(font)
+    }
+
+    /// Returns the names of all the available font families, sorted
+    /// alphabetically.
+    fn font_families(&self) -> Vec<String> {
+        crate::text::font_system()
+            .read()
+            .expect("Read font system")
+            .families()
+    }
+
+    /// Returns true if a font face with the exact family, weight, stretch
+    /// and style of the given [`Font`] is available.
+    fn is_font_available(&self, font: Font) -> bool {
+        crate::text::font_system()
+            .read()
+            .expect("Read font system")
+            .is_available(font)
+    }
+
+    /// Sets the font family used for the given generic [`font::Family`].
+    fn set_font_family(&mut self, generic: font::Family, name: String) {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .set_family(generic, name);
+    }
+
+    /// Sets the chain of font families used to render text in the given
+    /// [`font::Script`].
+    fn set_font_fallback(
+        &mut self,
+        script: font::Script,
+        families: Vec<&'static str>,
+    ) {
         crate::text::font_system()
             .write()
             .expect("Write to font system")
-            .load_font(font);
+            .set_fallback(script, families);
+    }
+
+    /// Sets whether the fonts installed in the system can be used.
+    fn use_system_fonts(&mut self, enabled: bool) {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .use_system_fonts(enabled);
+    }
+
+    /// Evicts the image with the given [`Handle`] from the caches of the
+    /// [`Renderer`].
+    ///
+    /// [`Handle`]: image::Handle
+    /// [`Renderer`]: Self::Renderer
+    fn evict_image(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _handle: &image::Handle,
+    ) {
+    }
+
+    /// Returns the [`Metrics`] of the image caches of the [`Renderer`].
+    ///
+    /// [`Metrics`]: image::Metrics
+    /// [`Renderer`]: Self::Renderer
+    fn image_metrics(&self, _renderer: &Self::Renderer) -> image::Metrics {
+        image::Metrics::default()
     }
 
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
@@ -178,7 +250,12 @@ impl Compositor for () {
     ) {
     }
 
-    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+    fn load_font(
+        &mut self,
+        _font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Ok(())
+    }
 
     fn fetch_information(&self) -> Information {
         Information {
@@ -1,4 +1,5 @@
 //! Build and draw geometry.
+pub mod export;
 pub mod fill;
 pub mod frame;
 pub mod path;
@@ -14,7 +15,7 @@ pub use frame::Frame;
 pub use path::Path;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
-pub use text::Text;
+pub use text::{RichText, Text};
 
 pub use crate::core::{Image, Svg};
 pub use crate::gradient::{self, Gradient};
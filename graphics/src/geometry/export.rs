@@ -0,0 +1,558 @@
+//! Export geometry as vector files; like SVG or PDF.
+//!
+//! The [`Renderer`] of this module records the commands of any [`Frame`]
+//! into a [`Drawing`] instead of drawing them. It needs no window nor GPU.
+//! Any [`Drawing`] can then be serialized with [`svg`] or [`pdf`].
+//!
+//! [`Frame`]: crate::geometry::Frame
+//!
+//! # Example
+//! ```no_run
+//! use iced_graphics::geometry::export;
+//! use iced_graphics::geometry::{Frame, Path};
+//! use iced_graphics::core::{Color, Point, Size};
+//!
+//! let renderer = export::Renderer::new();
+//! let size = Size::new(200.0, 100.0);
+//!
+//! let mut frame = Frame::new(&renderer, size);
+//! frame.fill(&Path::circle(Point::new(50.0, 50.0), 40.0), Color::BLACK);
+//!
+//! let drawing = frame.into_geometry();
+//!
+//! std::fs::write("chart.svg", export::svg(size, [&drawing]))
+//!     .expect("Write SVG file");
+//! std::fs::write("chart.pdf", export::pdf(size, [&drawing]))
+//!     .expect("Write PDF file");
+//! ```
+mod pdf;
+mod svg;
+
+pub use pdf::pdf;
+pub use svg::svg;
+
+use crate::cache::{self, Cached};
+use crate::core::image;
+use crate::core::renderer;
+use crate::core::{
+    Background, Point, Radians, Rectangle, Size, Transformation, Vector,
+};
+use crate::geometry::{self, Fill, Image, LineCap, LineJoin, Stroke, Style};
+use crate::geometry::{Path, Svg, Text};
+use crate::mesh::{Indexed, TexturedVertex2D};
+
+use lyon_path::math::{Angle, Transform};
+use std::sync::Arc;
+
+/// A renderer that records geometry for exporting.
+///
+/// It only supports geometry; any other primitive is ignored.
+#[derive(Debug, Default)]
+pub struct Renderer {
+    drawings: Vec<Drawing>,
+}
+
+impl Renderer {
+    /// Creates a new [`Renderer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`Drawing`]s drawn with the [`Renderer`] so far.
+    pub fn drawings(&self) -> &[Drawing] {
+        &self.drawings
+    }
+
+    /// Turns the [`Renderer`] into the [`Drawing`]s drawn with it.
+    pub fn into_drawings(self) -> Vec<Drawing> {
+        self.drawings
+    }
+}
+
+impl crate::core::Renderer for Renderer {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, _transformation: Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn clear(&mut self) {
+        self.drawings.clear();
+    }
+
+    fn fill_quad(
+        &mut self,
+        _quad: renderer::Quad,
+        _background: impl Into<Background>,
+    ) {
+    }
+}
+
+impl geometry::Renderer for Renderer {
+    type Geometry = Drawing;
+    type Frame = Frame;
+
+    fn new_frame(&self, size: Size) -> Frame {
+        Frame::new(size)
+    }
+
+    fn draw_geometry(&mut self, drawing: Drawing) {
+        self.drawings.push(drawing);
+    }
+}
+
+/// The recorded commands of a [`Frame`].
+///
+/// [`Frame`]: crate::geometry::Frame
+#[derive(Debug, Clone)]
+pub struct Drawing {
+    size: Size,
+    commands: Arc<[Command]>,
+}
+
+impl Drawing {
+    /// Returns the [`Size`] of the frame of the [`Drawing`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl Cached for Drawing {
+    type Cache = Drawing;
+
+    fn load(cache: &Drawing) -> Self {
+        cache.clone()
+    }
+
+    fn cache(
+        self,
+        _group: cache::Group,
+        _previous: Option<Drawing>,
+    ) -> Drawing {
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    Fill {
+        path: lyon_path::Path,
+        fill: Fill,
+        transform: Transform,
+    },
+    Stroke {
+        path: lyon_path::Path,
+        stroke: Stroking,
+        transform: Transform,
+    },
+    Text {
+        text: Text,
+        transform: Transform,
+    },
+    Image {
+        image: image::Image,
+        bounds: Rectangle,
+        transform: Transform,
+    },
+    Svg {
+        svg: Svg,
+        bounds: Rectangle,
+        transform: Transform,
+    },
+    Mesh {
+        buffers: Indexed<TexturedVertex2D>,
+        image: image::Image,
+        transform: Transform,
+    },
+    Clip {
+        bounds: Rectangle,
+        commands: Vec<Command>,
+    },
+}
+
+/// An owned [`Stroke`].
+#[derive(Debug, Clone)]
+struct Stroking {
+    style: Style,
+    width: f32,
+    line_cap: LineCap,
+    line_join: LineJoin,
+    dash: Vec<f32>,
+    dash_offset: f32,
+}
+
+impl From<Stroke<'_>> for Stroking {
+    fn from(stroke: Stroke<'_>) -> Self {
+        Self {
+            style: stroke.style,
+            width: stroke.width,
+            line_cap: stroke.line_cap,
+            line_join: stroke.line_join,
+            dash: stroke.line_dash.segments.to_vec(),
+            dash_offset: stroke.line_dash.offset as f32,
+        }
+    }
+}
+
+/// A [`Frame`] that records its commands into a [`Drawing`].
+///
+/// [`Frame`]: crate::geometry::Frame
+#[derive(Debug)]
+pub struct Frame {
+    clip_bounds: Rectangle,
+    transform: Transform,
+    stack: Vec<Transform>,
+    commands: Vec<Command>,
+}
+
+impl Frame {
+    /// Creates a new [`Frame`] with the given [`Size`].
+    pub fn new(size: Size) -> Self {
+        Self::with_clip(Rectangle::with_size(size))
+    }
+
+    fn with_clip(clip_bounds: Rectangle) -> Self {
+        Self {
+            clip_bounds,
+            transform: Transform::translation(clip_bounds.x, clip_bounds.y),
+            stack: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl geometry::frame::Backend for Frame {
+    type Geometry = Drawing;
+
+    fn width(&self) -> f32 {
+        self.clip_bounds.width
+    }
+
+    fn height(&self) -> f32 {
+        self.clip_bounds.height
+    }
+
+    fn size(&self) -> Size {
+        self.clip_bounds.size()
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.clip_bounds.width / 2.0, self.clip_bounds.height / 2.0)
+    }
+
+    fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = self.transform.pre_translate(lyon_path::math::vector(
+            translation.x,
+            translation.y,
+        ));
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform =
+            self.transform.pre_rotate(Angle::radians(angle.into().0));
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+
+        self.scale_nonuniform(Vector { x: scale, y: scale });
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        self.transform = self.transform.pre_scale(scale.x, scale.y);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        Self::with_clip(clip_bounds)
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.commands.push(Command::Clip {
+            bounds: frame.clip_bounds,
+            commands: frame.commands,
+        });
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        self.commands.push(Command::Stroke {
+            path: path.raw().clone(),
+            stroke: stroke.into().into(),
+            transform: self.transform,
+        });
+    }
+
+    fn stroke_rectangle<'a>(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        stroke: impl Into<Stroke<'a>>,
+    ) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        self.commands.push(Command::Fill {
+            path: path.raw().clone(),
+            fill: fill.into(),
+            transform: self.transform,
+        });
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        self.commands.push(Command::Text {
+            text: text.into(),
+            transform: self.transform,
+        });
+    }
+
+    fn fill_rectangle(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        fill: impl Into<Fill>,
+    ) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
+        self.commands.push(Command::Image {
+            image: image.into(),
+            bounds,
+            transform: self.transform,
+        });
+    }
+
+    fn draw_textured_mesh(
+        &mut self,
+        buffers: Indexed<TexturedVertex2D>,
+        image: impl Into<Image>,
+    ) {
+        self.commands.push(Command::Mesh {
+            buffers,
+            image: image.into(),
+            transform: self.transform,
+        });
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        self.commands.push(Command::Svg {
+            svg: svg.into(),
+            bounds,
+            transform: self.transform,
+        });
+    }
+
+    fn into_geometry(self) -> Drawing {
+        Drawing {
+            size: self.clip_bounds.size(),
+            commands: Arc::from(self.commands),
+        }
+    }
+}
+
+/// Returns the [`Transform`] rotating the given bounds on their center.
+fn rotation(bounds: Rectangle, rotation: Radians) -> Transform {
+    let center = bounds.center();
+
+    Transform::translation(-center.x, -center.y)
+        .then_rotate(Angle::radians(rotation.0))
+        .then_translate(lyon_path::math::vector(center.x, center.y))
+}
+
+/// Returns the region of the given image to draw, in pixels.
+fn source(image: &image::Image, width: u32, height: u32) -> Rectangle {
+    let source = image.source.unwrap_or(Rectangle {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    });
+
+    Rectangle {
+        x: source.x as f32,
+        y: source.y as f32,
+        width: source.width as f32,
+        height: source.height as f32,
+    }
+}
+
+/// Returns the [`Transform`] mapping the given source region of an image
+/// onto the given bounds.
+fn sprite(source: Rectangle, bounds: Rectangle) -> Transform {
+    Transform::translation(-source.x, -source.y)
+        .then_scale(bounds.width / source.width, bounds.height / source.height)
+        .then_translate(lyon_path::math::vector(bounds.x, bounds.y))
+}
+
+/// Returns the triangles of a textured mesh, along with the [`Transform`]
+/// mapping the pixels of its image onto each of them.
+///
+/// Triangles with degenerate texture coordinates are skipped.
+fn triangles(
+    buffers: &Indexed<TexturedVertex2D>,
+    source: Rectangle,
+) -> impl Iterator<Item = ([Point; 3], Transform)> + '_ {
+    let affine = |[a, b, c]: [Point; 3]| {
+        Transform::new(b.x - a.x, b.y - a.y, c.x - a.x, c.y - a.y, a.x, a.y)
+    };
+
+    buffers.indices.chunks_exact(3).filter_map(move |triangle| {
+        let vertex = |index: u32| buffers.vertices.get(index as usize);
+
+        let vertices = [
+            vertex(triangle[0])?,
+            vertex(triangle[1])?,
+            vertex(triangle[2])?,
+        ];
+
+        let positions = vertices
+            .map(|vertex| Point::new(vertex.position[0], vertex.position[1]));
+
+        let texels = vertices.map(|vertex| {
+            Point::new(
+                source.x + vertex.uv[0] * source.width,
+                source.y + vertex.uv[1] * source.height,
+            )
+        });
+
+        Some((
+            positions,
+            affine(texels).inverse()?.then(&affine(positions)),
+        ))
+    })
+}
+
+/// Returns the width and height of the given encoded image.
+///
+/// This is only possible if the `image` feature is enabled.
+fn dimensions(_bytes: &[u8]) -> Option<(u32, u32)> {
+    #[cfg(feature = "image")]
+    {
+        ::image::io::Reader::new(std::io::Cursor::new(_bytes))
+            .with_guessed_format()
+            .ok()?
+            .into_dimensions()
+            .ok()
+    }
+
+    #[cfg(not(feature = "image"))]
+    None
+}
+
+/// Returns the encoded bytes of the given image [`Handle`] and their MIME
+/// type, if known.
+///
+/// [`Handle`]: image::Handle
+fn encoded_image(handle: &image::Handle) -> Option<(Vec<u8>, &'static str)> {
+    let bytes = match handle {
+        image::Handle::Path(_, path) => std::fs::read(path).ok()?,
+        image::Handle::Bytes(_, bytes) => bytes.to_vec(),
+        #[cfg(feature = "image")]
+        image::Handle::Rgba {
+            width,
+            height,
+            pixels,
+            ..
+        } => {
+            let image =
+                ::image::RgbaImage::from_raw(*width, *height, pixels.to_vec())?;
+
+            let mut png = std::io::Cursor::new(Vec::new());
+
+            image.write_to(&mut png, ::image::ImageFormat::Png).ok()?;
+
+            return Some((png.into_inner(), "image/png"));
+        }
+        #[cfg(not(feature = "image"))]
+        image::Handle::Rgba { .. } => return None,
+    };
+
+    let mime = match bytes.as_slice() {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+            "image/webp"
+        }
+        [b'B', b'M', ..] => "image/bmp",
+        _ => return None,
+    };
+
+    Some((bytes, mime))
+}
+
+/// Returns the decoded RGBA pixels of the given image [`Handle`], along
+/// with its width and height.
+///
+/// Encoded images can only be decoded if the `image` feature is enabled.
+///
+/// [`Handle`]: image::Handle
+fn decoded_image(handle: &image::Handle) -> Option<(u32, u32, image::Bytes)> {
+    match handle {
+        image::Handle::Rgba {
+            width,
+            height,
+            pixels,
+            ..
+        } => Some((*width, *height, pixels.clone())),
+        #[cfg(feature = "image")]
+        _ => {
+            let image = crate::image::load(handle).ok()?;
+
+            Some((image.width(), image.height(), image.into_raw()))
+        }
+        #[cfg(not(feature = "image"))]
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangles_map_texels_to_positions() {
+        let buffers = Indexed {
+            vertices: vec![
+                TexturedVertex2D {
+                    position: [10.0, 10.0],
+                    uv: [0.0, 0.0],
+                },
+                TexturedVertex2D {
+                    position: [50.0, 10.0],
+                    uv: [1.0, 0.0],
+                },
+                TexturedVertex2D {
+                    position: [10.0, 30.0],
+                    uv: [0.0, 1.0],
+                },
+            ],
+            indices: vec![0, 1, 2, 0, 1, 7],
+        };
+
+        let source = Rectangle::new(Point::new(8.0, 4.0), Size::new(4.0, 2.0));
+        let triangles: Vec<_> = triangles(&buffers, source).collect();
+
+        assert_eq!(triangles.len(), 1);
+
+        let (positions, transform) = triangles[0];
+        let texel =
+            transform.transform_point(lyon_path::math::point(12.0, 6.0));
+
+        assert_eq!(positions[1], Point::new(50.0, 10.0));
+        assert!((texel.x - 50.0).abs() < 1e-4);
+        assert!((texel.y - 30.0).abs() < 1e-4);
+    }
+}
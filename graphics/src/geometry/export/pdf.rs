@@ -0,0 +1,584 @@
+use super::{decoded_image, rotation, source, sprite, triangles};
+use super::{Command, Drawing, Stroking};
+
+use crate::core::{Color, Rectangle, Size};
+use crate::geometry::fill;
+use crate::geometry::gradient::Linear;
+use crate::geometry::{Gradient, LineCap, LineJoin, Style};
+
+use lyon_path::math::Transform;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Serializes the given [`Drawing`]s as a single-page PDF document of the
+/// given [`Size`].
+///
+/// The [`Drawing`]s are stacked in order. Text is converted to outlines;
+/// so the document does not depend on any fonts.
+///
+/// Embedded SVGs are not supported and encoded images can only be exported
+/// if the `image` feature is enabled. Gradients ignore the alpha channel
+/// of their color stops.
+pub fn pdf<'a>(
+    size: Size,
+    drawings: impl IntoIterator<Item = &'a Drawing>,
+) -> Vec<u8> {
+    // The catalog, the page tree, the page, and its content are the first
+    // objects; they are written once all the resources are known
+    let mut writer = Writer {
+        objects: vec![Vec::new(); 4],
+        content: String::new(),
+        height: size.height,
+        opacities: BTreeMap::new(),
+        patterns: Vec::new(),
+        images: Vec::new(),
+    };
+
+    // Flip the Y axis; PDF coordinates start at the bottom-left corner
+    let _ = writeln!(writer.content, "1 0 0 -1 0 {} cm", size.height);
+
+    for drawing in drawings {
+        for command in drawing.commands.iter() {
+            writer.command(command);
+        }
+    }
+
+    writer.finish(size)
+}
+
+struct Writer {
+    objects: Vec<Vec<u8>>,
+    content: String,
+    height: f32,
+    opacities: BTreeMap<u32, usize>,
+    patterns: Vec<usize>,
+    images: Vec<usize>,
+}
+
+impl Writer {
+    fn command(&mut self, command: &Command) {
+        match command {
+            Command::Fill {
+                path,
+                fill,
+                transform,
+            } => {
+                self.content.push_str("q\n");
+                self.paint(fill.style, transform, false);
+                self.transform(transform);
+                self.path(path);
+
+                self.content.push_str(match fill.rule {
+                    fill::Rule::NonZero => "f\n",
+                    fill::Rule::EvenOdd => "f*\n",
+                });
+
+                self.content.push_str("Q\n");
+            }
+            Command::Stroke {
+                path,
+                stroke,
+                transform,
+            } => {
+                self.content.push_str("q\n");
+                self.paint(stroke.style, transform, true);
+                self.transform(transform);
+                self.stroke(stroke);
+                self.path(path);
+                self.content.push_str("S\nQ\n");
+            }
+            Command::Text { text, transform } => {
+                text.draw_with(|path, color| {
+                    self.command(&Command::Fill {
+                        path: path.raw().clone(),
+                        fill: color.into(),
+                        transform: *transform,
+                    });
+                });
+            }
+            Command::Image {
+                image,
+                bounds,
+                transform,
+            } => {
+                let Some((width, height, pixels)) =
+                    decoded_image(&image.handle)
+                else {
+                    log::warn!(
+                        "Image could not be exported: {:?}",
+                        image.handle
+                    );
+                    return;
+                };
+
+                let name = self.image(width, height, &pixels);
+
+                self.content.push_str("q\n");
+                self.opacity(image.opacity);
+                self.transform(
+                    &rotation(*bounds, image.rotation).then(transform),
+                );
+
+                if image.source.is_none() {
+                    self.draw(&name, *bounds);
+                } else {
+                    // Draw the whole image and clip it to the source region
+                    let _ = writeln!(
+                        self.content,
+                        "{} {} {} {} re W n",
+                        bounds.x, bounds.y, bounds.width, bounds.height,
+                    );
+
+                    self.transform(&sprite(
+                        source(image, width, height),
+                        *bounds,
+                    ));
+
+                    self.draw(&name, extent(width, height));
+                }
+
+                self.content.push_str("Q\n");
+            }
+            Command::Mesh {
+                buffers,
+                image,
+                transform,
+            } => {
+                let Some((width, height, pixels)) =
+                    decoded_image(&image.handle)
+                else {
+                    log::warn!(
+                        "Textured mesh could not be exported: {:?}",
+                        image.handle
+                    );
+                    return;
+                };
+
+                let name = self.image(width, height, &pixels);
+
+                self.content.push_str("q\n");
+                self.opacity(image.opacity);
+
+                for ([a, b, c], texture) in
+                    triangles(buffers, source(image, width, height))
+                {
+                    self.content.push_str("q\n");
+                    self.transform(transform);
+
+                    let _ = writeln!(
+                        self.content,
+                        "{} {} m {} {} l {} {} l h W n",
+                        a.x, a.y, b.x, b.y, c.x, c.y
+                    );
+
+                    self.transform(&texture);
+                    self.draw(&name, extent(width, height));
+                    self.content.push_str("Q\n");
+                }
+
+                self.content.push_str("Q\n");
+            }
+            Command::Svg { svg, .. } => {
+                log::warn!(
+                    "SVG could not be exported to PDF: {:?}",
+                    svg.handle.data()
+                );
+            }
+            Command::Clip { bounds, commands } => {
+                let _ = writeln!(
+                    self.content,
+                    "q\n{} {} {} {} re W n",
+                    bounds.x, bounds.y, bounds.width, bounds.height,
+                );
+
+                for command in commands {
+                    self.command(command);
+                }
+
+                self.content.push_str("Q\n");
+            }
+        }
+    }
+
+    fn paint(&mut self, style: Style, transform: &Transform, stroke: bool) {
+        match style {
+            Style::Solid(color) => {
+                self.opacity(color.a);
+
+                let _ = writeln!(
+                    self.content,
+                    "{} {} {} {}",
+                    color.r,
+                    color.g,
+                    color.b,
+                    if stroke { "RG" } else { "rg" }
+                );
+            }
+            Style::Gradient(Gradient::Linear(linear)) => {
+                let name = self.pattern(&linear, transform);
+
+                let _ = writeln!(
+                    self.content,
+                    "/Pattern {} /{name} {}",
+                    if stroke { "CS" } else { "cs" },
+                    if stroke { "SCN" } else { "scn" },
+                );
+            }
+        }
+    }
+
+    fn opacity(&mut self, opacity: f32) {
+        if opacity >= 1.0 {
+            return;
+        }
+
+        let key = opacity.max(0.0).to_bits();
+
+        let object = match self.opacities.get(&key) {
+            Some(object) => *object,
+            None => {
+                let object = self.add(format!(
+                    "<< /Type /ExtGState /ca {opacity} /CA {opacity} >>"
+                ));
+
+                let _ = self.opacities.insert(key, object);
+
+                object
+            }
+        };
+
+        let _ = writeln!(self.content, "/GS{object} gs");
+    }
+
+    fn transform(&mut self, transform: &Transform) {
+        let _ = writeln!(
+            self.content,
+            "{} {} {} {} {} {} cm",
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32
+        );
+    }
+
+    /// Draws the image with the given name inside the given bounds.
+    fn draw(&mut self, name: &str, bounds: Rectangle) {
+        // Images are drawn in a unit square with a flipped Y axis
+        let _ = writeln!(
+            self.content,
+            "{} 0 0 {} {} {} cm\n/{name} Do",
+            bounds.width,
+            -bounds.height,
+            bounds.x,
+            bounds.y + bounds.height,
+        );
+    }
+
+    fn stroke(&mut self, stroke: &Stroking) {
+        let _ = writeln!(
+            self.content,
+            "{} w {} J {} j",
+            stroke.width,
+            match stroke.line_cap {
+                LineCap::Butt => 0,
+                LineCap::Round => 1,
+                LineCap::Square => 2,
+            },
+            match stroke.line_join {
+                LineJoin::Miter => 0,
+                LineJoin::Round => 1,
+                LineJoin::Bevel => 2,
+            },
+        );
+
+        if !stroke.dash.is_empty() {
+            let dash: Vec<String> =
+                stroke.dash.iter().map(ToString::to_string).collect();
+
+            let _ = writeln!(
+                self.content,
+                "[{}] {} d",
+                dash.join(" "),
+                stroke.dash_offset
+            );
+        }
+    }
+
+    fn path(&mut self, path: &lyon_path::Path) {
+        for event in path.iter() {
+            let _ = match event {
+                lyon_path::Event::Begin { at } => {
+                    writeln!(self.content, "{} {} m", at.x, at.y)
+                }
+                lyon_path::Event::Line { to, .. } => {
+                    writeln!(self.content, "{} {} l", to.x, to.y)
+                }
+                lyon_path::Event::Quadratic { from, ctrl, to } => {
+                    let ctrl1 = from + (ctrl - from) * (2.0 / 3.0);
+                    let ctrl2 = to + (ctrl - to) * (2.0 / 3.0);
+
+                    writeln!(
+                        self.content,
+                        "{} {} {} {} {} {} c",
+                        ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                    )
+                }
+                lyon_path::Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => writeln!(
+                    self.content,
+                    "{} {} {} {} {} {} c",
+                    ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                ),
+                lyon_path::Event::End { close: true, .. } => {
+                    writeln!(self.content, "h")
+                }
+                lyon_path::Event::End { close: false, .. } => Ok(()),
+            };
+        }
+    }
+
+    fn pattern(&mut self, linear: &Linear, transform: &Transform) -> String {
+        let mut stops: Vec<(f32, Color)> = linear
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| (stop.offset, stop.color))
+            .collect();
+
+        match (stops.first().copied(), stops.last().copied()) {
+            (Some(first), Some(last)) => {
+                if first.0 > 0.0 {
+                    stops.insert(0, (0.0, first.1));
+                }
+
+                if last.0 < 1.0 {
+                    stops.push((1.0, last.1));
+                }
+            }
+            _ => {
+                stops = vec![(0.0, Color::BLACK), (1.0, Color::BLACK)];
+            }
+        }
+
+        let functions: Vec<String> = stops
+            .windows(2)
+            .map(|pair| {
+                let (a, b) = (pair[0].1, pair[1].1);
+
+                format!(
+                    "<< /FunctionType 2 /Domain [0 1] /C0 [{} {} {}] \
+                     /C1 [{} {} {}] /N 1 >>",
+                    a.r, a.g, a.b, b.r, b.g, b.b
+                )
+            })
+            .collect();
+
+        let bounds: Vec<String> = stops[1..stops.len() - 1]
+            .iter()
+            .map(|(offset, _)| offset.to_string())
+            .collect();
+
+        let encode = vec!["0 1"; functions.len()];
+
+        // Patterns live in the default space of the page; so they need the
+        // flip of the Y axis applied to the current transform
+        let object = self.add(format!(
+            "<< /PatternType 2 /Matrix [{} {} {} {} {} {}] \
+             /Shading << /ShadingType 2 /ColorSpace /DeviceRGB \
+             /Coords [{} {} {} {}] /Extend [true true] \
+             /Function << /FunctionType 3 /Domain [0 1] /Functions [{}] \
+             /Bounds [{}] /Encode [{}] >> >> >>",
+            transform.m11,
+            -transform.m12,
+            transform.m21,
+            -transform.m22,
+            transform.m31,
+            self.height - transform.m32,
+            linear.start.x,
+            linear.start.y,
+            linear.end.x,
+            linear.end.y,
+            functions.join(" "),
+            bounds.join(" "),
+            encode.join(" "),
+        ));
+
+        self.patterns.push(object);
+
+        format!("P{object}")
+    }
+
+    fn image(&mut self, width: u32, height: u32, pixels: &[u8]) -> String {
+        let (color, alpha): (Vec<[u8; 3]>, Vec<u8>) = pixels
+            .chunks_exact(4)
+            .map(|pixel| ([pixel[0], pixel[1], pixel[2]], pixel[3]))
+            .unzip();
+
+        let mask = self.add_stream(
+            format!(
+                "/Type /XObject /Subtype /Image /Width {width} \
+                 /Height {height} /ColorSpace /DeviceGray \
+                 /BitsPerComponent 8"
+            ),
+            &alpha,
+        );
+
+        let object = self.add_stream(
+            format!(
+                "/Type /XObject /Subtype /Image /Width {width} \
+                 /Height {height} /ColorSpace /DeviceRGB \
+                 /BitsPerComponent 8 /SMask {mask} 0 R"
+            ),
+            &color.concat(),
+        );
+
+        self.images.push(object);
+
+        format!("Im{object}")
+    }
+
+    fn add(&mut self, object: String) -> usize {
+        self.objects.push(object.into_bytes());
+        self.objects.len()
+    }
+
+    fn add_stream(&mut self, dictionary: String, data: &[u8]) -> usize {
+        let mut object =
+            format!("<< {dictionary} /Length {} >>\nstream\n", data.len())
+                .into_bytes();
+
+        object.extend_from_slice(data);
+        object.extend_from_slice(b"\nendstream");
+
+        self.objects.push(object);
+        self.objects.len()
+    }
+
+    fn finish(mut self, size: Size) -> Vec<u8> {
+        let graphics_states = resources("GS", self.opacities.values().copied());
+        let patterns = resources("P", self.patterns.iter().copied());
+        let images = resources("Im", self.images.iter().copied());
+
+        self.objects[0] = b"<< /Type /Catalog /Pages 2 0 R >>".to_vec();
+        self.objects[1] = b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec();
+        self.objects[2] = format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Contents 4 0 R /Resources << /ExtGState << {graphics_states} >> \
+             /Pattern << {patterns} >> /XObject << {images} >> >> >>",
+            size.width, size.height,
+        )
+        .into_bytes();
+
+        let content = std::mem::take(&mut self.content);
+        let mut stream =
+            format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+
+        stream.extend_from_slice(content.as_bytes());
+        stream.extend_from_slice(b"endstream");
+
+        self.objects[3] = stream;
+
+        let mut output = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(self.objects.len());
+
+        for (i, object) in self.objects.iter().enumerate() {
+            offsets.push(output.len());
+
+            output.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            output.extend_from_slice(object);
+            output.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = output.len();
+        let mut trailer =
+            format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+
+        for offset in offsets {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            self.objects.len() + 1
+        );
+
+        output.extend_from_slice(trailer.as_bytes());
+        output
+    }
+}
+
+fn extent(width: u32, height: u32) -> Rectangle {
+    Rectangle::with_size(Size::new(width as f32, height as f32))
+}
+
+fn resources(prefix: &str, objects: impl Iterator<Item = usize>) -> String {
+    objects
+        .map(|object| format!("/{prefix}{object} {object} 0 R"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Point;
+    use crate::geometry::export::Renderer;
+    use crate::geometry::{Frame, Path};
+
+    #[test]
+    fn cross_reference_offsets() {
+        let renderer = Renderer::new();
+        let size = Size::new(100.0, 50.0);
+
+        let mut frame = Frame::new(&renderer, size);
+
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            size,
+            Color::from_rgba(1.0, 0.0, 0.0, 0.5),
+        );
+        frame.fill(
+            &Path::circle(Point::new(50.0, 25.0), 10.0),
+            Linear::new(Point::ORIGIN, Point::new(100.0, 0.0))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        let pdf = pdf(size, [&frame.into_geometry()]);
+
+        let find = |needle: &str| {
+            pdf.windows(needle.len())
+                .position(|window| window == needle.as_bytes())
+        };
+
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(find("/MediaBox [0 0 100 50]").is_some());
+        assert!(find("/ca 0.5").is_some());
+        assert!(find("/PatternType 2").is_some());
+        assert!(pdf.ends_with(b"%%EOF\n"));
+
+        let xref = find("\nxref\n").expect("Cross-reference table") + 1;
+        let trailer = std::str::from_utf8(&pdf[xref..]).expect("Trailer");
+
+        let startxref: usize = trailer
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .expect("Cross-reference offset");
+
+        assert_eq!(startxref, xref);
+
+        for (i, entry) in trailer.lines().skip(3).take(5).enumerate() {
+            let offset: usize = entry[..10].parse().expect("Object offset");
+
+            assert!(pdf[offset..]
+                .starts_with(format!("{} 0 obj", i + 1).as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use super::{dimensions, encoded_image, rotation, source, sprite, triangles};
+use super::{Command, Drawing, Stroking};
+
+use crate::core::alignment;
+use crate::core::font::{self, Font};
+use crate::core::image;
+use crate::core::svg;
+use crate::core::{Color, Point, Rectangle, Size};
+use crate::geometry::fill;
+use crate::geometry::{Gradient, LineCap, LineJoin, Style, Text};
+
+use lyon_path::math::Transform;
+use std::fmt::Write;
+
+/// Serializes the given [`Drawing`]s as an SVG document of the given
+/// [`Size`].
+///
+/// The [`Drawing`]s are stacked in order. Text is kept as text; so the
+/// fonts it uses must be available wherever the document is displayed.
+pub fn svg<'a>(
+    size: Size,
+    drawings: impl IntoIterator<Item = &'a Drawing>,
+) -> String {
+    let mut writer = Writer {
+        output: String::new(),
+        definitions: 0,
+    };
+
+    let _ = writeln!(
+        writer.output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+         width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">",
+        width = size.width,
+        height = size.height,
+    );
+
+    for drawing in drawings {
+        for command in drawing.commands.iter() {
+            writer.command(command);
+        }
+    }
+
+    writer.output.push_str("</svg>\n");
+    writer.output
+}
+
+struct Writer {
+    output: String,
+    definitions: usize,
+}
+
+impl Writer {
+    fn command(&mut self, command: &Command) {
+        match command {
+            Command::Fill {
+                path,
+                fill,
+                transform,
+            } => {
+                let paint = self.paint(fill.style);
+
+                let _ = writeln!(
+                    self.output,
+                    "<path d=\"{data}\" {paint}fill-rule=\"{rule}\" \
+                     transform=\"{transform}\"/>",
+                    data = path_data(path),
+                    paint = paint.attributes("fill"),
+                    rule = match fill.rule {
+                        fill::Rule::NonZero => "nonzero",
+                        fill::Rule::EvenOdd => "evenodd",
+                    },
+                    transform = matrix(transform),
+                );
+            }
+            Command::Stroke {
+                path,
+                stroke,
+                transform,
+            } => {
+                let paint = self.paint(stroke.style);
+
+                let _ = writeln!(
+                    self.output,
+                    "<path d=\"{data}\" fill=\"none\" {paint}{attributes}\
+                     transform=\"{transform}\"/>",
+                    data = path_data(path),
+                    paint = paint.attributes("stroke"),
+                    attributes = stroke_attributes(stroke),
+                    transform = matrix(transform),
+                );
+            }
+            Command::Text { text, transform } => {
+                self.text(text, transform);
+            }
+            Command::Image {
+                image,
+                bounds,
+                transform,
+            } => {
+                let Some((bytes, mime)) = encoded_image(&image.handle) else {
+                    log::warn!(
+                        "Image could not be exported: {:?}",
+                        image.handle
+                    );
+                    return;
+                };
+
+                let transform =
+                    rotation(*bounds, image.rotation).then(transform);
+
+                if image.source.is_none() {
+                    self.image(
+                        *bounds,
+                        &transform,
+                        image.opacity,
+                        rendering(image.filter_method),
+                        mime,
+                        &bytes,
+                    );
+
+                    return;
+                }
+
+                let Some((width, height)) = dimensions(&bytes) else {
+                    log::warn!(
+                        "Image source could not be exported: {:?}",
+                        image.handle
+                    );
+                    return;
+                };
+
+                // Draw the whole image and clip it to the source region
+                self.clip(
+                    &format!(
+                        "M{x} {y}h{width}v{height}h{}Z",
+                        -bounds.width,
+                        x = bounds.x,
+                        y = bounds.y,
+                        width = bounds.width,
+                        height = bounds.height,
+                    ),
+                    &transform,
+                );
+
+                self.image(
+                    Rectangle::new(
+                        Point::ORIGIN,
+                        Size::new(width as f32, height as f32),
+                    ),
+                    &sprite(source(image, width, height), *bounds)
+                        .then(&transform),
+                    image.opacity,
+                    rendering(image.filter_method),
+                    mime,
+                    &bytes,
+                );
+
+                self.output.push_str("</g>\n");
+            }
+            Command::Mesh {
+                buffers,
+                image,
+                transform,
+            } => {
+                let Some((width, height, bytes, mime)) =
+                    encoded_image(&image.handle).and_then(|(bytes, mime)| {
+                        let (width, height) = dimensions(&bytes)?;
+
+                        Some((width, height, bytes, mime))
+                    })
+                else {
+                    log::warn!(
+                        "Textured mesh could not be exported: {:?}",
+                        image.handle
+                    );
+                    return;
+                };
+
+                let id = self.definition();
+
+                let _ = writeln!(
+                    self.output,
+                    "<defs><image id=\"d{id}\" width=\"{width}\" \
+                     height=\"{height}\" preserveAspectRatio=\"none\" \
+                     {rendering}xlink:href=\"data:{mime};base64,{data}\"/>\
+                     </defs>",
+                    rendering = rendering(image.filter_method),
+                    data = base64(&bytes),
+                );
+
+                for ([a, b, c], texture) in
+                    triangles(buffers, source(image, width, height))
+                {
+                    self.clip(
+                        &format!(
+                            "M{} {}L{} {}L{} {}Z",
+                            a.x, a.y, b.x, b.y, c.x, c.y
+                        ),
+                        transform,
+                    );
+
+                    let _ = writeln!(
+                        self.output,
+                        "<use xlink:href=\"#d{id}\" opacity=\"{opacity}\" \
+                         transform=\"{transform}\"/>\n</g>",
+                        opacity = image.opacity,
+                        transform = matrix(&texture.then(transform)),
+                    );
+                }
+            }
+            Command::Svg {
+                svg,
+                bounds,
+                transform,
+            } => {
+                let bytes = match svg.handle.data() {
+                    svg::Data::Path(path) => std::fs::read(path).ok(),
+                    svg::Data::Bytes(bytes) => Some(bytes.to_vec()),
+                };
+
+                let Some(bytes) = bytes else {
+                    log::warn!(
+                        "SVG could not be exported: {:?}",
+                        svg.handle.data()
+                    );
+                    return;
+                };
+
+                self.image(
+                    *bounds,
+                    &rotation(*bounds, svg.rotation).then(transform),
+                    svg.opacity,
+                    "",
+                    "image/svg+xml",
+                    &bytes,
+                );
+            }
+            Command::Clip { bounds, commands } => {
+                let id = self.definition();
+
+                let _ = writeln!(
+                    self.output,
+                    "<clipPath id=\"d{id}\"><rect x=\"{}\" y=\"{}\" \
+                     width=\"{}\" height=\"{}\"/></clipPath>\n\
+                     <g clip-path=\"url(#d{id})\">",
+                    bounds.x, bounds.y, bounds.width, bounds.height,
+                );
+
+                for command in commands {
+                    self.command(command);
+                }
+
+                self.output.push_str("</g>\n");
+            }
+        }
+    }
+
+    fn text(&mut self, text: &Text, transform: &Transform) {
+        let line_height = text.line_height.to_absolute(text.size);
+
+        // Mirror the baseline used by `Text::draw_with`
+        let top = match text.vertical_alignment {
+            alignment::Vertical::Top => text.position.y,
+            alignment::Vertical::Center => {
+                text.position.y - line_height.0 / 2.0
+            }
+            alignment::Vertical::Bottom => text.position.y - line_height.0,
+        };
+
+        let anchor = match text.horizontal_alignment {
+            alignment::Horizontal::Left => "start",
+            alignment::Horizontal::Center => "middle",
+            alignment::Horizontal::Right => "end",
+        };
+
+        let _ = writeln!(
+            self.output,
+            "<text x=\"{x}\" y=\"{y}\" font-size=\"{size}\" {font}\
+             text-anchor=\"{anchor}\" {paint}xml:space=\"preserve\" \
+             transform=\"{transform}\">{content}</text>",
+            x = text.position.x,
+            y = top + text.size.0,
+            size = text.size.0,
+            font = font_attributes(text.font),
+            paint = Paint::Color(text.color).attributes("fill"),
+            transform = matrix(transform),
+            content = escape(&text.content),
+        );
+    }
+
+    fn image(
+        &mut self,
+        bounds: Rectangle,
+        transform: &Transform,
+        opacity: f32,
+        rendering: &str,
+        mime: &str,
+        bytes: &[u8],
+    ) {
+        let _ = writeln!(
+            self.output,
+            "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+             preserveAspectRatio=\"none\" opacity=\"{opacity}\" {rendering}\
+             transform=\"{transform}\" \
+             xlink:href=\"data:{mime};base64,{data}\"/>",
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+            transform = matrix(transform),
+            data = base64(bytes),
+        );
+    }
+
+    /// Opens a group clipped by the given path data.
+    ///
+    /// The group must be closed afterwards.
+    fn clip(&mut self, data: &str, transform: &Transform) {
+        let id = self.definition();
+
+        let _ = writeln!(
+            self.output,
+            "<clipPath id=\"d{id}\"><path d=\"{data}\" \
+             transform=\"{transform}\"/></clipPath>\n\
+             <g clip-path=\"url(#d{id})\">",
+            transform = matrix(transform),
+        );
+    }
+
+    fn paint(&mut self, style: Style) -> Paint {
+        match style {
+            Style::Solid(color) => Paint::Color(color),
+            Style::Gradient(Gradient::Linear(linear)) => {
+                let id = self.definition();
+
+                let _ = writeln!(
+                    self.output,
+                    "<linearGradient id=\"d{id}\" \
+                     gradientUnits=\"userSpaceOnUse\" \
+                     x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\">",
+                    linear.start.x, linear.start.y, linear.end.x, linear.end.y,
+                );
+
+                for stop in linear.stops.iter().flatten() {
+                    let _ = writeln!(
+                        self.output,
+                        "<stop offset=\"{}\" stop-color=\"{}\" \
+                         stop-opacity=\"{}\"/>",
+                        stop.offset,
+                        rgb(stop.color),
+                        stop.color.a,
+                    );
+                }
+
+                self.output.push_str("</linearGradient>\n");
+
+                Paint::Reference(id)
+            }
+        }
+    }
+
+    fn definition(&mut self) -> usize {
+        self.definitions += 1;
+        self.definitions - 1
+    }
+}
+
+enum Paint {
+    Color(Color),
+    Reference(usize),
+}
+
+impl Paint {
+    fn attributes(&self, property: &str) -> String {
+        match self {
+            Paint::Color(color) => format!(
+                "{property}=\"{}\" {property}-opacity=\"{}\" ",
+                rgb(*color),
+                color.a
+            ),
+            Paint::Reference(id) => format!("{property}=\"url(#d{id})\" "),
+        }
+    }
+}
+
+fn path_data(path: &lyon_path::Path) -> String {
+    let mut data = String::new();
+
+    for event in path.iter() {
+        let _ = match event {
+            lyon_path::Event::Begin { at } => {
+                write!(data, "M{} {} ", at.x, at.y)
+            }
+            lyon_path::Event::Line { to, .. } => {
+                write!(data, "L{} {} ", to.x, to.y)
+            }
+            lyon_path::Event::Quadratic { ctrl, to, .. } => {
+                write!(data, "Q{} {} {} {} ", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            lyon_path::Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                data,
+                "C{} {} {} {} {} {} ",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            lyon_path::Event::End { close: true, .. } => {
+                write!(data, "Z ")
+            }
+            lyon_path::Event::End { close: false, .. } => Ok(()),
+        };
+    }
+
+    data.truncate(data.trim_end().len());
+    data
+}
+
+fn stroke_attributes(stroke: &Stroking) -> String {
+    let mut attributes = format!(
+        "stroke-width=\"{}\" stroke-linecap=\"{}\" stroke-linejoin=\"{}\" ",
+        stroke.width,
+        match stroke.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        },
+        match stroke.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        },
+    );
+
+    if !stroke.dash.is_empty() {
+        let dash: Vec<String> =
+            stroke.dash.iter().map(ToString::to_string).collect();
+
+        let _ = write!(
+            attributes,
+            "stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\" ",
+            dash.join(" "),
+            stroke.dash_offset
+        );
+    }
+
+    attributes
+}
+
+fn font_attributes(font: Font) -> String {
+    let family = match font.family {
+        font::Family::Name(name) => format!("'{}'", escape(name)),
+        font::Family::Serif => String::from("serif"),
+        font::Family::SansSerif => String::from("sans-serif"),
+        font::Family::Cursive => String::from("cursive"),
+        font::Family::Fantasy => String::from("fantasy"),
+        font::Family::Monospace => String::from("monospace"),
+    };
+
+    let weight = match font.weight {
+        font::Weight::Thin => 100,
+        font::Weight::ExtraLight => 200,
+        font::Weight::Light => 300,
+        font::Weight::Normal => 400,
+        font::Weight::Medium => 500,
+        font::Weight::Semibold => 600,
+        font::Weight::Bold => 700,
+        font::Weight::ExtraBold => 800,
+        font::Weight::Black => 900,
+    };
+
+    let style = match font.style {
+        font::Style::Normal => "normal",
+        font::Style::Italic => "italic",
+        font::Style::Oblique => "oblique",
+    };
+
+    format!(
+        "font-family=\"{family}\" font-weight=\"{weight}\" \
+         font-style=\"{style}\" "
+    )
+}
+
+fn rendering(filter_method: image::FilterMethod) -> &'static str {
+    match filter_method {
+        image::FilterMethod::Linear => "",
+        image::FilterMethod::Nearest => "image-rendering=\"pixelated\" ",
+    }
+}
+
+fn matrix(transform: &Transform) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        transform.m11,
+        transform.m12,
+        transform.m21,
+        transform.m22,
+        transform.m31,
+        transform.m32
+    )
+}
+
+fn rgb(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+
+    format!("rgb({r},{g},{b})")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+
+        let n =
+            (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded
+                    .push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Point, Size};
+    use crate::geometry::export::Renderer;
+    use crate::geometry::{Frame, LineDash, Path, Stroke};
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+    }
+
+    #[test]
+    fn frame_commands() {
+        let renderer = Renderer::new();
+        let size = Size::new(100.0, 50.0);
+
+        let mut frame = Frame::new(&renderer, size);
+
+        frame.fill_rectangle(Point::ORIGIN, size, Color::WHITE);
+
+        frame.with_clip(
+            Rectangle::new(Point::new(10.0, 10.0), size),
+            |frame| {
+                frame.stroke(
+                    &Path::line(Point::ORIGIN, Point::new(10.0, 0.0)),
+                    Stroke {
+                        line_dash: LineDash {
+                            segments: &[4.0, 2.0],
+                            offset: 1,
+                        },
+                        ..Stroke::default()
+                    },
+                );
+            },
+        );
+
+        frame.fill_text("<Total>");
+
+        let svg = svg(size, [&frame.into_geometry()]);
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("d=\"M0 0 L100 0 L100 50 L0 50 Z\""));
+        assert!(svg.contains("fill=\"rgb(255,255,255)\""));
+        assert!(svg.contains("clip-path=\"url(#d0)\""));
+        assert!(
+            svg.contains("stroke-dasharray=\"4 2\" stroke-dashoffset=\"1\"")
+        );
+        assert!(svg.contains("matrix(1 0 0 1 10 10)"));
+        assert!(svg.contains(">&lt;Total&gt;</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -1,6 +1,7 @@
 //! Draw and generate geometry.
 use crate::core::{Point, Radians, Rectangle, Size, Vector};
-use crate::geometry::{self, Fill, Image, Path, Stroke, Svg, Text};
+use crate::geometry::{self, Fill, Image, Path, RichText, Stroke, Svg, Text};
+use crate::mesh::{Indexed, TexturedVertex2D};
 
 /// The region of a surface that can be used to draw geometry.
 #[allow(missing_debug_implementations)]
@@ -86,12 +87,46 @@ where
         self.raw.fill_text(text);
     }
 
+    /// Draws the given [`RichText`] on the [`Frame`]; wrapping it within its
+    /// maximum width.
+    ///
+    /// The same warning of [`fill_text`](Self::fill_text) applies.
+    pub fn fill_rich_text<'a>(&mut self, text: impl Into<RichText<'a>>) {
+        text.into().draw(self);
+    }
+
+    /// Returns the [`Size`] that the given text would take once drawn on the
+    /// [`Frame`].
+    ///
+    /// This is useful to lay out text before drawing it; for instance, to
+    /// avoid overlapping labels.
+    pub fn measure_text<'a>(&self, text: impl Into<RichText<'a>>) -> Size {
+        text.into().measure()
+    }
+
     /// Draws the given [`Image`] on the [`Frame`] inside the given bounds.
     #[cfg(feature = "image")]
     pub fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
         self.raw.draw_image(bounds, image);
     }
 
+    /// Draws the given [`Image`] mapped onto a mesh of triangles.
+    ///
+    /// The position of each vertex is given in the coordinate system of the
+    /// [`Frame`], while its texture coordinates are normalized to the
+    /// image—or its source region, if any.
+    ///
+    /// This is useful to warp images, draw map tiles, or draw sprites of a
+    /// sprite sheet.
+    #[cfg(feature = "image")]
+    pub fn draw_textured_mesh(
+        &mut self,
+        buffers: Indexed<TexturedVertex2D>,
+        image: impl Into<Image>,
+    ) {
+        self.raw.draw_textured_mesh(buffers, image);
+    }
+
     /// Draws the given [`Svg`] on the [`Frame`] inside the given bounds.
     #[cfg(feature = "svg")]
     pub fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
@@ -228,6 +263,11 @@ pub trait Backend: Sized {
     );
 
     fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>);
+    fn draw_textured_mesh(
+        &mut self,
+        buffers: Indexed<TexturedVertex2D>,
+        image: impl Into<Image>,
+    );
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>);
 
     fn into_geometry(self) -> Self::Geometry;
@@ -284,6 +324,12 @@ impl Backend for () {
     }
 
     fn draw_image(&mut self, _bounds: Rectangle, _image: impl Into<Image>) {}
+    fn draw_textured_mesh(
+        &mut self,
+        _buffers: Indexed<TexturedVertex2D>,
+        _image: impl Into<Image>,
+    ) {
+    }
     fn draw_svg(&mut self, _bounds: Rectangle, _svg: impl Into<Svg>) {}
 
     fn into_geometry(self) -> Self::Geometry {}
@@ -2,15 +2,24 @@
 pub mod arc;
 
 mod builder;
+mod measurements;
 
 #[doc(no_inline)]
 pub use arc::Arc;
 pub use builder::Builder;
+pub use measurements::{Measurements, Sample};
 
 pub use lyon_path;
 
 use crate::core::border;
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size, Vector};
+use crate::geometry::fill;
+
+use lyon_path::iterator::PathIterator;
+
+/// The maximum distance between a curve and its approximation, used when
+/// measuring or hit testing a [`Path`].
+const TOLERANCE: f32 = 0.01;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -64,6 +73,122 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Parses a [`Path`] from the given [SVG path data]; like the `d`
+    /// attribute of a `<path>` element.
+    ///
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn from_svg_data(data: &str) -> Result<Self, ParseError> {
+        let mut builder = lyon_path::Path::builder();
+        let mut source = lyon_extra::parser::Source::new(data.chars());
+
+        lyon_extra::parser::PathParser::new()
+            .parse(
+                &lyon_extra::parser::ParserOptions::DEFAULT,
+                &mut source,
+                &mut builder,
+            )
+            .map_err(|error| ParseError {
+                reason: error.to_string(),
+            })?;
+
+        Ok(Self {
+            raw: builder.build(),
+        })
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the [`Path`]; including
+    /// the extrema of its curves.
+    pub fn bounds(&self) -> Rectangle {
+        if self.raw.iter().next().is_none() {
+            return Rectangle::default();
+        }
+
+        let bounds = lyon_algorithms::aabb::bounding_box(self.raw.iter());
+
+        Rectangle {
+            x: bounds.min.x,
+            y: bounds.min.y,
+            width: bounds.width(),
+            height: bounds.height(),
+        }
+    }
+
+    /// Returns the length of the [`Path`].
+    pub fn length(&self) -> f32 {
+        lyon_algorithms::length::approximate_length(self.raw.iter(), TOLERANCE)
+    }
+
+    /// Returns the [`Point`] of the [`Path`] at the given distance from its
+    /// start, if the [`Path`] is not empty.
+    ///
+    /// Use [`Path::measure`] to sample many points of the same [`Path`].
+    pub fn point_at_length(&self, distance: f32) -> Option<Point> {
+        self.measure()
+            .sample(distance)
+            .map(|sample| sample.position)
+    }
+
+    /// Returns the unit tangent [`Vector`] of the [`Path`] at the given
+    /// distance from its start, if the [`Path`] is not empty.
+    ///
+    /// Use [`Path::measure`] to sample many points of the same [`Path`].
+    pub fn tangent_at_length(&self, distance: f32) -> Option<Vector> {
+        self.measure().sample(distance).map(|sample| sample.tangent)
+    }
+
+    /// Measures the [`Path`]; producing some [`Measurements`] that can be
+    /// sampled efficiently.
+    ///
+    /// This is useful to animate something along a [`Path`].
+    pub fn measure(&self) -> Measurements {
+        Measurements::new(self.raw.clone(), TOLERANCE)
+    }
+
+    /// Returns true if the given [`Point`] is inside of the [`Path`] when
+    /// filled using the given [`fill::Rule`].
+    pub fn contains(&self, point: Point, rule: fill::Rule) -> bool {
+        lyon_algorithms::hit_test::hit_test_path(
+            &lyon_path::math::point(point.x, point.y),
+            self.raw.iter(),
+            match rule {
+                fill::Rule::NonZero => lyon_path::FillRule::NonZero,
+                fill::Rule::EvenOdd => lyon_path::FillRule::EvenOdd,
+            },
+            TOLERANCE,
+        )
+    }
+
+    /// Returns true if the given [`Point`] is on the stroke of the [`Path`]
+    /// with the given width, allowing the given extra distance of tolerance.
+    ///
+    /// The stroke is considered to have round joins and caps.
+    pub fn stroke_contains(
+        &self,
+        point: Point,
+        width: f32,
+        tolerance: f32,
+    ) -> bool {
+        let max_distance = width / 2.0 + tolerance;
+        let point = lyon_path::math::point(point.x, point.y);
+
+        self.raw.iter().flattened(TOLERANCE).any(|event| {
+            let (from, to) = match event {
+                lyon_path::Event::Line { from, to }
+                | lyon_path::Event::End {
+                    last: from,
+                    first: to,
+                    close: true,
+                } => (from, to),
+                lyon_path::Event::Begin { at } => (at, at),
+                _ => return false,
+            };
+
+            lyon_path::geom::LineSegment { from, to }
+                .square_distance_to_point(point)
+                <= max_distance * max_distance
+        })
+    }
+
     /// Returns the internal [`lyon_path::Path`].
     #[inline]
     pub fn raw(&self) -> &lyon_path::Path {
@@ -78,3 +203,45 @@ impl Path {
         }
     }
 }
+
+/// An error produced when parsing invalid [SVG path data].
+///
+/// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid SVG path data: {reason}")]
+pub struct ParseError {
+    reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_data_utilities() {
+        let square = Path::from_svg_data("M 0 0 h 10 v 10 H 0 Z")
+            .expect("Valid path data");
+
+        assert_eq!(
+            square.bounds(),
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0))
+        );
+        assert!((square.length() - 40.0).abs() < 0.01);
+
+        let point = square.point_at_length(15.0).expect("Non-empty path");
+        assert!(point.distance(Point::new(10.0, 5.0)) < 0.01);
+
+        let tangent = square.tangent_at_length(15.0).expect("Non-empty path");
+        assert!((tangent.y - 1.0).abs() < 0.01);
+
+        assert!(square.contains(Point::new(5.0, 5.0), fill::Rule::NonZero));
+        assert!(!square.contains(Point::new(15.0, 5.0), fill::Rule::EvenOdd));
+
+        assert!(square.stroke_contains(Point::new(5.0, 1.0), 2.0, 0.5));
+        assert!(square.stroke_contains(Point::new(0.5, 5.0), 0.0, 1.0));
+        assert!(!square.stroke_contains(Point::new(5.0, 5.0), 2.0, 0.5));
+
+        assert!(Path::from_svg_data("M 0 0 L 10").is_err());
+        assert_eq!(Path::from_svg_data("").unwrap().point_at_length(0.0), None);
+    }
+}
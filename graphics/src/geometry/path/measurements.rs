@@ -0,0 +1,68 @@
+use crate::core::{Point, Vector};
+
+use lyon_algorithms::measure::{PathMeasurements, SampleType};
+use std::fmt;
+use std::sync::Arc;
+
+/// The measurements of a [`Path`], which can be sampled efficiently.
+///
+/// [`Path`]: super::Path
+#[derive(Clone)]
+pub struct Measurements {
+    path: lyon_path::Path,
+    measurements: Arc<PathMeasurements>,
+}
+
+/// A sample of some [`Measurements`] at a specific distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The position of the [`Sample`].
+    pub position: Point,
+
+    /// The unit tangent of the path at the position of the [`Sample`].
+    pub tangent: Vector,
+}
+
+impl Measurements {
+    pub(super) fn new(path: lyon_path::Path, tolerance: f32) -> Self {
+        let measurements =
+            Arc::new(PathMeasurements::from_path(&path, tolerance));
+
+        Self { path, measurements }
+    }
+
+    /// Returns the total length of the measured path.
+    pub fn length(&self) -> f32 {
+        self.measurements.length()
+    }
+
+    /// Samples the measured path at the given distance from its start.
+    ///
+    /// The distance is clamped to the length of the path. Returns `None`
+    /// if the path is empty.
+    pub fn sample(&self, distance: f32) -> Option<Sample> {
+        let _ = self.path.first_endpoint()?;
+
+        let mut sampler = self
+            .measurements
+            .create_sampler(&self.path, SampleType::Distance);
+
+        let sample = sampler.sample(distance.clamp(0.0, self.length()));
+
+        let position = sample.position();
+        let tangent = sample.tangent();
+
+        Some(Sample {
+            position: Point::new(position.x, position.y),
+            tangent: Vector::new(tangent.x, tangent.y),
+        })
+    }
+}
+
+impl fmt::Debug for Measurements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Measurements")
+            .field("length", &self.length())
+            .finish_non_exhaustive()
+    }
+}
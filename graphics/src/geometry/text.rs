@@ -1,7 +1,9 @@
 use crate::core::alignment;
-use crate::core::text::{LineHeight, Shaping};
-use crate::core::{Color, Font, Pixels, Point, Size, Vector};
-use crate::geometry::Path;
+use crate::core::text::{LineHeight, Paragraph as _, Shaping, Span};
+use crate::core::{
+    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+};
+use crate::geometry::{Frame, Path, Renderer, Stroke};
 use crate::text;
 
 /// A bunch of text that can be drawn to a canvas
@@ -198,3 +200,331 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+/// Some rich text that can be drawn to a canvas.
+///
+/// It is made of [`Span`]s that can have their own font, color, size,
+/// highlight, and decorations. The text wraps when it exceeds
+/// its [`max_width`](Self::max_width).
+#[derive(Debug, Clone)]
+pub struct RichText<'a> {
+    /// The spans of the text.
+    pub spans: Vec<Span<'a>>,
+    /// The position of the text relative to the alignment properties.
+    ///
+    /// See [`Text::position`] for more details.
+    pub position: Point,
+    /// The maximum width of the text before wrapping.
+    ///
+    /// By default, it is infinite.
+    pub max_width: f32,
+    /// The default color of the spans.
+    pub color: Color,
+    /// The default size of the spans.
+    pub size: Pixels,
+    /// The default line height of the spans.
+    pub line_height: LineHeight,
+    /// The default font of the spans.
+    pub font: Font,
+    /// The horizontal alignment of the text.
+    pub horizontal_alignment: alignment::Horizontal,
+    /// The vertical alignment of the text.
+    pub vertical_alignment: alignment::Vertical,
+    /// The shaping strategy of the text.
+    pub shaping: Shaping,
+}
+
+impl<'a> RichText<'a> {
+    /// Creates some [`RichText`] with the given [`Span`]s.
+    pub fn new(spans: impl IntoIterator<Item = Span<'a>>) -> Self {
+        let text = Text::default();
+
+        Self {
+            spans: spans.into_iter().collect(),
+            position: text.position,
+            max_width: f32::INFINITY,
+            color: text.color,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the [`Size`] of the [`RichText`] once laid out.
+    pub fn measure(&self) -> Size {
+        self.layout().min_bounds()
+    }
+
+    fn layout(&self) -> text::Paragraph {
+        text::Paragraph::with_spans(crate::core::Text {
+            content: self.spans.as_slice(),
+            bounds: Size::new(self.max_width, f32::INFINITY),
+            size: self.size,
+            line_height: self.line_height,
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.shaping,
+            wrapping: crate::core::text::Wrapping::default(),
+            ellipsis: crate::core::text::Ellipsis::default(),
+            max_lines: None,
+        })
+    }
+
+    /// Draws the [`RichText`] on the given [`Frame`].
+    ///
+    /// Every run of glyphs of the same [`Span`] is drawn as [`Text`]; so it
+    /// benefits from the text rendering of the [`Renderer`].
+    pub(crate) fn draw<R: Renderer>(&self, frame: &mut Frame<R>) {
+        let paragraph = self.layout();
+        let fragments = self.fragments(&paragraph);
+
+        for fragment in &fragments {
+            let span = &self.spans[fragment.span];
+
+            let Some(highlight) = span.highlight else {
+                continue;
+            };
+
+            let bounds = fragment.bounds.expand(span.padding);
+
+            let path = Path::rounded_rectangle(
+                bounds.position(),
+                bounds.size(),
+                highlight.border.radius,
+            );
+
+            if let Background::Color(color) = highlight.background {
+                frame.fill(&path, color);
+            }
+
+            if highlight.border.width > 0.0 {
+                frame.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_width(highlight.border.width)
+                        .with_color(highlight.border.color),
+                );
+            }
+        }
+
+        for fragment in &fragments {
+            let span = &self.spans[fragment.span];
+            let size = span.size.unwrap_or(self.size);
+            let color = fragment.color.unwrap_or(self.color);
+
+            frame.fill_text(Text {
+                content: fragment.content.to_owned(),
+                position: fragment.bounds.position(),
+                color,
+                size,
+                line_height: LineHeight::Absolute(Pixels(
+                    fragment.bounds.height,
+                )),
+                font: span.font.unwrap_or(self.font),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: self.shaping,
+            });
+
+            for (line, decoration) in span.decorations() {
+                for segment in decoration.segments(line, fragment.bounds, size)
+                {
+                    frame.fill_rectangle(
+                        segment.position(),
+                        segment.size(),
+                        decoration.color.unwrap_or(color),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Splits the laid out [`RichText`] into runs of glyphs of the same
+    /// [`Span`], positioned according to its alignment.
+    fn fragments<'b>(
+        &self,
+        paragraph: &'b text::Paragraph,
+    ) -> Vec<Fragment<'b>> {
+        let bounds = paragraph.min_bounds();
+
+        let origin = Point::new(
+            match self.horizontal_alignment {
+                alignment::Horizontal::Left => self.position.x,
+                alignment::Horizontal::Center => {
+                    self.position.x - bounds.width / 2.0
+                }
+                alignment::Horizontal::Right => self.position.x - bounds.width,
+            },
+            match self.vertical_alignment {
+                alignment::Vertical::Top => self.position.y,
+                alignment::Vertical::Center => {
+                    self.position.y - bounds.height / 2.0
+                }
+                alignment::Vertical::Bottom => self.position.y - bounds.height,
+            },
+        ) - Point::ORIGIN;
+
+        let mut fragments = Vec::new();
+
+        for run in paragraph.buffer().layout_runs() {
+            let mut glyphs = run.glyphs.iter().peekable();
+
+            while let Some(first) = glyphs.next() {
+                let mut range = first.start..first.end;
+                let mut width = first.w;
+                let mut x = first.x;
+
+                while let Some(glyph) =
+                    glyphs.next_if(|glyph| glyph.metadata == first.metadata)
+                {
+                    range.start = range.start.min(glyph.start);
+                    range.end = range.end.max(glyph.end);
+                    width += glyph.w;
+                    x = x.min(glyph.x);
+                }
+
+                fragments.push(Fragment {
+                    span: first.metadata,
+                    content: &run.text[range],
+                    bounds: Rectangle::new(
+                        Point::new(x, run.line_top + first.y) + origin,
+                        Size::new(
+                            width,
+                            first.line_height_opt.unwrap_or(run.line_height),
+                        ),
+                    ),
+                    color: first.color_opt.map(|color| {
+                        let [r, g, b, a] = color.as_rgba();
+
+                        Color::from_rgba8(r, g, b, f32::from(a) / 255.0)
+                    }),
+                });
+            }
+        }
+
+        fragments
+    }
+}
+
+/// A run of glyphs of the same [`Span`].
+struct Fragment<'a> {
+    span: usize,
+    content: &'a str,
+    bounds: Rectangle,
+    color: Option<Color>,
+}
+
+impl From<Text> for RichText<'static> {
+    fn from(text: Text) -> Self {
+        Self {
+            spans: vec![Span::new(text.content)],
+            position: text.position,
+            max_width: f32::INFINITY,
+            color: text.color,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+        }
+    }
+}
+
+impl From<String> for RichText<'static> {
+    fn from(content: String) -> Self {
+        Text::from(content).into()
+    }
+}
+
+impl<'a> From<&'a str> for RichText<'a> {
+    fn from(content: &'a str) -> Self {
+        Self::new([Span::new(content)])
+    }
+}
+
+impl<'a> From<Vec<Span<'a>>> for RichText<'a> {
+    fn from(spans: Vec<Span<'a>>) -> Self {
+        Self::new(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rich_text() -> RichText<'static> {
+        RichText {
+            font: text::load_fira_sans(),
+            position: Point::new(10.0, 20.0),
+            ..RichText::new([
+                Span::new("Small ").underline(true),
+                Span::new("Large").size(48.0).strikethrough(true),
+            ])
+        }
+    }
+
+    #[test]
+    fn spans_are_measured_together() {
+        let text = rich_text();
+        let size = text.measure();
+
+        let small = RichText {
+            spans: vec![Span::new("Small ")],
+            ..text.clone()
+        }
+        .measure();
+
+        assert!(size.width > small.width);
+        assert!(size.height >= 48.0);
+
+        let wrapped = RichText {
+            max_width: size.width - 1.0,
+            ..text
+        }
+        .measure();
+
+        assert!(wrapped.width < size.width);
+        assert!(wrapped.height > size.height);
+    }
+
+    #[test]
+    fn decorations_stay_within_their_span() {
+        let text = rich_text();
+        let size = text.measure();
+        let bounds = Rectangle::new(text.position, size);
+
+        let paragraph = text.layout();
+        let fragments = text.fragments(&paragraph);
+
+        assert_eq!(fragments.len(), 2);
+        assert!(
+            fragments[0].bounds.x + fragments[0].bounds.width
+                <= fragments[1].bounds.x + 0.01
+        );
+
+        for fragment in &fragments {
+            let span = &text.spans[fragment.span];
+            let size = span.size.unwrap_or(text.size);
+
+            assert!(bounds.contains(fragment.bounds.position()));
+
+            for (line, decoration) in span.decorations() {
+                for segment in decoration.segments(line, fragment.bounds, size)
+                {
+                    assert_eq!(segment.x, fragment.bounds.x);
+                    assert_eq!(segment.width, fragment.bounds.width);
+                    assert!(segment.y >= fragment.bounds.y);
+                    assert!(
+                        segment.y + segment.height
+                            <= fragment.bounds.y + fragment.bounds.height
+                    );
+                }
+            }
+        }
+    }
+}
@@ -27,71 +27,83 @@ impl Image {
 }
 
 #[cfg(feature = "image")]
-/// Tries to load an image by its [`Handle`].
-///
-/// [`Handle`]: image::Handle
-pub fn load(
-    handle: &image::Handle,
-) -> ::image::ImageResult<::image::ImageBuffer<::image::Rgba<u8>, image::Bytes>>
-{
-    use bitflags::bitflags;
+bitflags::bitflags! {
+    /// The transformation described by the EXIF orientation of an image.
+    struct Operation: u8 {
+        const FLIP_HORIZONTALLY = 0b001;
+        const ROTATE_180 = 0b010;
+        const FLIP_DIAGONALLY = 0b100;
+    }
+}
 
-    bitflags! {
-        struct Operation: u8 {
-            const FLIP_HORIZONTALLY = 0b001;
-            const ROTATE_180 = 0b010;
-            const FLIP_DIAGONALLY = 0b100;
-        }
+#[cfg(feature = "image")]
+impl Operation {
+    // Meaning of the returned value is described e.g. at:
+    // https://magnushoff.com/articles/jpeg-orientation/
+    fn from_exif<R>(reader: &mut R) -> Result<Self, exif::Error>
+    where
+        R: std::io::BufRead + std::io::Seek,
+    {
+        let exif = exif::Reader::new().read_from_container(reader)?;
+
+        Ok(exif
+            .get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+            .and_then(|field| field.value.get_uint(0))
+            .and_then(|value| u8::try_from(value).ok())
+            .and_then(|value| Self::from_bits(value.saturating_sub(1)))
+            .unwrap_or_else(Self::empty))
     }
 
-    impl Operation {
-        // Meaning of the returned value is described e.g. at:
-        // https://magnushoff.com/articles/jpeg-orientation/
-        fn from_exif<R>(reader: &mut R) -> Result<Self, exif::Error>
-        where
-            R: std::io::BufRead + std::io::Seek,
-        {
-            let exif = exif::Reader::new().read_from_container(reader)?;
-
-            Ok(exif
-                .get_field(exif::Tag::Orientation, exif::In::PRIMARY)
-                .and_then(|field| field.value.get_uint(0))
-                .and_then(|value| u8::try_from(value).ok())
-                .and_then(|value| Self::from_bits(value.saturating_sub(1)))
-                .unwrap_or_else(Self::empty))
-        }
+    fn of_path(path: &std::path::Path) -> Self {
+        std::fs::File::open(path)
+            .ok()
+            .map(std::io::BufReader::new)
+            .and_then(|mut reader| Self::from_exif(&mut reader).ok())
+            .unwrap_or_else(Self::empty)
+    }
 
-        fn perform(
-            self,
-            mut image: ::image::DynamicImage,
-        ) -> ::image::DynamicImage {
-            use ::image::imageops;
+    fn of_bytes(bytes: &[u8]) -> Self {
+        Self::from_exif(&mut std::io::Cursor::new(bytes))
+            .ok()
+            .unwrap_or_else(Self::empty)
+    }
 
-            if self.contains(Self::FLIP_DIAGONALLY) {
-                imageops::flip_vertical_in_place(&mut image);
-            }
+    fn perform(
+        self,
+        mut image: ::image::DynamicImage,
+    ) -> ::image::DynamicImage {
+        use ::image::imageops;
 
-            if self.contains(Self::ROTATE_180) {
-                imageops::rotate180_in_place(&mut image);
-            }
+        if self.contains(Self::FLIP_DIAGONALLY) {
+            // A transposition, which swaps the dimensions of the image
+            image = image.rotate90().fliph();
+        }
 
-            if self.contains(Self::FLIP_HORIZONTALLY) {
-                imageops::flip_horizontal_in_place(&mut image);
-            }
+        if self.contains(Self::ROTATE_180) {
+            imageops::rotate180_in_place(&mut image);
+        }
 
-            image
+        if self.contains(Self::FLIP_HORIZONTALLY) {
+            imageops::flip_horizontal_in_place(&mut image);
         }
+
+        image
     }
+}
 
+#[cfg(feature = "image")]
+/// Tries to load an image by its [`Handle`].
+///
+/// [`Handle`]: image::Handle
+pub fn load(
+    handle: &image::Handle,
+) -> ::image::ImageResult<::image::ImageBuffer<::image::Rgba<u8>, image::Bytes>>
+{
     let (width, height, pixels) = match handle {
         image::Handle::Path(_, path) => {
             let image = ::image::open(path)?;
 
-            let operation = std::fs::File::open(path)
-                .ok()
-                .map(std::io::BufReader::new)
-                .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
-                .unwrap_or_else(Operation::empty);
+            let operation = Operation::of_path(path);
 
             let rgba = operation.perform(image).into_rgba8();
 
@@ -103,10 +115,7 @@ pub fn load(
         }
         image::Handle::Bytes(_, bytes) => {
             let image = ::image::load_from_memory(bytes)?;
-            let operation =
-                Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                    .ok()
-                    .unwrap_or_else(Operation::empty);
+            let operation = Operation::of_bytes(bytes);
 
             let rgba = operation.perform(image).into_rgba8();
 
@@ -134,3 +143,272 @@ pub fn load(
         ))
     }
 }
+
+#[cfg(feature = "image")]
+/// Reads the dimensions of an image by its [`Handle`], without decoding it.
+///
+/// Like [`load`], this takes the EXIF orientation of the image into account.
+///
+/// [`Handle`]: image::Handle
+pub fn dimensions(
+    handle: &image::Handle,
+) -> ::image::ImageResult<crate::core::Size<u32>> {
+    let ((width, height), operation) = match handle {
+        image::Handle::Path(_, path) => (
+            ::image::io::Reader::open(path)?
+                .with_guessed_format()?
+                .into_dimensions()?,
+            Operation::of_path(path),
+        ),
+        image::Handle::Bytes(_, bytes) => (
+            ::image::io::Reader::new(std::io::Cursor::new(bytes))
+                .with_guessed_format()?
+                .into_dimensions()?,
+            Operation::of_bytes(bytes),
+        ),
+        image::Handle::Rgba { width, height, .. } => {
+            ((*width, *height), Operation::empty())
+        }
+    };
+
+    if operation.contains(Operation::FLIP_DIAGONALLY) {
+        Ok(crate::core::Size::new(height, width))
+    } else {
+        Ok(crate::core::Size::new(width, height))
+    }
+}
+
+#[cfg(feature = "image")]
+/// The decoded pixels of an image, in RGBA.
+pub type Buffer = ::image::ImageBuffer<::image::Rgba<u8>, image::Bytes>;
+
+#[cfg(feature = "image")]
+/// Decodes images in a pool of background threads.
+#[derive(Debug)]
+pub struct Decoder {
+    sender: std::sync::mpsc::Sender<(image::Id, ::image::ImageResult<Buffer>)>,
+    receiver:
+        std::sync::mpsc::Receiver<(image::Id, ::image::ImageResult<Buffer>)>,
+}
+
+#[cfg(feature = "image")]
+impl Decoder {
+    /// Creates a new [`Decoder`].
+    pub fn new() -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        Self { sender, receiver }
+    }
+
+    /// Starts decoding the image of the given [`Handle`] in the background.
+    ///
+    /// The result will be produced by [`Decoder::poll`] once ready.
+    ///
+    /// [`Handle`]: image::Handle
+    pub fn decode(&self, handle: &image::Handle) {
+        let handle = handle.clone();
+        let sender = self.sender.clone();
+
+        spawn(move || {
+            let _ = sender.send((handle.id(), load(&handle)));
+
+            wake();
+        });
+    }
+
+    /// Returns the images decoded since the last call.
+    ///
+    /// The waker set with [`set_waker`] is called whenever there are new
+    /// results; so there is no need to poll continuously.
+    pub fn poll(
+        &self,
+    ) -> impl Iterator<Item = (image::Id, ::image::ImageResult<Buffer>)> + '_
+    {
+        self.receiver.try_iter()
+    }
+}
+
+#[cfg(feature = "image")]
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type Waker = std::sync::Arc<dyn Fn() + Send + Sync>;
+
+static WAKER: std::sync::RwLock<Option<Waker>> = std::sync::RwLock::new(None);
+
+/// Sets the function to call whenever an image finishes decoding in the
+/// background.
+///
+/// A shell can use it to wake up its event loop and redraw; instead of
+/// redrawing continuously while images are loading.
+pub fn set_waker(waker: impl Fn() + Send + Sync + 'static) {
+    *WAKER.write().expect("Write image waker") =
+        Some(std::sync::Arc::new(waker));
+}
+
+#[cfg(feature = "image")]
+fn wake() {
+    let waker = WAKER.read().expect("Read image waker").clone();
+
+    if let Some(waker) = waker {
+        waker();
+    }
+}
+
+/// Runs the given job in the shared pool of decoding threads.
+///
+/// The job runs immediately if threads are not available.
+#[cfg(feature = "image")]
+fn spawn(job: impl FnOnce() + Send + 'static) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::sync::{mpsc, OnceLock};
+        use std::thread;
+
+        type Job = Box<dyn FnOnce() + Send>;
+
+        static POOL: OnceLock<Option<mpsc::Sender<Job>>> = OnceLock::new();
+
+        let pool = POOL.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel::<Job>();
+            let receiver = std::sync::Arc::new(std::sync::Mutex::new(receiver));
+
+            let workers = thread::available_parallelism()
+                .map_or(1, std::num::NonZeroUsize::get)
+                .min(4);
+
+            let spawned = (0..workers)
+                .filter(|i| {
+                    let receiver = receiver.clone();
+
+                    thread::Builder::new()
+                        .name(format!("iced image decoder {i}"))
+                        .spawn(move || loop {
+                            let Ok(job) = receiver
+                                .lock()
+                                .expect("Lock image decoder queue")
+                                .recv()
+                            else {
+                                break;
+                            };
+
+                            job();
+                        })
+                        .is_ok()
+                })
+                .count();
+
+            (spawned > 0).then_some(sender)
+        });
+
+        if let Some(pool) = pool {
+            if let Err(mpsc::SendError(job)) = pool.send(Box::new(job)) {
+                job();
+            }
+
+            return;
+        }
+    }
+
+    job();
+}
+
+#[cfg(feature = "animated-image")]
+/// A frame of an animated image.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The [`Handle`] with the pixels of the frame.
+    ///
+    /// [`Handle`]: image::Handle
+    pub handle: image::Handle,
+
+    /// How long the frame should be displayed.
+    pub delay: std::time::Duration,
+}
+
+#[cfg(feature = "animated-image")]
+/// Tries to load all the frames of an animated image by its [`Handle`].
+///
+/// GIF, APNG and animated WebP images are supported. Any other image is
+/// loaded as a single frame.
+///
+/// Delays shorter than 10 milliseconds are considered to be 100 milliseconds
+/// long; like most browsers do.
+///
+/// [`Handle`]: image::Handle
+pub fn load_frames(handle: &image::Handle) -> ::image::ImageResult<Vec<Frame>> {
+    use ::image::codecs::{gif, png, webp};
+    use ::image::{AnimationDecoder, ImageFormat};
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    let single = |handle: &image::Handle| {
+        let image = load(handle)?;
+
+        Ok(vec![Frame {
+            handle: image::Handle::from_rgba(
+                image.width(),
+                image.height(),
+                image.into_raw(),
+            ),
+            delay: Duration::ZERO,
+        }])
+    };
+
+    let bytes = match handle {
+        image::Handle::Path(_, path) => {
+            image::Bytes::from(std::fs::read(path)?)
+        }
+        image::Handle::Bytes(_, bytes) => bytes.clone(),
+        image::Handle::Rgba { .. } => return single(handle),
+    };
+
+    let frames = match ::image::guess_format(&bytes)? {
+        ImageFormat::Gif => gif::GifDecoder::new(Cursor::new(&bytes))?
+            .into_frames()
+            .collect_frames()?,
+        ImageFormat::Png => {
+            let decoder = png::PngDecoder::new(Cursor::new(&bytes))?;
+
+            if !decoder.is_apng() {
+                return single(handle);
+            }
+
+            decoder.apng().into_frames().collect_frames()?
+        }
+        ImageFormat::WebP => {
+            let decoder = webp::WebPDecoder::new(Cursor::new(&bytes))?;
+
+            if !decoder.has_animation() {
+                return single(handle);
+            }
+
+            decoder.into_frames().collect_frames()?
+        }
+        _ => return single(handle),
+    };
+
+    Ok(frames
+        .into_iter()
+        .map(|frame| {
+            let delay = Duration::from(frame.delay());
+            let buffer = frame.into_buffer();
+
+            Frame {
+                handle: image::Handle::from_rgba(
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.into_raw(),
+                ),
+                delay: if delay < Duration::from_millis(10) {
+                    Duration::from_millis(100)
+                } else {
+                    delay
+                },
+            }
+        })
+        .collect())
+}
@@ -1,6 +1,9 @@
 //! Draw and stack layers of graphical primitives.
+use crate::core::renderer::Effect;
 use crate::core::{Rectangle, Transformation};
 
+use std::ops::Range;
+
 /// A layer of graphical primitives.
 ///
 /// Layers normally dictate a set of primitives that are
@@ -28,6 +31,8 @@ pub struct Stack<T: Layer> {
     layers: Vec<T>,
     transformations: Vec<Transformation>,
     previous: Vec<usize>,
+    groups: Vec<Group>,
+    open_groups: Vec<(usize, usize)>,
     current: usize,
     active_count: usize,
 }
@@ -39,6 +44,8 @@ impl<T: Layer> Stack<T> {
             layers: vec![T::default()],
             transformations: vec![Transformation::IDENTITY],
             previous: vec![],
+            groups: vec![],
+            open_groups: vec![],
             current: 0,
             active_count: 1,
         }
@@ -76,12 +83,60 @@ impl<T: Layer> Stack<T> {
         }
     }
 
+    /// Pushes a new clipping region in the [`Stack`] whose contents will be
+    /// composited together with the given [`Effect`].
+    ///
+    /// All the layers created until the region is popped will be part of the
+    /// same [`Group`].
+    pub fn push_clip_with_effect(&mut self, bounds: Rectangle, effect: Effect) {
+        if effect.is_none() {
+            self.push_clip(bounds);
+            return;
+        }
+
+        let _ = self.push_group(bounds, effect);
+    }
+
+    /// Pushes a new clipping region in the [`Stack`] whose contents will be
+    /// composited together in a new [`Group`]; even if the [`Effect`] does
+    /// not alter them.
+    ///
+    /// Returns the index of the new [`Group`].
+    pub fn push_group(&mut self, bounds: Rectangle, effect: Effect) -> usize {
+        self.push_clip(bounds);
+
+        let transformation = self.transformation();
+
+        self.open_groups
+            .push((self.previous.len(), self.groups.len()));
+
+        self.groups.push(Group {
+            layers: self.current..self.current + 1,
+            bounds: bounds * transformation,
+            effect: Effect {
+                backdrop_blur: effect.backdrop_blur
+                    * transformation.scale_factor(),
+                ..effect
+            },
+        });
+
+        self.groups.len() - 1
+    }
+
     /// Pops the current clipping region from the [`Stack`] and restores the previous one.
     ///
     /// The current layer will be recorded for drawing.
     pub fn pop_clip(&mut self) {
         self.flush();
 
+        if let Some(&(depth, group)) = self.open_groups.last() {
+            if depth == self.previous.len() {
+                self.groups[group].layers.end = self.active_count;
+
+                let _ = self.open_groups.pop();
+            }
+        }
+
         self.current = self.previous.pop().unwrap();
     }
 
@@ -118,6 +173,14 @@ impl<T: Layer> Stack<T> {
         &self.layers[..self.active_count]
     }
 
+    /// Returns the effect groups of the [`Stack`], sorted by their first layer.
+    ///
+    /// Groups may be nested; an outer [`Group`] always comes before any of
+    /// its inner groups.
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
     /// Flushes and settles any primitives in the current layer of the [`Stack`].
     pub fn flush(&mut self) {
         self.layers[self.current].flush();
@@ -134,6 +197,8 @@ impl<T: Layer> Stack<T> {
         self.current = 0;
         self.active_count = 1;
         self.previous.clear();
+        self.groups.clear();
+        self.open_groups.clear();
     }
 }
 
@@ -142,3 +207,135 @@ impl<T: Layer> Default for Stack<T> {
         Self::new()
     }
 }
+
+/// A range of layers in a [`Stack`] that must be composited together
+/// with an [`Effect`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    /// The indices of the layers of the [`Group`].
+    pub layers: Range<usize>,
+    /// The bounds of the [`Group`], with its transformation applied.
+    pub bounds: Rectangle,
+    /// The [`Effect`] of the [`Group`].
+    pub effect: Effect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Bounds(Option<Rectangle>);
+
+    impl Layer for Bounds {
+        fn with_bounds(bounds: Rectangle) -> Self {
+            Self(Some(bounds))
+        }
+
+        fn flush(&mut self) {}
+
+        fn resize(&mut self, bounds: Rectangle) {
+            self.0 = Some(bounds);
+        }
+
+        fn reset(&mut self) {
+            self.0 = None;
+        }
+    }
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn groups_span_their_nested_layers() {
+        let mut stack = Stack::<Bounds>::new();
+
+        let outer = stack.push_group(
+            rectangle(0.0, 0.0, 100.0, 100.0),
+            Effect::opacity(0.5),
+        );
+
+        stack.push_clip(rectangle(10.0, 10.0, 20.0, 20.0));
+        stack.pop_clip();
+
+        let inner = stack.push_group(
+            rectangle(50.0, 50.0, 10.0, 10.0),
+            Effect::backdrop_blur(4.0),
+        );
+        stack.pop_clip();
+
+        stack.pop_clip();
+        stack.push_clip(rectangle(0.0, 0.0, 5.0, 5.0));
+        stack.pop_clip();
+
+        assert_eq!((outer, inner), (0, 1));
+        assert_eq!(stack.as_slice().len(), 5);
+        assert_eq!(stack.groups()[outer].layers, 1..4);
+        assert_eq!(stack.groups()[inner].layers, 3..4);
+    }
+
+    #[test]
+    fn groups_are_transformed() {
+        let mut stack = Stack::<Bounds>::new();
+
+        stack.push_transformation(Transformation::scale(2.0));
+
+        let group = stack.push_group(
+            rectangle(1.0, 2.0, 3.0, 4.0),
+            Effect::backdrop_blur(5.0),
+        );
+        stack.pop_clip();
+
+        let group = &stack.groups()[group];
+
+        assert_eq!(group.bounds, rectangle(2.0, 4.0, 6.0, 8.0));
+        assert_eq!(group.effect.backdrop_blur, 10.0);
+        assert_eq!(group.effect.opacity, 1.0);
+        assert_eq!(stack.as_slice()[1].0, Some(group.bounds));
+    }
+
+    #[test]
+    fn popping_a_clip_restores_the_previous_layer() {
+        let mut stack = Stack::<Bounds>::new();
+
+        stack.push_clip(rectangle(0.0, 0.0, 10.0, 10.0));
+        stack.push_clip(rectangle(0.0, 0.0, 5.0, 5.0));
+
+        assert_eq!(
+            stack.current_mut().0 .0,
+            Some(rectangle(0.0, 0.0, 5.0, 5.0))
+        );
+
+        stack.pop_clip();
+
+        assert_eq!(
+            stack.current_mut().0 .0,
+            Some(rectangle(0.0, 0.0, 10.0, 10.0))
+        );
+
+        stack.pop_clip();
+
+        assert_eq!(stack.current_mut().0 .0, None);
+        assert!(stack.groups().is_empty());
+    }
+
+    #[test]
+    fn effects_that_do_nothing_do_not_create_groups() {
+        let mut stack = Stack::<Bounds>::new();
+
+        stack.push_clip_with_effect(
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            Effect::NONE,
+        );
+        stack.pop_clip();
+
+        assert!(stack.groups().is_empty());
+        assert_eq!(stack.as_slice().len(), 2);
+    }
+}
@@ -1,5 +1,6 @@
 //! Draw triangles!
 use crate::color;
+use crate::core::image;
 use crate::core::{Rectangle, Transformation};
 use crate::gradient;
 
@@ -27,6 +28,23 @@ pub enum Mesh {
         /// The [`Transformation`] for the vertices of the [`Mesh`].
         transformation: Transformation,
 
+        /// The clip bounds of the [`Mesh`].
+        clip_bounds: Rectangle,
+    },
+    /// A mesh textured with an image.
+    ///
+    /// The rotation and snapping of the image are ignored; the texture
+    /// coordinates of each vertex decide how the image is mapped.
+    Textured {
+        /// The vertices and indices of the mesh.
+        buffers: Indexed<TexturedVertex2D>,
+
+        /// The image used as the texture of the [`Mesh`].
+        image: image::Image,
+
+        /// The [`Transformation`] for the vertices of the [`Mesh`].
+        transformation: Transformation,
+
         /// The clip bounds of the [`Mesh`].
         clip_bounds: Rectangle,
     },
@@ -38,6 +56,7 @@ impl Mesh {
         match self {
             Self::Solid { buffers, .. } => &buffers.indices,
             Self::Gradient { buffers, .. } => &buffers.indices,
+            Self::Textured { buffers, .. } => &buffers.indices,
         }
     }
 
@@ -45,7 +64,8 @@ impl Mesh {
     pub fn transformation(&self) -> Transformation {
         match self {
             Self::Solid { transformation, .. }
-            | Self::Gradient { transformation, .. } => *transformation,
+            | Self::Gradient { transformation, .. }
+            | Self::Textured { transformation, .. } => *transformation,
         }
     }
 
@@ -61,6 +81,11 @@ impl Mesh {
                 clip_bounds,
                 transformation,
                 ..
+            }
+            | Self::Textured {
+                clip_bounds,
+                transformation,
+                ..
             } => *clip_bounds * *transformation,
         }
     }
@@ -100,6 +125,21 @@ pub struct GradientVertex2D {
     pub gradient: gradient::Packed,
 }
 
+/// A two-dimensional vertex with texture coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct TexturedVertex2D {
+    /// The vertex position in 2D space.
+    pub position: [f32; 2],
+
+    /// The texture coordinates of the vertex.
+    ///
+    /// They are normalized; `[0.0, 0.0]` is the top-left corner of the
+    /// image—or of its source region, if any—and `[1.0, 1.0]` is its
+    /// bottom-right corner.
+    pub uv: [f32; 2],
+}
+
 /// The result of counting the attributes of a set of meshes.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct AttributeCount {
@@ -115,6 +155,12 @@ pub struct AttributeCount {
     /// The total amount of gradient meshes.
     pub gradients: usize,
 
+    /// The total amount of textured vertices.
+    pub textured_vertices: usize,
+
+    /// The total amount of textured meshes.
+    pub textured: usize,
+
     /// The total amount of indices.
     pub indices: usize,
 }
@@ -135,6 +181,11 @@ pub fn attribute_count_of(meshes: &[Mesh]) -> AttributeCount {
                     count.gradient_vertices += buffers.vertices.len();
                     count.indices += buffers.indices.len();
                 }
+                Mesh::Textured { buffers, .. } => {
+                    count.textured += 1;
+                    count.textured_vertices += buffers.vertices.len();
+                    count.indices += buffers.indices.len();
+                }
             }
 
             count
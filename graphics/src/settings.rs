@@ -16,6 +16,19 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The maximum amount of memory, in bytes, that cached images may use.
+    ///
+    /// Once exceeded, the least recently used images that are not being
+    /// displayed will be evicted.
+    ///
+    /// Only raster images count towards the budget; rasterized vector
+    /// graphics do not. Renderers without an image atlas, like `tiny-skia`,
+    /// ignore it altogether.
+    ///
+    /// By default, it is `None`; and images are only evicted when they
+    /// stop being displayed.
+    pub image_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -24,6 +37,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_budget: None,
         }
     }
 }
@@ -16,6 +16,7 @@ use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::sync::{Arc, RwLock, Weak};
 
 /// A text primitive.
@@ -149,10 +150,27 @@ impl Text {
 /// It is loaded as part of the default fonts in Wasm builds.
 ///
 /// [Fira Sans]: https://mozilla.github.io/Fira/
-#[cfg(all(target_arch = "wasm32", feature = "fira-sans"))]
-pub const FIRA_SANS_REGULAR: &'static [u8] =
+#[cfg(any(test, all(target_arch = "wasm32", feature = "fira-sans")))]
+pub const FIRA_SANS_REGULAR: &[u8] =
     include_bytes!("../fonts/FiraSans-Regular.ttf").as_slice();
 
+/// Loads the bundled Fira Sans font into the global [`FontSystem`], so tests
+/// do not depend on the fonts installed in the system.
+#[cfg(test)]
+pub(crate) fn load_fira_sans() -> Font {
+    static LOAD: std::sync::Once = std::sync::Once::new();
+
+    LOAD.call_once(|| {
+        font_system()
+            .write()
+            .expect("Write font system")
+            .load_font(Cow::Borrowed(FIRA_SANS_REGULAR))
+            .expect("Load Fira Sans");
+    });
+
+    Font::with_name("Fira Sans")
+}
+
 /// Returns the global [`FontSystem`].
 pub fn font_system() -> &'static RwLock<FontSystem> {
     static FONT_SYSTEM: OnceCell<RwLock<FontSystem>> = OnceCell::new();
@@ -169,6 +187,9 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
                 )),
             ]),
             version: Version::default(),
+            has_system_fonts: true,
+            fallbacks: Vec::new(),
+            resolved_fallbacks: Vec::new(),
         })
     })
 }
@@ -178,6 +199,9 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
 pub struct FontSystem {
     raw: cosmic_text::FontSystem,
     version: Version,
+    has_system_fonts: bool,
+    fallbacks: Vec<(font::Script, Vec<&'static str>)>,
+    resolved_fallbacks: Vec<(font::Script, &'static str)>,
 }
 
 impl FontSystem {
@@ -187,14 +211,218 @@ impl FontSystem {
     }
 
     /// Loads a font from its bytes.
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        let _ = self.raw.db_mut().load_font_source(
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        let faces = self.raw.db_mut().load_font_source(
             cosmic_text::fontdb::Source::Binary(Arc::new(bytes.into_owned())),
         );
 
+        if faces.is_empty() {
+            return Err(font::Error::Invalid);
+        }
+
+        self.resolve_fallbacks();
+        self.version = Version(self.version.0 + 1);
+
+        Ok(())
+    }
+
+    /// Returns the names of all the available font families, sorted
+    /// alphabetically.
+    pub fn families(&self) -> Vec<String> {
+        let families: BTreeSet<&str> = self
+            .raw
+            .db()
+            .faces()
+            .filter_map(|face| face.families.first())
+            .map(|(name, _language)| name.as_str())
+            .collect();
+
+        families.into_iter().map(str::to_owned).collect()
+    }
+
+    /// Returns true if a font face with the exact family, weight, stretch
+    /// and style of the given [`Font`] is available.
+    pub fn is_available(&self, font: Font) -> bool {
+        let database = self.raw.db();
+        let family = to_family(font.family);
+        let name = database.family_name(&family);
+
+        database.faces().any(|face| {
+            face.families.iter().any(|(family, _)| family == name)
+                && face.weight == to_weight(font.weight)
+                && face.stretch == to_stretch(font.stretch)
+                && face.style == to_style(font.style)
+        })
+    }
+
+    /// Sets the font family used for the given generic [`font::Family`].
+    ///
+    /// Setting the family of a [`font::Family::Name`] has no effect.
+    pub fn set_family(&mut self, generic: font::Family, name: String) {
+        let database = self.raw.db_mut();
+
+        match generic {
+            font::Family::Name(_) => return,
+            font::Family::Serif => database.set_serif_family(name),
+            font::Family::SansSerif => database.set_sans_serif_family(name),
+            font::Family::Cursive => database.set_cursive_family(name),
+            font::Family::Fantasy => database.set_fantasy_family(name),
+            font::Family::Monospace => database.set_monospace_family(name),
+        }
+
+        self.version = Version(self.version.0 + 1);
+    }
+
+    /// Sets whether the fonts installed in the system can be used.
+    ///
+    /// When disabled, only the fonts loaded from bytes are kept.
+    pub fn use_system_fonts(&mut self, enabled: bool) {
+        if self.has_system_fonts == enabled {
+            return;
+        }
+
+        // Rebuild the font system to drop any cached system fonts
+        let raw = std::mem::replace(
+            &mut self.raw,
+            cosmic_text::FontSystem::new_with_locale_and_db(
+                String::new(),
+                cosmic_text::fontdb::Database::new(),
+            ),
+        );
+
+        let (locale, mut database) = raw.into_locale_and_db();
+
+        if enabled {
+            database.load_system_fonts();
+        } else {
+            let system_faces: Vec<_> = database
+                .faces()
+                .filter(|face| {
+                    !matches!(
+                        face.source,
+                        cosmic_text::fontdb::Source::Binary(_)
+                    )
+                })
+                .map(|face| face.id)
+                .collect();
+
+            for face in system_faces {
+                database.remove_face(face);
+            }
+        }
+
+        self.raw =
+            cosmic_text::FontSystem::new_with_locale_and_db(locale, database);
+        self.has_system_fonts = enabled;
+        self.resolve_fallbacks();
+        self.version = Version(self.version.0 + 1);
+    }
+
+    /// Sets the chain of font families used to render text in the given
+    /// [`font::Script`].
+    ///
+    /// The first available family of the chain is used. An empty chain
+    /// restores the default fallback behavior for the [`font::Script`].
+    pub fn set_fallback(
+        &mut self,
+        script: font::Script,
+        families: Vec<&'static str>,
+    ) {
+        self.fallbacks.retain(|(current, _)| *current != script);
+
+        if !families.is_empty() {
+            self.fallbacks.push((script, families));
+        }
+
+        self.resolve_fallbacks();
         self.version = Version(self.version.0 + 1);
     }
 
+    /// Splits the given content into spans with the given attributes,
+    /// replacing the family of the characters of any [`font::Script`]
+    /// with a configured fallback chain.
+    ///
+    /// Grapheme clusters are never split, so variation selectors and
+    /// combining marks stay with their base character, and a cluster like
+    /// a keycap sequence uses the family of any of its characters.
+    pub fn with_fallbacks<'a>(
+        &self,
+        content: &'a str,
+        attributes: cosmic_text::Attrs<'static>,
+    ) -> Vec<(&'a str, cosmic_text::Attrs<'static>)> {
+        if self.resolved_fallbacks.is_empty() {
+            return vec![(content, attributes)];
+        }
+
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let family = |grapheme: &str| {
+            let script = grapheme.chars().find_map(font::Script::of)?;
+
+            self.resolved_fallbacks
+                .iter()
+                .find(|(fallback, _)| *fallback == script)
+                .map(|(_, family)| *family)
+        };
+
+        let mut spans = Vec::new();
+        let mut start = 0;
+        let mut current = None;
+
+        for (i, grapheme) in content.grapheme_indices(true) {
+            // Whitespace stays with the preceding character
+            let next = if grapheme.chars().all(char::is_whitespace) {
+                current
+            } else {
+                family(grapheme)
+            };
+
+            if next != current && i > start {
+                spans.push((&content[start..i], current));
+                start = i;
+            }
+
+            current = next;
+        }
+
+        spans.push((&content[start..], current));
+
+        spans
+            .into_iter()
+            .map(|(text, family)| {
+                let attributes = match family {
+                    Some(name) => {
+                        attributes.family(cosmic_text::Family::Name(name))
+                    }
+                    None => attributes,
+                };
+
+                (text, attributes)
+            })
+            .collect()
+    }
+
+    fn resolve_fallbacks(&mut self) {
+        let database = self.raw.db();
+
+        self.resolved_fallbacks = self
+            .fallbacks
+            .iter()
+            .filter_map(|(script, families)| {
+                let family = families.iter().find(|name| {
+                    database.faces().any(|face| {
+                        face.families.iter().any(|(family, _)| family == *name)
+                    })
+                })?;
+
+                Some((*script, *family))
+            })
+            .collect();
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -322,3 +550,54 @@ pub fn to_color(color: Color) -> cosmic_text::Color {
 
     cosmic_text::Color::rgba(r, g, b, a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallbacks_keep_grapheme_clusters_together() {
+        let mut font_system = FontSystem {
+            raw: cosmic_text::FontSystem::new_with_fonts([
+                cosmic_text::fontdb::Source::Binary(Arc::new(
+                    FIRA_SANS_REGULAR,
+                )),
+            ]),
+            version: Version::default(),
+            has_system_fonts: false,
+            fallbacks: Vec::new(),
+            resolved_fallbacks: Vec::new(),
+        };
+
+        font_system.set_fallback(font::Script::Emoji, vec!["Fira Sans"]);
+
+        let attributes = cosmic_text::Attrs::new();
+        let emoji = attributes.family(cosmic_text::Family::Name("Fira Sans"));
+
+        let spans = |content| -> Vec<_> {
+            font_system
+                .with_fallbacks(content, attributes)
+                .into_iter()
+                .map(|(text, attributes)| (text.to_owned(), attributes))
+                .collect()
+        };
+
+        assert_eq!(
+            spans("a1\u{FE0F}\u{20E3}b"),
+            vec![
+                ("a".to_owned(), attributes),
+                ("1\u{FE0F}\u{20E3}".to_owned(), emoji),
+                ("b".to_owned(), attributes),
+            ]
+        );
+
+        assert_eq!(
+            spans("e\u{301} \u{2764}\u{FE0F} \u{1F44D}\u{1F3FD}!"),
+            vec![
+                ("e\u{301} ".to_owned(), attributes),
+                ("\u{2764}\u{FE0F} \u{1F44D}\u{1F3FD}".to_owned(), emoji),
+                ("!".to_owned(), attributes),
+            ]
+        );
+    }
+}
@@ -12,6 +12,7 @@ pub struct Cache {
     entries: FxHashMap<KeyHash, Entry>,
     aliases: FxHashMap<KeyHash, KeyHash>,
     recently_used: FxHashSet<KeyHash>,
+    version: text::Version,
 }
 
 impl Cache {
@@ -28,9 +29,17 @@ impl Cache {
     /// Allocates a text [`Entry`] if it is not already present in the [`Cache`].
     pub fn allocate(
         &mut self,
-        font_system: &mut cosmic_text::FontSystem,
+        font_system: &mut text::FontSystem,
         key: Key<'_>,
     ) -> (KeyHash, &mut Entry) {
+        // Buffers shaped with an older font system may be stale
+        if self.version != font_system.version() {
+            self.entries.clear();
+            self.aliases.clear();
+            self.recently_used.clear();
+            self.version = font_system.version();
+        }
+
         let hash = key.hash(FxHasher::default());
 
         if let Some(hash) = self.aliases.get(&hash) {
@@ -40,6 +49,10 @@ impl Cache {
         }
 
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(hash) {
+            let attributes = text::to_attributes(key.font);
+            let spans = font_system.with_fallbacks(key.content, attributes);
+            let font_system = font_system.raw();
+
             let metrics = cosmic_text::Metrics::new(
                 key.size,
                 key.line_height.max(f32::MIN_POSITIVE),
@@ -51,10 +64,10 @@ impl Cache {
                 Some(key.bounds.width),
                 Some(key.bounds.height.max(key.line_height)),
             );
-            buffer.set_text(
+            buffer.set_rich_text(
                 font_system,
-                key.content,
-                text::to_attributes(key.font),
+                spans,
+                attributes,
                 text::to_shaping(key.shaping),
             );
 
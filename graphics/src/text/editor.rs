@@ -1,4 +1,5 @@
 //! Draw and edit text.
+use crate::core::text::decoration::{self, Decoration};
 use crate::core::text::editor::{
     self, Action, Cursor, Direction, Edit, Motion,
 };
@@ -10,6 +11,7 @@ use crate::text;
 use cosmic_text::Edit as _;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A multi-line text editor.
@@ -22,6 +24,7 @@ struct Internal {
     bounds: Size,
     topmost_line_changed: Option<usize>,
     version: text::Version,
+    decorations: Vec<(Option<Decoration>, Option<Decoration>)>,
 }
 
 impl Editor {
@@ -68,13 +71,23 @@ impl editor::Editor for Editor {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
+        let attributes = text::to_attributes(Font::default());
+
         buffer.set_text(
             font_system.raw(),
             text,
-            cosmic_text::Attrs::new(),
+            attributes,
             cosmic_text::Shaping::Advanced,
         );
 
+        for line in buffer.lines.iter_mut() {
+            let _ = line.set_attrs_list(attributes_list(
+                &font_system,
+                line.text(),
+                attributes,
+            ));
+        }
+
         Editor(Some(Arc::new(Internal {
             editor: cosmic_text::Editor::new(buffer),
             version: font_system.version(),
@@ -104,6 +117,98 @@ impl editor::Editor for Editor {
         self.internal().editor.copy_selection()
     }
 
+    fn range_bounds(&self, line: usize, range: Range<usize>) -> Vec<Rectangle> {
+        let buffer = self.buffer();
+
+        let Some(buffer_line) = buffer.lines.get(line) else {
+            return Vec::new();
+        };
+
+        let line_height = buffer.metrics().line_height;
+        let visual_lines_offset = visual_lines_offset(line, buffer);
+
+        highlight_line(buffer_line, range.start, range.end)
+            .enumerate()
+            .filter_map(|(visual_line, (x, width))| {
+                (width > 0.0).then(|| Rectangle {
+                    x,
+                    width,
+                    y: (visual_line as i32 + visual_lines_offset) as f32
+                        * line_height
+                        - buffer.scroll().vertical,
+                    height: line_height,
+                })
+            })
+            .collect()
+    }
+
+    fn visible_lines(&self) -> Range<usize> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        buffer.scroll().line
+            ..last_visible_line(buffer, internal.bounds.height) + 1
+    }
+
+    fn hit(&self, point: Point) -> Option<(usize, usize)> {
+        let cursor = self.buffer().hit(point.x, point.y)?;
+
+        Some((cursor.line, cursor.index))
+    }
+
+    fn decorations(&self) -> Vec<(Rectangle, decoration::Line, Decoration)> {
+        let internal = self.internal();
+
+        if internal.decorations.is_empty() {
+            return Vec::new();
+        }
+
+        let buffer = buffer_from_editor(&internal.editor);
+        let mut decorations = Vec::new();
+
+        for run in buffer.layout_runs() {
+            let mut glyphs = run.glyphs.iter().peekable();
+
+            while let Some(glyph) = glyphs.next() {
+                let mut start = glyph.x;
+                let mut end = glyph.x + glyph.w;
+
+                while let Some(next) =
+                    glyphs.next_if(|next| next.metadata == glyph.metadata)
+                {
+                    start = start.min(next.x);
+                    end = end.max(next.x + next.w);
+                }
+
+                // Metadata is the index of the decorations plus one
+                let Some((underline, strikethrough)) = glyph
+                    .metadata
+                    .checked_sub(1)
+                    .and_then(|index| internal.decorations.get(index))
+                else {
+                    continue;
+                };
+
+                let bounds = Rectangle {
+                    x: start,
+                    y: run.line_top,
+                    width: end - start,
+                    height: run.line_height,
+                };
+
+                decorations.extend(underline.map(|decoration| {
+                    (bounds, decoration::Line::Underline, decoration)
+                }));
+
+                decorations.extend(strikethrough.map(|decoration| {
+                    (bounds, decoration::Line::Strikethrough, decoration)
+                }));
+            }
+        }
+
+        decorations
+    }
+
     fn cursor(&self) -> editor::Cursor {
         let internal = self.internal();
 
@@ -373,6 +478,15 @@ impl editor::Editor for Editor {
                             cosmic_text::Action::Delete,
                         );
                     }
+                    Edit::Replace { line, range, text } => {
+                        editor.set_selection(cosmic_text::Selection::Normal(
+                            cosmic_text::Cursor::new(line, range.start),
+                        ));
+                        editor.set_cursor(cosmic_text::Cursor::new(
+                            line, range.end,
+                        ));
+                        editor.insert_string(&text, None);
+                    }
                 }
 
                 let cursor = editor.cursor();
@@ -451,27 +565,26 @@ impl editor::Editor for Editor {
 
         let buffer = buffer_mut_from_editor(&mut internal.editor);
 
-        if font_system.version() != internal.version {
-            log::trace!("Updating `FontSystem` of `Editor`...");
-
-            for line in buffer.lines.iter_mut() {
-                line.reset();
-            }
-
-            internal.version = font_system.version();
-            internal.topmost_line_changed = Some(0);
-        }
-
-        if new_font != internal.font {
+        if font_system.version() != internal.version
+            || new_font != internal.font
+        {
             log::trace!("Updating font of `Editor`...");
 
+            // The fallbacks may have changed along with the `FontSystem`
+            let attributes = text::to_attributes(new_font);
+
             for line in buffer.lines.iter_mut() {
-                let _ = line.set_attrs_list(cosmic_text::AttrsList::new(
-                    text::to_attributes(new_font),
+                let _ = line.set_attrs_list(attributes_list(
+                    &font_system,
+                    line.text(),
+                    attributes,
                 ));
+
+                line.reset();
             }
 
             internal.font = new_font;
+            internal.version = font_system.version();
             internal.topmost_line_changed = Some(0);
         }
 
@@ -532,28 +645,8 @@ impl editor::Editor for Editor {
         let internal = self.internal();
         let buffer = buffer_from_editor(&internal.editor);
 
-        let scroll = buffer.scroll();
-        let mut window = (internal.bounds.height / buffer.metrics().line_height)
-            .ceil() as i32;
-
-        let last_visible_line = buffer.lines[scroll.line..]
-            .iter()
-            .enumerate()
-            .find_map(|(i, line)| {
-                let visible_lines = line
-                    .layout_opt()
-                    .as_ref()
-                    .expect("Line layout should be cached")
-                    .len() as i32;
-
-                if window > visible_lines {
-                    window -= visible_lines;
-                    None
-                } else {
-                    Some(scroll.line + i)
-                }
-            })
-            .unwrap_or(buffer.lines.len().saturating_sub(1));
+        let last_visible_line =
+            last_visible_line(buffer, internal.bounds.height);
 
         let current_line = highlighter.current_line();
 
@@ -575,23 +668,58 @@ impl editor::Editor for Editor {
         for line in &mut buffer_mut_from_editor(&mut internal.editor).lines
             [current_line..=last_visible_line]
         {
-            let mut list = cosmic_text::AttrsList::new(attributes);
+            let mut list =
+                attributes_list(&font_system, line.text(), attributes);
 
             for (range, highlight) in highlighter.highlight_line(line.text()) {
                 let format = format_highlight(&highlight);
 
-                if format.color.is_some() || format.font.is_some() {
-                    list.add_span(
-                        range,
+                let decorations = (format.underline, format.strikethrough);
+
+                let metadata = if decorations == (None, None) {
+                    0
+                } else {
+                    let index = internal
+                        .decorations
+                        .iter()
+                        .position(|current| *current == decorations)
+                        .unwrap_or_else(|| {
+                            internal.decorations.push(decorations);
+                            internal.decorations.len() - 1
+                        });
+
+                    index + 1
+                };
+
+                if format.color.is_some()
+                    || format.font.is_some()
+                    || metadata != 0
+                {
+                    let Some(content) = line.text().get(range.clone()) else {
+                        continue;
+                    };
+
+                    let mut start = range.start;
+
+                    for (span, span_attributes) in font_system.with_fallbacks(
+                        content,
                         cosmic_text::Attrs {
                             color_opt: format.color.map(text::to_color),
+                            metadata,
                             ..if let Some(font) = format.font {
                                 text::to_attributes(font)
                             } else {
                                 attributes
                             }
                         },
-                    );
+                    ) {
+                        list.add_span(
+                            start..start + span.len(),
+                            span_attributes,
+                        );
+
+                        start += span.len();
+                    }
                 }
             }
 
@@ -632,6 +760,7 @@ impl Default for Internal {
             bounds: Size::ZERO,
             topmost_line_changed: None,
             version: text::Version::default(),
+            decorations: Vec::new(),
         }
     }
 }
@@ -669,6 +798,28 @@ impl PartialEq for Weak {
     }
 }
 
+/// Produces the [`cosmic_text::AttrsList`] of a line with the given text,
+/// using the fallback families of the [`text::FontSystem`] where needed.
+fn attributes_list(
+    font_system: &text::FontSystem,
+    text: &str,
+    attributes: cosmic_text::Attrs<'static>,
+) -> cosmic_text::AttrsList {
+    let mut list = cosmic_text::AttrsList::new(attributes);
+    let mut start = 0;
+
+    for (span, span_attributes) in font_system.with_fallbacks(text, attributes)
+    {
+        if span_attributes != attributes {
+            list.add_span(start..start + span.len(), span_attributes);
+        }
+
+        start += span.len();
+    }
+
+    list
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -720,6 +871,32 @@ fn highlight_line(
     })
 }
 
+/// Returns the index of the last line of the buffer that is visible within
+/// the given height.
+fn last_visible_line(buffer: &cosmic_text::Buffer, height: f32) -> usize {
+    let scroll = buffer.scroll();
+    let mut window = (height / buffer.metrics().line_height).ceil() as i32;
+
+    buffer.lines[scroll.line..]
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| {
+            let visible_lines = line
+                .layout_opt()
+                .as_ref()
+                .expect("Line layout should be cached")
+                .len() as i32;
+
+            if window > visible_lines {
+                window -= visible_lines;
+                None
+            } else {
+                Some(scroll.line + i)
+            }
+        })
+        .unwrap_or(buffer.lines.len().saturating_sub(1))
+}
+
 fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     let scroll = buffer.scroll();
 
@@ -1,11 +1,12 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, Shaping, Span, Text, Wrapping};
+use crate::core::text::{Ellipsis, Hit, Shaping, Span, Text, Wrapping};
 use crate::core::{Font, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -18,10 +19,13 @@ struct Internal {
     font: Font,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
     min_bounds: Size,
+    is_truncated: bool,
     version: text::Version,
 }
 
@@ -80,13 +84,29 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_text(
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let attributes = text::to_attributes(text.font);
+        let spans = font_system.with_fallbacks(text.content, attributes);
+
+        buffer.set_rich_text(
             font_system.raw(),
-            text.content,
-            text::to_attributes(text.font),
+            spans.iter().copied(),
+            attributes,
             text::to_shaping(text.shaping),
         );
 
+        let is_truncated = truncate(
+            font_system.raw(),
+            &mut buffer,
+            &spans,
+            attributes,
+            text::to_shaping(text.shaping),
+            text.ellipsis,
+            text.max_lines,
+            text.bounds,
+        );
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
@@ -96,8 +116,11 @@ impl core::text::Paragraph for Paragraph {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
             bounds: text.bounds,
             min_bounds,
+            is_truncated,
             version: font_system.version(),
         }))
     }
@@ -122,9 +145,13 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_rich_text(
-            font_system.raw(),
-            text.content.iter().enumerate().map(|(i, span)| {
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let spans: Vec<_> = text
+            .content
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| {
                 let attrs = text::to_attributes(span.font.unwrap_or(text.font));
 
                 let attrs = match (span.size, span.line_height) {
@@ -148,12 +175,29 @@ impl core::text::Paragraph for Paragraph {
                     attrs
                 };
 
-                (span.text.as_ref(), attrs.metadata(i))
-            }),
+                font_system
+                    .with_fallbacks(span.text.as_ref(), attrs.metadata(i))
+            })
+            .collect();
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans.iter().copied(),
             text::to_attributes(text.font),
             text::to_shaping(text.shaping),
         );
 
+        let is_truncated = truncate(
+            font_system.raw(),
+            &mut buffer,
+            &spans,
+            text::to_attributes(text.font),
+            text::to_shaping(text.shaping),
+            text.ellipsis,
+            text.max_lines,
+            text.bounds,
+        );
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
@@ -163,8 +207,11 @@ impl core::text::Paragraph for Paragraph {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
             bounds: text.bounds,
             min_bounds,
+            is_truncated,
             version: font_system.version(),
         }))
     }
@@ -196,11 +243,20 @@ impl core::text::Paragraph for Paragraph {
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
             || paragraph.wrapping != text.wrapping
+            || paragraph.ellipsis != text.ellipsis
+            || paragraph.max_lines != text.max_lines
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
             core::text::Difference::Shape
         } else if paragraph.bounds != text.bounds {
+            // Truncated text needs to be laid out again from its contents
+            if paragraph.ellipsis != Ellipsis::None
+                || paragraph.max_lines.is_some()
+            {
+                return core::text::Difference::Shape;
+            }
+
             core::text::Difference::Bounds
         } else {
             core::text::Difference::None
@@ -219,6 +275,10 @@ impl core::text::Paragraph for Paragraph {
         self.internal().min_bounds
     }
 
+    fn is_truncated(&self) -> bool {
+        self.internal().is_truncated
+    }
+
     fn hit_test(&self, point: Point) -> Option<Hit> {
         let cursor = self.internal().buffer.hit(point.x, point.y)?;
 
@@ -306,6 +366,36 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+        let cursor = buffer.hit(point.x, point.y)?;
+
+        Some(line_offset(buffer, cursor.line) + cursor.index)
+    }
+
+    fn selection_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+
+        let start =
+            to_cursor(buffer, range.start, cosmic_text::Affinity::Before);
+        let end = to_cursor(buffer, range.end, cosmic_text::Affinity::After);
+
+        buffer
+            .layout_runs()
+            .filter(|run| start.line <= run.line_i && run.line_i <= end.line)
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                (width > 0.0).then(|| {
+                    Rectangle::new(
+                        Point::new(x, run.line_top),
+                        Size::new(width, run.line_height),
+                    )
+                })
+            })
+            .collect()
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -349,6 +439,183 @@ impl core::text::Paragraph for Paragraph {
     }
 }
 
+/// Truncates the contents of the buffer to fit within its bounds and the
+/// given maximum amount of lines, inserting an ellipsis if needed.
+///
+/// Returns true if the contents were truncated.
+fn truncate(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &mut cosmic_text::Buffer,
+    spans: &[(&str, cosmic_text::Attrs<'static>)],
+    default_attributes: cosmic_text::Attrs<'static>,
+    shaping: cosmic_text::Shaping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    bounds: Size,
+) -> bool {
+    if ellipsis == Ellipsis::None && max_lines.is_none()
+        || fits(font_system, buffer, ellipsis, max_lines, bounds)
+    {
+        return false;
+    }
+
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let content: String = spans.iter().map(|(text, _)| *text).collect();
+    let length = content.len();
+
+    // Graphemes may span multiple spans, so we find them in the whole content
+    let boundaries: Vec<usize> = content
+        .grapheme_indices(true)
+        .map(|(i, _)| i)
+        .chain(std::iter::once(length))
+        .collect();
+
+    let total = boundaries.len() - 1;
+
+    let mut set_truncated = |kept: usize| {
+        let (head, tail) = match ellipsis {
+            Ellipsis::None | Ellipsis::End => (kept, 0),
+            Ellipsis::Middle => (kept - kept / 2, kept / 2),
+            Ellipsis::Start => (0, kept),
+        };
+
+        let head = 0..boundaries[head];
+        let tail = boundaries[total - tail]..length;
+
+        let pieces = |range: Range<usize>| {
+            spans
+                .iter()
+                .scan(0, |offset, (text, attributes)| {
+                    let start = *offset;
+                    *offset += text.len();
+
+                    Some((start, *text, *attributes))
+                })
+                .filter_map(move |(start, text, attributes)| {
+                    let from = range.start.max(start);
+                    let to = range.end.min(start + text.len());
+
+                    (from < to)
+                        .then(|| (&text[from - start..to - start], attributes))
+                })
+        };
+
+        let head: Vec<_> = pieces(head).collect();
+        let tail: Vec<_> = pieces(tail).collect();
+
+        let marker = (ellipsis != Ellipsis::None).then(|| {
+            let attributes = head
+                .last()
+                .or(tail.first())
+                .map_or(default_attributes, |(_, attributes)| *attributes);
+
+            ("…", attributes)
+        });
+
+        buffer.set_rich_text(
+            font_system,
+            head.into_iter().chain(marker).chain(tail),
+            default_attributes,
+            shaping,
+        );
+
+        fits(font_system, buffer, ellipsis, max_lines, bounds)
+    };
+
+    // Find the most graphemes we can keep with a binary search
+    let (mut low, mut high) = (0, total.saturating_sub(1));
+
+    while low < high {
+        let middle = (low + high).div_ceil(2);
+
+        if set_truncated(middle) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    let _ = set_truncated(low);
+
+    true
+}
+
+/// Returns true if the laid out contents of the buffer fit within the given
+/// bounds and maximum amount of lines.
+fn fits(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &mut cosmic_text::Buffer,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    bounds: Size,
+) -> bool {
+    let default_line_height = buffer.metrics().line_height;
+
+    let mut lines = 0;
+    let mut height = 0.0;
+
+    for i in 0..buffer.lines.len() {
+        for line in buffer.line_layout(font_system, i).unwrap_or_default() {
+            if ellipsis != Ellipsis::None && line.w > bounds.width {
+                return false;
+            }
+
+            lines += 1;
+            height += line.line_height_opt.unwrap_or(default_line_height);
+        }
+    }
+
+    let fits_lines = match max_lines {
+        Some(max_lines) => lines <= max_lines,
+        None => true,
+    };
+
+    fits_lines
+        && (ellipsis == Ellipsis::None || lines <= 1 || height <= bounds.height)
+}
+
+/// Returns the byte offset where the given line of the buffer starts.
+fn line_offset(buffer: &cosmic_text::Buffer, line: usize) -> usize {
+    buffer
+        .lines
+        .iter()
+        .take(line)
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum()
+}
+
+/// Converts a byte offset of the contents of the buffer into a cursor.
+fn to_cursor(
+    buffer: &cosmic_text::Buffer,
+    offset: usize,
+    affinity: cosmic_text::Affinity,
+) -> cosmic_text::Cursor {
+    let mut start = 0;
+
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let end = start + line.text().len();
+
+        if offset <= end {
+            return cosmic_text::Cursor::new_with_affinity(
+                i,
+                offset.saturating_sub(start),
+                affinity,
+            );
+        }
+
+        start = end + line.ending().as_str().len();
+    }
+
+    let last = buffer.lines.len().saturating_sub(1);
+
+    cosmic_text::Cursor::new_with_affinity(
+        last,
+        buffer.lines.get(last).map_or(0, |line| line.text().len()),
+        affinity,
+    )
+}
+
 impl Default for Paragraph {
     fn default() -> Self {
         Self(Arc::new(Internal::default()))
@@ -392,10 +659,13 @@ impl Default for Internal {
             font: Font::default(),
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
             min_bounds: Size::ZERO,
+            is_truncated: false,
             version: text::Version::default(),
         }
     }
@@ -428,3 +698,190 @@ impl PartialEq for Weak {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::Paragraph as _;
+    use crate::core::{Color, Pixels};
+
+    fn text<T>(
+        content: T,
+        width: f32,
+        ellipsis: Ellipsis,
+        max_lines: Option<usize>,
+    ) -> Text<T> {
+        Text {
+            content,
+            // A single line fits vertically
+            bounds: Size::new(width, 30.0),
+            size: Pixels(20.0),
+            line_height: core::text::LineHeight::default(),
+            font: text::load_fira_sans(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::Word,
+            ellipsis,
+            max_lines,
+        }
+    }
+
+    fn paragraph(content: &str, width: f32, ellipsis: Ellipsis) -> Paragraph {
+        Paragraph::with_text(text(content, width, ellipsis, None))
+    }
+
+    fn contents(paragraph: &Paragraph) -> String {
+        paragraph
+            .buffer()
+            .lines
+            .iter()
+            .map(cosmic_text::BufferLine::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn lines(paragraph: &Paragraph) -> usize {
+        paragraph.buffer().layout_runs().count()
+    }
+
+    const CONTENT: &str = "Ünïcödé text—it wraps, ça va très bien, merci!";
+
+    #[test]
+    fn text_that_fits_is_not_truncated() {
+        for ellipsis in [Ellipsis::End, Ellipsis::Middle, Ellipsis::Start] {
+            let paragraph = paragraph("Ünïcödé", 1000.0, ellipsis);
+
+            assert!(!paragraph.is_truncated());
+            assert_eq!(contents(&paragraph), "Ünïcödé");
+        }
+    }
+
+    #[test]
+    fn end_ellipsis_keeps_the_start() {
+        let paragraph = paragraph(CONTENT, 150.0, Ellipsis::End);
+        let contents = contents(&paragraph);
+
+        assert!(paragraph.is_truncated());
+        assert_eq!(lines(&paragraph), 1);
+        assert!(paragraph.min_bounds().width <= 150.0);
+
+        let kept = contents.strip_suffix('…').expect("Ellipsis at the end");
+
+        assert!(!kept.is_empty());
+        assert!(CONTENT.starts_with(kept));
+    }
+
+    #[test]
+    fn start_ellipsis_keeps_the_end() {
+        let paragraph = paragraph(CONTENT, 150.0, Ellipsis::Start);
+        let contents = contents(&paragraph);
+
+        assert!(paragraph.is_truncated());
+        assert_eq!(lines(&paragraph), 1);
+        assert!(paragraph.min_bounds().width <= 150.0);
+
+        let kept = contents.strip_prefix('…').expect("Ellipsis at the start");
+
+        assert!(!kept.is_empty());
+        assert!(CONTENT.ends_with(kept));
+    }
+
+    #[test]
+    fn middle_ellipsis_keeps_both_ends() {
+        let paragraph = paragraph(CONTENT, 150.0, Ellipsis::Middle);
+        let contents = contents(&paragraph);
+
+        assert!(paragraph.is_truncated());
+        assert_eq!(lines(&paragraph), 1);
+        assert!(paragraph.min_bounds().width <= 150.0);
+
+        let (head, tail) =
+            contents.split_once('…').expect("Ellipsis in the middle");
+
+        assert!(!head.is_empty() && !tail.is_empty());
+        assert!(CONTENT.starts_with(head));
+        assert!(CONTENT.ends_with(tail));
+    }
+
+    #[test]
+    fn graphemes_are_never_split() {
+        // An "e" followed by a combining acute accent
+        let content = "e\u{301}".repeat(50);
+
+        for ellipsis in [Ellipsis::End, Ellipsis::Middle, Ellipsis::Start] {
+            let paragraph = paragraph(&content, 101.0, ellipsis);
+            let contents = contents(&paragraph);
+
+            assert!(paragraph.is_truncated());
+
+            for piece in contents.split('…') {
+                assert_eq!(piece.len() % 3, 0, "{ellipsis:?}: {contents:?}");
+                assert!(!piece.starts_with('\u{301}'));
+            }
+        }
+    }
+
+    #[test]
+    fn max_lines_cut_off_the_rest() {
+        let content = "one two three four five six seven eight nine ten";
+
+        let mut text = text(content, 80.0, Ellipsis::None, Some(2));
+        text.bounds.height = f32::INFINITY;
+
+        let paragraph = Paragraph::with_text(text);
+
+        assert!(paragraph.is_truncated());
+        assert_eq!(lines(&paragraph), 2);
+        assert!(content.starts_with(contents(&paragraph).as_str()));
+
+        let paragraph = Paragraph::with_text(Text {
+            ellipsis: Ellipsis::End,
+            ..text
+        });
+
+        assert!(paragraph.is_truncated());
+        assert_eq!(lines(&paragraph), 2);
+        assert!(contents(&paragraph).ends_with('…'));
+
+        let paragraph = Paragraph::with_text(Text {
+            max_lines: Some(100),
+            ..text
+        });
+
+        assert!(!paragraph.is_truncated());
+        assert_eq!(contents(&paragraph), content);
+    }
+
+    #[test]
+    fn rich_text_keeps_its_spans() {
+        let spans: [Span<'_>; 2] = [
+            Span::new("Hello there, my dear "),
+            Span::new("wörld, how are you?")
+                .color(Color::from_rgb(1.0, 0.0, 0.0)),
+        ];
+
+        let paragraph =
+            Paragraph::with_spans(text(&spans[..], 100.0, Ellipsis::End, None));
+
+        assert!(paragraph.is_truncated());
+        assert!(contents(&paragraph).starts_with("Hello"));
+        assert!(contents(&paragraph).ends_with('…'));
+        assert!(!paragraph.span_bounds(0).is_empty());
+        assert!(paragraph.span_bounds(1).is_empty());
+
+        let paragraph = Paragraph::with_spans(text(
+            &spans[..],
+            100.0,
+            Ellipsis::Start,
+            None,
+        ));
+
+        assert!(paragraph.is_truncated());
+        assert!(contents(&paragraph).starts_with('…'));
+        assert!(contents(&paragraph).ends_with("you?"));
+        assert!(paragraph.span_bounds(0).is_empty());
+        assert!(!paragraph.span_bounds(1).is_empty());
+    }
+}
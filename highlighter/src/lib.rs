@@ -1,32 +1,91 @@
 //! A syntax highlighter for iced.
 use iced_core as core;
 
+pub mod registry;
+
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
+mod palette;
+
 use crate::core::font::{self, Font};
 use crate::core::text::highlighter::{self, Format};
+use crate::core::text::Decoration;
+use crate::core::theme;
 use crate::core::Color;
 
-use once_cell::sync::Lazy;
+use ouroboros::self_referencing;
+use std::fmt;
 use std::ops::Range;
+use std::sync::Arc;
 use syntect::highlighting;
 use syntect::parsing;
 
-static SYNTAXES: Lazy<parsing::SyntaxSet> =
-    Lazy::new(parsing::SyntaxSet::load_defaults_nonewlines);
-
-static THEMES: Lazy<highlighting::ThemeSet> =
-    Lazy::new(highlighting::ThemeSet::load_defaults);
-
 const LINES_PER_SNAPSHOT: usize = 50;
 
 /// A syntax highlighter.
+///
+/// It uses the syntaxes and themes of the [`registry`].
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: usize,
+    styler: Styler,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
 }
 
+impl Highlighter {
+    fn syntax(&self) -> &parsing::SyntaxReference {
+        &self.syntaxes.syntaxes()[self.syntax]
+    }
+}
+
+/// A highlighting theme together with the style resolver built from it.
+///
+/// Building the resolver splits all the scope selectors of the theme; so it
+/// is only rebuilt when the theme changes.
+#[self_referencing]
+struct Styler {
+    theme: Arc<highlighting::Theme>,
+
+    #[borrows(theme)]
+    #[covariant]
+    highlighter: highlighting::Highlighter<'this>,
+}
+
+impl Styler {
+    fn from_theme(theme: Arc<highlighting::Theme>) -> Self {
+        StylerBuilder {
+            theme,
+            highlighter_builder: |theme| highlighting::Highlighter::new(theme),
+        }
+        .build()
+    }
+}
+
+impl fmt::Debug for Styler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Styler")
+            .field(&self.borrow_theme().name)
+            .finish()
+    }
+}
+
+/// Finds the syntax of the given token in a [`parsing::SyntaxSet`],
+/// returning its index.
+fn find_syntax(syntaxes: &parsing::SyntaxSet, token: &str) -> usize {
+    let syntax = syntaxes
+        .find_syntax_by_token(token)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+
+    syntaxes
+        .syntaxes()
+        .iter()
+        .position(|candidate| std::ptr::eq(candidate, syntax))
+        .unwrap_or_default()
+}
+
 impl highlighter::Highlighter for Highlighter {
     type Settings = Settings;
     type Highlight = Highlight;
@@ -35,33 +94,30 @@ impl highlighter::Highlighter for Highlighter {
         Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
-
-        let highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[settings.theme.key()],
-        );
+        let syntaxes = registry::syntaxes();
+        let syntax = find_syntax(&syntaxes, &settings.token);
 
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntaxes.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            syntaxes,
             syntax,
-            highlighter,
+            styler: Styler::from_theme(registry::theme(&settings.theme)),
             caches: vec![(parser, stack)],
             current_line: 0,
         }
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        self.syntaxes = registry::syntaxes();
+        self.syntax = find_syntax(&self.syntaxes, &new_settings.token);
 
-        self.highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[new_settings.theme.key()],
-        );
+        let theme = registry::theme(&new_settings.theme);
+
+        if !Arc::ptr_eq(&theme, self.styler.borrow_theme()) {
+            self.styler = Styler::from_theme(theme);
+        }
 
         // Restart the highlighter
         self.change_line(0);
@@ -81,7 +137,7 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last().cloned().unwrap_or_else(|| {
                 (
-                    parsing::ParseState::new(self.syntax),
+                    parsing::ParseState::new(self.syntax()),
                     parsing::ScopeStack::new(),
                 )
             });
@@ -102,9 +158,9 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser.parse_line(line, &self.syntaxes).unwrap_or_default();
 
-        let highlighter = &self.highlighter;
+        let highlighter = self.styler.borrow_highlighter();
 
         Box::new(
             ScopeRangeIterator {
@@ -191,29 +247,46 @@ impl Highlight {
         })
     }
 
+    /// Returns the underline [`Decoration`] of this [`Highlight`], if any.
+    pub fn underline(&self) -> Option<Decoration> {
+        self.0.font_style.and_then(|style| {
+            style
+                .contains(highlighting::FontStyle::UNDERLINE)
+                .then(Decoration::default)
+        })
+    }
+
     /// Returns the [`Format`] of the [`Highlight`].
     ///
-    /// It contains both the [`color`] and the [`font`].
+    /// It contains the [`color`], the [`font`], and the [`underline`].
     ///
     /// [`color`]: Self::color
     /// [`font`]: Self::font
+    /// [`underline`]: Self::underline
     pub fn to_format(&self) -> Format<Font> {
         Format {
             color: self.color(),
             font: self.font(),
+            underline: self.underline(),
+            strikethrough: None,
         }
     }
 }
 
 /// A highlighting theme.
+///
+/// A [`Theme`] is not `Copy`, since it may name a custom theme of the
+/// [`registry`]; but cloning it is cheap.
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Theme {
     SolarizedDark,
     Base16Mocha,
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A theme of the [`registry`] with the given name.
+    Custom(Arc<str>),
 }
 
 impl Theme {
@@ -226,24 +299,51 @@ impl Theme {
         Self::InspiredGitHub,
     ];
 
+    /// Creates a [`Theme`] that matches the colors of the given
+    /// [`theme::Palette`]; so highlighted code fits the rest of an
+    /// application.
+    ///
+    /// The [`Theme`] is added to the [`registry`] the first time it is
+    /// created.
+    pub fn from_palette(palette: theme::Palette) -> Self {
+        let name: Arc<str> = palette::name(&palette).into();
+
+        if registry::contains_theme(&name) {
+            Self::Custom(name)
+        } else {
+            registry::insert_theme(name, palette::theme(&palette))
+        }
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
-    pub fn is_dark(self) -> bool {
+    pub fn is_dark(&self) -> bool {
         match self {
             Self::SolarizedDark
             | Self::Base16Mocha
             | Self::Base16Ocean
             | Self::Base16Eighties => true,
             Self::InspiredGitHub => false,
+            Self::Custom(_) => registry::theme(self)
+                .settings
+                .background
+                .is_some_and(|background| {
+                    let luminance = 0.299 * f32::from(background.r)
+                        + 0.587 * f32::from(background.g)
+                        + 0.114 * f32::from(background.b);
+
+                    luminance < 128.0
+                }),
         }
     }
 
-    fn key(self) -> &'static str {
+    fn key(&self) -> &str {
         match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
+            Theme::Custom(name) => name,
         }
     }
 }
@@ -256,6 +356,7 @@ impl std::fmt::Display for Theme {
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Custom(name) => write!(f, "{name}"),
         }
     }
 }
@@ -0,0 +1,109 @@
+//! Derive highlighting themes from a [`Palette`].
+use crate::core::theme::Palette;
+use crate::core::Color;
+
+use std::str::FromStr;
+use syntect::highlighting::{
+    self, FontStyle, ScopeSelectors, StyleModifier, ThemeItem, ThemeSettings,
+};
+
+/// Returns a unique name for the theme of the given [`Palette`].
+pub fn name(palette: &Palette) -> String {
+    let hex = |color: Color| {
+        let [r, g, b, a] = color.into_rgba8();
+
+        format!("{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    format!(
+        "palette-{}-{}-{}-{}-{}",
+        hex(palette.background),
+        hex(palette.text),
+        hex(palette.primary),
+        hex(palette.success),
+        hex(palette.danger),
+    )
+}
+
+/// Creates a highlighting theme using the colors of the given [`Palette`].
+pub fn theme(palette: &Palette) -> highlighting::Theme {
+    let comment = mix(palette.text, palette.background, 0.45);
+    let function = mix(palette.primary, palette.text, 0.4);
+    let kind = mix(palette.success, palette.primary, 0.5);
+    let constant = mix(palette.danger, palette.primary, 0.4);
+
+    let rules: [(&str, Color, Option<FontStyle>); 10] = [
+        (
+            "comment, punctuation.definition.comment",
+            comment,
+            Some(FontStyle::ITALIC),
+        ),
+        ("string, constant.character", palette.success, None),
+        ("constant, support.constant", constant, None),
+        (
+            "keyword, storage, keyword.operator.word",
+            palette.primary,
+            None,
+        ),
+        (
+            "entity.name.function, support.function, meta.function-call",
+            function,
+            None,
+        ),
+        (
+            "entity.name.type, entity.name.class, support.type, \
+             storage.type, support.class",
+            kind,
+            None,
+        ),
+        (
+            "entity.name.tag, entity.other.attribute-name",
+            function,
+            None,
+        ),
+        ("invalid, message.error", palette.danger, None),
+        ("markup.heading", palette.primary, Some(FontStyle::BOLD)),
+        ("markup.quote, markup.raw", comment, None),
+    ];
+
+    let scopes = rules
+        .into_iter()
+        .map(|(selectors, color, font_style)| ThemeItem {
+            scope: ScopeSelectors::from_str(selectors)
+                .expect("Valid scope selectors"),
+            style: StyleModifier {
+                foreground: Some(to_syntect(color)),
+                background: None,
+                font_style,
+            },
+        })
+        .collect();
+
+    highlighting::Theme {
+        name: Some(name(palette)),
+        author: None,
+        settings: ThemeSettings {
+            foreground: Some(to_syntect(palette.text)),
+            background: Some(to_syntect(palette.background)),
+            caret: Some(to_syntect(palette.primary)),
+            selection: Some(to_syntect(palette.primary.scale_alpha(0.3))),
+            ..ThemeSettings::default()
+        },
+        scopes,
+    }
+}
+
+fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
+    }
+}
+
+fn to_syntect(color: Color) -> highlighting::Color {
+    let [r, g, b, a] = color.into_rgba8();
+
+    highlighting::Color { r, g, b, a }
+}
@@ -0,0 +1,265 @@
+//! Register custom syntaxes and themes for highlighting.
+//!
+//! Any [`Highlighter`]—and any code block highlighted with one—looks for its
+//! syntax and [`Theme`] in this registry.
+//!
+//! # Example
+//! ```no_run
+//! use iced_highlighter::{registry, Settings};
+//!
+//! registry::load_syntax("syntaxes/my-language.sublime-syntax")
+//!     .expect("Load syntax");
+//!
+//! let theme = registry::load_theme("themes/my-theme.tmTheme")
+//!     .expect("Load theme");
+//!
+//! let settings = Settings {
+//!     theme,
+//!     token: "my-language".to_owned(),
+//! };
+//! ```
+//!
+//! [`Highlighter`]: crate::Highlighter
+use crate::Theme;
+
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use syntect::highlighting;
+use syntect::parsing;
+
+static REGISTRY: Lazy<RwLock<Registry>> = Lazy::new(|| {
+    RwLock::new(Registry {
+        syntaxes: Arc::new(parsing::SyntaxSet::load_defaults_nonewlines()),
+        themes: BTreeMap::new(),
+    })
+});
+
+static BUILT_IN_THEMES: Lazy<BTreeMap<String, Arc<highlighting::Theme>>> =
+    Lazy::new(|| {
+        highlighting::ThemeSet::load_defaults()
+            .themes
+            .into_iter()
+            .map(|(name, theme)| (name, Arc::new(theme)))
+            .collect()
+    });
+
+struct Registry {
+    syntaxes: Arc<parsing::SyntaxSet>,
+    themes: BTreeMap<Arc<str>, Arc<highlighting::Theme>>,
+}
+
+/// Registers a syntax from the contents of a `.sublime-syntax` file.
+///
+/// The syntax will be available to any [`Highlighter`] created
+/// afterwards, using its name or any of its file extensions as the token.
+///
+/// Registering a syntax rebuilds the whole set of syntaxes—including the
+/// built-in ones—which can take a while. Use [`add_syntaxes`] to register
+/// many syntaxes at once.
+///
+/// [`Highlighter`]: crate::Highlighter
+pub fn add_syntax(definition: &str) -> Result<(), Error> {
+    add_syntaxes([definition])
+}
+
+/// Registers many syntaxes from the contents of their `.sublime-syntax`
+/// files; rebuilding the set of syntaxes only once.
+///
+/// If any definition is invalid, no syntax is registered.
+///
+/// See [`add_syntax`] for more details.
+pub fn add_syntaxes<'a>(
+    definitions: impl IntoIterator<Item = &'a str>,
+) -> Result<(), Error> {
+    let syntaxes = definitions
+        .into_iter()
+        .map(|definition| {
+            parsing::SyntaxDefinition::load_from_str(definition, false, None)
+                .map_err(|error| Error::InvalidSyntax(error.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut registry = REGISTRY.write().expect("Write syntax registry");
+
+    let mut builder = registry.syntaxes.as_ref().clone().into_builder();
+
+    for syntax in syntaxes {
+        builder.add(syntax);
+    }
+
+    registry.syntaxes = Arc::new(builder.build());
+
+    Ok(())
+}
+
+/// Registers a syntax from the `.sublime-syntax` file at the given path.
+///
+/// See [`add_syntax`] for more details.
+pub fn load_syntax(path: impl AsRef<Path>) -> Result<(), Error> {
+    let definition = std::fs::read_to_string(path)?;
+
+    add_syntax(&definition)
+}
+
+/// Registers a theme with the given name from the contents of a `.tmTheme`
+/// file.
+///
+/// It returns the [`Theme::Custom`] that can be used to select the new
+/// theme. Any previous theme with the same name will be replaced.
+pub fn add_theme(
+    name: impl Into<Arc<str>>,
+    definition: &str,
+) -> Result<Theme, Error> {
+    let theme = highlighting::ThemeSet::load_from_reader(&mut io::Cursor::new(
+        definition,
+    ))
+    .map_err(|error| Error::InvalidTheme(error.to_string()))?;
+
+    Ok(insert_theme(name.into(), theme))
+}
+
+/// Registers a theme from the `.tmTheme` file at the given path.
+///
+/// The theme is named after the file; without its extension.
+/// See [`add_theme`] for more details.
+pub fn load_theme(path: impl AsRef<Path>) -> Result<Theme, Error> {
+    let path = path.as_ref();
+    let definition = std::fs::read_to_string(path)?;
+
+    let name = path
+        .file_stem()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    add_theme(name, &definition)
+}
+
+/// Returns all the available themes; including the built-in ones.
+pub fn themes() -> Vec<Theme> {
+    let registry = REGISTRY.read().expect("Read theme registry");
+
+    Theme::ALL
+        .iter()
+        .cloned()
+        .chain(registry.themes.keys().cloned().map(Theme::Custom))
+        .collect()
+}
+
+pub(crate) fn insert_theme(
+    name: Arc<str>,
+    theme: highlighting::Theme,
+) -> Theme {
+    let _ = REGISTRY
+        .write()
+        .expect("Write theme registry")
+        .themes
+        .insert(name.clone(), Arc::new(theme));
+
+    Theme::Custom(name)
+}
+
+pub(crate) fn contains_theme(name: &str) -> bool {
+    REGISTRY
+        .read()
+        .expect("Read theme registry")
+        .themes
+        .contains_key(name)
+}
+
+pub(crate) fn syntaxes() -> Arc<parsing::SyntaxSet> {
+    REGISTRY
+        .read()
+        .expect("Read syntax registry")
+        .syntaxes
+        .clone()
+}
+
+/// Returns the highlighting theme of the given [`Theme`].
+///
+/// Unknown custom themes fall back to a built-in theme.
+pub(crate) fn theme(theme: &Theme) -> Arc<highlighting::Theme> {
+    if let Theme::Custom(name) = theme {
+        let registry = REGISTRY.read().expect("Read theme registry");
+
+        if let Some(theme) = registry.themes.get(name) {
+            return theme.clone();
+        }
+    }
+
+    BUILT_IN_THEMES
+        .get(theme.key())
+        .unwrap_or_else(|| &BUILT_IN_THEMES[Theme::Base16Ocean.key()])
+        .clone()
+}
+
+/// An error that occurred while registering a syntax or a theme.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The definition file could not be read.
+    #[error("the definition could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The syntax definition is invalid.
+    #[error("invalid syntax definition: {0}")]
+    InvalidSyntax(String),
+
+    /// The theme definition is invalid.
+    #[error("invalid theme definition: {0}")]
+    InvalidTheme(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::highlighter::Highlighter as _;
+    use crate::core::theme;
+    use crate::{Highlighter, Settings};
+
+    #[test]
+    fn custom_syntax_and_palette_theme() {
+        add_syntax(
+            r#"%YAML 1.2
+---
+name: Greeting
+file_extensions: [greeting]
+scope: source.greeting
+contexts:
+  main:
+    - match: '\bhello\b'
+      scope: keyword.greeting
+"#,
+        )
+        .expect("Add syntax");
+
+        let theme = Theme::from_palette(theme::Palette::DARK);
+
+        assert_eq!(Theme::from_palette(theme::Palette::DARK), theme);
+        assert!(theme.is_dark());
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme,
+            token: String::from("greeting"),
+        });
+
+        let highlights: Vec<_> = highlighter
+            .highlight_line("hello world")
+            .map(|(range, highlight)| (range, highlight.color()))
+            .collect();
+
+        assert_eq!(highlights[0].0, 0..5);
+        assert_eq!(
+            highlights[0].1.map(crate::core::Color::into_rgba8),
+            Some(theme::Palette::DARK.primary.into_rgba8())
+        );
+    }
+}
@@ -0,0 +1,630 @@
+//! Highlight code using [tree-sitter] grammars.
+//!
+//! Unlike the default [`Highlighter`](crate::Highlighter), this highlighter
+//! builds a concrete syntax tree of the whole text. The tree is reparsed
+//! incrementally as lines change, which keeps highlighting fast on large
+//! files.
+//!
+//! [tree-sitter]: https://tree-sitter.github.io
+//!
+//! # Example
+//! ```
+//! use iced_core::text::highlighter::Highlighter as _;
+//! use iced_core::theme::Palette;
+//! use iced_highlighter::tree_sitter::{Highlighter, Language, Settings, Theme};
+//!
+//! let rust = Language::new(
+//!     "rust",
+//!     tree_sitter_rust::LANGUAGE,
+//!     tree_sitter_rust::HIGHLIGHTS_QUERY,
+//! )
+//! .expect("Valid highlights query");
+//!
+//! let settings = Settings {
+//!     language: rust,
+//!     theme: Theme::from_palette(Palette::DARK),
+//! };
+//!
+//! let mut highlighter = Highlighter::new(&settings);
+//! let spans: Vec<_> = highlighter.highlight_line("fn main() {}").collect();
+//!
+//! // `fn` is a keyword and `main` a function
+//! assert_eq!(spans[0].0, 0..2);
+//! assert_eq!(spans[1].0, 3..7);
+//! ```
+use crate::core::font::{self, Font};
+use crate::core::text::highlighter::{self, Format};
+use crate::core::theme;
+use crate::core::Color;
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+use streaming_iterator::StreamingIterator;
+
+/// A tree-sitter grammar together with the query used to highlight it.
+#[derive(Clone)]
+pub struct Language {
+    name: Arc<str>,
+    language: ::tree_sitter::Language,
+    query: Arc<::tree_sitter::Query>,
+}
+
+impl Language {
+    /// Creates a new [`Language`] with the given name, grammar, and
+    /// highlights query.
+    ///
+    /// Grammar crates usually expose both the grammar and the query;
+    /// as `LANGUAGE` and `HIGHLIGHTS_QUERY`, respectively.
+    pub fn new(
+        name: impl Into<Arc<str>>,
+        language: impl Into<::tree_sitter::Language>,
+        highlights_query: &str,
+    ) -> Result<Self, Error> {
+        let language = language.into();
+
+        let query = ::tree_sitter::Query::new(&language, highlights_query)
+            .map_err(|error| Error::InvalidQuery(error.to_string()))?;
+
+        Ok(Self {
+            name: name.into(),
+            language,
+            query: Arc::new(query),
+        })
+    }
+
+    /// Returns the name of the [`Language`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Arc::ptr_eq(&self.query, &other.query)
+    }
+}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Language").field(&self.name).finish()
+    }
+}
+
+/// A mapping between capture names of a highlights query and the
+/// [`Format`] of the text they capture.
+///
+/// Capture names are dot-separated; like `function.method`. A capture uses
+/// the format of its longest matching name. For instance, a
+/// `function.method` capture will use the format of `function` if there is
+/// no format for `function.method`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Theme {
+    captures: Vec<(String, Format<Font>)>,
+}
+
+impl Theme {
+    /// Creates an empty [`Theme`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`Format`] of the given capture name.
+    pub fn capture(
+        mut self,
+        name: impl Into<String>,
+        format: Format<Font>,
+    ) -> Self {
+        let name = name.into();
+
+        self.captures.retain(|(capture, _)| *capture != name);
+        self.captures.push((name, format));
+        self
+    }
+
+    /// Creates a [`Theme`] matching the colors of the given
+    /// [`theme::Palette`].
+    pub fn from_palette(palette: theme::Palette) -> Self {
+        let mix = |a: Color, b: Color, factor: f32| Color {
+            r: a.r + (b.r - a.r) * factor,
+            g: a.g + (b.g - a.g) * factor,
+            b: a.b + (b.b - a.b) * factor,
+            a: a.a + (b.a - a.a) * factor,
+        };
+
+        let color = |color| Format {
+            color: Some(color),
+            ..Format::default()
+        };
+
+        let comment = mix(palette.text, palette.background, 0.45);
+        let function = mix(palette.primary, palette.text, 0.4);
+        let kind = mix(palette.success, palette.primary, 0.5);
+        let constant = mix(palette.danger, palette.primary, 0.4);
+
+        Self::new()
+            .capture(
+                "comment",
+                Format {
+                    color: Some(comment),
+                    font: Some(Font {
+                        style: font::Style::Italic,
+                        ..Font::MONOSPACE
+                    }),
+                    ..Format::default()
+                },
+            )
+            .capture("string", color(palette.success))
+            .capture("escape", color(constant))
+            .capture("constant", color(constant))
+            .capture("number", color(constant))
+            .capture("boolean", color(constant))
+            .capture("keyword", color(palette.primary))
+            .capture("operator", color(comment))
+            .capture("function", color(function))
+            .capture("constructor", color(kind))
+            .capture("type", color(kind))
+            .capture("attribute", color(function))
+            .capture("tag", color(function))
+            .capture("label", color(constant))
+            .capture("error", color(palette.danger))
+    }
+
+    /// Resolves the [`Format`] of each capture name, in order.
+    fn resolve(&self, names: &[&str]) -> Vec<Option<Format<Font>>> {
+        names
+            .iter()
+            .map(|name| {
+                let mut name = *name;
+
+                loop {
+                    if let Some((_, format)) = self
+                        .captures
+                        .iter()
+                        .find(|(capture, _)| capture == name)
+                    {
+                        return Some(*format);
+                    }
+
+                    name = &name[..name.rfind('.')?];
+                }
+            })
+            .collect()
+    }
+}
+
+/// The settings of a [`Highlighter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// The [`Language`] of the text.
+    pub language: Language,
+
+    /// The [`Theme`] of the [`Highlighter`].
+    pub theme: Theme,
+}
+
+/// A syntax highlighter backed by tree-sitter.
+///
+/// The [`Highlighter`] keeps the full text of every line it has seen; so
+/// when some lines change, it can describe the exact edit to its syntax
+/// tree and reparse it incrementally. Lines that did not change are
+/// highlighted straight from the existing tree.
+pub struct Highlighter {
+    parser: ::tree_sitter::Parser,
+    cursor: ::tree_sitter::QueryCursor,
+    tree: Option<::tree_sitter::Tree>,
+    is_stale: bool,
+    language: Language,
+    formats: Vec<Option<Format<Font>>>,
+    source: String,
+    line_starts: Vec<usize>,
+    current_line: usize,
+    displaced: Vec<String>,
+    spans: Vec<(Range<usize>, Highlight)>,
+}
+
+impl Highlighter {
+    fn configure(&mut self, settings: &Settings) {
+        if self.language != settings.language {
+            self.parser
+                .set_language(&settings.language.language)
+                .expect("Compatible tree-sitter grammar");
+
+            self.language = settings.language.clone();
+            self.tree = None;
+        }
+
+        self.formats =
+            settings.theme.resolve(self.language.query.capture_names());
+    }
+
+    /// Returns the text of the given line, without its line break.
+    fn line(&self, row: usize) -> Option<&str> {
+        let start = *self.line_starts.get(row)?;
+        let end = self.line_start(row + 1);
+
+        Some(&self.source[start..end - 1])
+    }
+
+    /// Returns the byte offset where the given line starts; or the end of
+    /// the source if there is no such line.
+    fn line_start(&self, row: usize) -> usize {
+        self.line_starts
+            .get(row)
+            .copied()
+            .unwrap_or(self.source.len())
+    }
+
+    fn position(&self, byte: usize) -> ::tree_sitter::Point {
+        if byte == self.source.len() {
+            return ::tree_sitter::Point::new(self.line_starts.len(), 0);
+        }
+
+        let row = self.line_starts.partition_point(|start| *start <= byte) - 1;
+
+        ::tree_sitter::Point::new(row, byte - self.line_starts[row])
+    }
+
+    /// Replaces the given range of bytes of the source with some text,
+    /// editing the syntax tree accordingly.
+    fn splice(&mut self, range: Range<usize>, text: &str) {
+        let start_position = self.position(range.start);
+        let old_end_position = self.position(range.end);
+
+        let new_end_position = match text.rfind('\n') {
+            Some(last) => ::tree_sitter::Point::new(
+                start_position.row + text.matches('\n').count(),
+                text.len() - last - 1,
+            ),
+            None => ::tree_sitter::Point::new(
+                start_position.row,
+                start_position.column + text.len(),
+            ),
+        };
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&::tree_sitter::InputEdit {
+                start_byte: range.start,
+                old_end_byte: range.end,
+                new_end_byte: range.start + text.len(),
+                start_position,
+                old_end_position,
+                new_end_position,
+            });
+        }
+
+        self.source.replace_range(range.clone(), text);
+
+        let row = start_position.row;
+        let from = range.start - start_position.column;
+
+        self.line_starts.truncate(row);
+        self.line_starts.extend(
+            std::iter::once(from)
+                .chain(
+                    self.source[from..]
+                        .match_indices('\n')
+                        .map(|(index, _)| from + index + 1),
+                )
+                .filter(|start| *start < self.source.len()),
+        );
+
+        self.is_stale = true;
+    }
+
+    /// Makes the given line of the source match the given text.
+    ///
+    /// Lines that were replaced or removed since the last change are kept
+    /// aside; so they can be brought back with a single edit if they turn
+    /// out to have been shifted by an insertion.
+    fn sync(&mut self, row: usize, text: &str) {
+        if self.line(row) == Some(text) {
+            return;
+        }
+
+        let at = self.line_start(row);
+
+        if let Some(index) = self.displaced.iter().position(|line| line == text)
+        {
+            let lines: String = self
+                .displaced
+                .drain(..)
+                .skip(index)
+                .flat_map(|line| [line, String::from("\n")])
+                .collect();
+
+            self.splice(at..at, &lines);
+            return;
+        }
+
+        if let Some(removed) = (row + 1..self.line_starts.len())
+            .find(|next| self.line(*next) == Some(text))
+        {
+            let end = self.line_start(removed);
+
+            self.displaced
+                .extend(self.source[at..end].lines().map(ToOwned::to_owned));
+            self.splice(at..end, "");
+            return;
+        }
+
+        let Some(old) = self.line(row).map(str::to_owned) else {
+            self.splice(at..at, &format!("{text}\n"));
+            return;
+        };
+
+        let prefix = old
+            .char_indices()
+            .zip(text.chars())
+            .find(|((_, a), b)| a != b)
+            .map(|((index, _), _)| index)
+            .unwrap_or(old.len().min(text.len()));
+
+        let suffix = old[prefix..]
+            .chars()
+            .rev()
+            .zip(text[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum::<usize>();
+
+        self.splice(
+            at + prefix..at + old.len() - suffix,
+            &text[prefix..text.len() - suffix],
+        );
+        self.displaced.push(old);
+    }
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> =
+        std::iter::Cloned<std::slice::Iter<'a, (Range<usize>, Highlight)>>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let mut parser = ::tree_sitter::Parser::new();
+
+        parser
+            .set_language(&settings.language.language)
+            .expect("Compatible tree-sitter grammar");
+
+        Self {
+            parser,
+            cursor: ::tree_sitter::QueryCursor::new(),
+            tree: None,
+            is_stale: true,
+            language: settings.language.clone(),
+            formats: settings
+                .theme
+                .resolve(settings.language.query.capture_names()),
+            source: String::new(),
+            line_starts: Vec::new(),
+            current_line: 0,
+            displaced: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.configure(new_settings);
+
+        // Restart the highlighter
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+        self.displaced.clear();
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let row = self.current_line;
+        self.current_line += 1;
+
+        self.sync(row, line);
+        self.spans.clear();
+
+        if self.is_stale || self.tree.is_none() {
+            self.tree = self.parser.parse(&self.source, self.tree.as_ref());
+            self.is_stale = false;
+        }
+
+        let Some(tree) = &self.tree else {
+            return self.spans.iter().cloned();
+        };
+
+        let start = self.line_starts[row];
+
+        // The format of each byte of the line; inner captures override
+        // outer ones
+        let mut formats = vec![None; line.len()];
+        let mut last_node = None;
+
+        let _ = self.cursor.set_byte_range(start..start + line.len());
+
+        let mut captures = self.cursor.captures(
+            &self.language.query,
+            tree.root_node(),
+            self.source.as_bytes(),
+        );
+
+        while let Some((matched, index)) = captures.next() {
+            let capture = matched.captures[*index];
+
+            // The first pattern matching a node takes precedence
+            if last_node == Some(capture.node.id()) {
+                continue;
+            }
+
+            let Some(format) = self.formats[capture.index as usize] else {
+                continue;
+            };
+
+            last_node = Some(capture.node.id());
+
+            let range = capture.node.byte_range();
+            let from = range.start.max(start) - start;
+            let to = range.end.min(start + line.len()).saturating_sub(start);
+
+            for byte in formats.iter_mut().take(to).skip(from) {
+                *byte = Some(format);
+            }
+        }
+
+        let mut from = 0;
+
+        for to in 1..=formats.len() {
+            if to == formats.len() || formats[to] != formats[from] {
+                if let Some(format) = formats[from] {
+                    self.spans.push((from..to, Highlight(format)));
+                }
+
+                from = to;
+            }
+        }
+
+        self.spans.iter().cloned()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+impl fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("language", &self.language)
+            .field("current_line", &self.current_line)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A highlight produced by a [`Highlighter`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Highlight(Format<Font>);
+
+impl Highlight {
+    /// Returns the color of this [`Highlight`].
+    ///
+    /// If `None`, the original text color should be unchanged.
+    pub fn color(&self) -> Option<Color> {
+        self.0.color
+    }
+
+    /// Returns the font of this [`Highlight`].
+    ///
+    /// If `None`, the original font should be unchanged.
+    pub fn font(&self) -> Option<Font> {
+        self.0.font
+    }
+
+    /// Returns the [`Format`] of the [`Highlight`].
+    pub fn to_format(&self) -> Format<Font> {
+        self.0
+    }
+}
+
+/// An error that occurred while creating a [`Language`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The highlights query is invalid for the grammar.
+    #[error("invalid highlights query: {0}")]
+    InvalidQuery(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::highlighter::Highlighter as _;
+
+    const SOURCE: &str = "\
+fn main() {
+    let greeting = \"hello\";
+
+    println!(\"{greeting}\");
+}
+";
+
+    fn settings() -> Settings {
+        Settings {
+            language: Language::new(
+                "rust",
+                tree_sitter_rust::LANGUAGE,
+                tree_sitter_rust::HIGHLIGHTS_QUERY,
+            )
+            .expect("Valid highlights query"),
+            theme: Theme::from_palette(theme::Palette::DARK),
+        }
+    }
+
+    /// Highlights the lines of the text starting at the current line of
+    /// the highlighter.
+    fn highlight(
+        highlighter: &mut Highlighter,
+        text: &str,
+    ) -> Vec<Vec<(Range<usize>, Highlight)>> {
+        text.lines()
+            .skip(highlighter.current_line())
+            .map(|line| highlighter.highlight_line(line).collect())
+            .collect()
+    }
+
+    /// Changes the text of the highlighter from the given line and checks
+    /// that the result matches highlighting the new text from scratch.
+    fn change(highlighter: &mut Highlighter, text: &str, line: usize) {
+        highlighter.change_line(line);
+
+        let incremental = highlight(highlighter, text);
+        let scratch = highlight(&mut Highlighter::new(&settings()), text);
+
+        assert_eq!(incremental, scratch[line..]);
+        assert_eq!(highlighter.source, text);
+        assert!(!highlighter.is_stale);
+    }
+
+    #[test]
+    fn edits_are_applied_incrementally() {
+        let mut highlighter = Highlighter::new(&settings());
+        let _ = highlight(&mut highlighter, SOURCE);
+
+        // Typing in a line
+        let text = SOURCE.replace("hello", "hello, wörld");
+        change(&mut highlighter, &text, 1);
+
+        // Breaking a line
+        let text = text.replace("let greeting", "let\n    greeting");
+        change(&mut highlighter, &text, 1);
+
+        // Joining it back
+        let text = text.replace("let\n    greeting", "let greeting");
+        change(&mut highlighter, &text, 1);
+
+        // Pasting some lines
+        let text = text.replace("\n\n", "\n    // Some\n    // comments\n\n");
+        change(&mut highlighter, &text, 2);
+
+        // Removing them
+        let text = text.replace("    // Some\n    // comments\n", "");
+        change(&mut highlighter, &text, 2);
+
+        // Commenting out the rest of the text
+        let text = text.replace("fn main() {\n", "fn main() {\n/*\n");
+        change(&mut highlighter, &text, 1);
+    }
+
+    #[test]
+    fn lines_after_a_change_are_kept() {
+        let mut highlighter = Highlighter::new(&settings());
+        let _ = highlight(&mut highlighter, SOURCE);
+
+        highlighter.change_line(0);
+        let _ = highlighter.highlight_line("fn start() {");
+
+        assert_eq!(highlighter.current_line(), 1);
+        assert_eq!(highlighter.source, SOURCE.replace("main", "start"));
+    }
+}
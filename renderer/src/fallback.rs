@@ -53,6 +53,18 @@ where
         delegate!(self, renderer, renderer.start_layer(bounds));
     }
 
+    fn start_layer_with_effect(
+        &mut self,
+        bounds: Rectangle,
+        effect: renderer::Effect,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.start_layer_with_effect(bounds, effect)
+        );
+    }
+
     fn end_layer(&mut self) {
         delegate!(self, renderer, renderer.end_layer());
     }
@@ -149,6 +161,10 @@ where
         delegate!(self, renderer, renderer.measure_image(handle))
     }
 
+    fn image_state(&self, handle: &Self::Handle) -> image::State {
+        delegate!(self, renderer, renderer.image_state(handle))
+    }
+
     fn draw_image(&mut self, image: Image<A::Handle>, bounds: Rectangle) {
         delegate!(self, renderer, renderer.draw_image(image, bounds));
     }
@@ -307,14 +323,45 @@ where
         }
     }
 
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
-        delegate!(self, compositor, compositor.load_font(font));
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), core::font::Error> {
+        delegate!(self, compositor, compositor.load_font(font))
     }
 
     fn fetch_information(&self) -> compositor::Information {
         delegate!(self, compositor, compositor.fetch_information())
     }
 
+    fn evict_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &image::Handle,
+    ) {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.evict_image(renderer, handle);
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.evict_image(renderer, handle);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn image_metrics(&self, renderer: &Self::Renderer) -> image::Metrics {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.image_metrics(renderer)
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.image_metrics(renderer)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -408,6 +455,25 @@ where
             }
         }
     }
+
+    fn start_layer_with_primitive(
+        &mut self,
+        bounds: Rectangle,
+        primitive: impl iced_wgpu::Primitive,
+    ) {
+        match self {
+            Self::Primary(renderer) => {
+                renderer.start_layer_with_primitive(bounds, primitive);
+            }
+            Self::Secondary(renderer) => {
+                log::warn!(
+                    "Custom shader primitive is not supported with this renderer."
+                );
+
+                renderer.start_layer(bounds);
+            }
+        }
+    }
 }
 
 #[cfg(feature = "geometry")]
@@ -416,6 +482,7 @@ mod geometry {
     use crate::core::{Point, Radians, Rectangle, Size, Svg, Vector};
     use crate::graphics::cache::{self, Cached};
     use crate::graphics::geometry::{self, Fill, Image, Path, Stroke, Text};
+    use crate::graphics::mesh::{Indexed, TexturedVertex2D};
 
     impl<A, B> geometry::Renderer for Renderer<A, B>
     where
@@ -561,6 +628,14 @@ mod geometry {
             delegate!(self, frame, frame.draw_image(bounds, image));
         }
 
+        fn draw_textured_mesh(
+            &mut self,
+            buffers: Indexed<TexturedVertex2D>,
+            image: impl Into<Image>,
+        ) {
+            delegate!(self, frame, frame.draw_textured_mesh(buffers, image));
+        }
+
         fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
             delegate!(self, frame, frame.draw_svg(bounds, svg));
         }
@@ -1,16 +1,138 @@
 //! Load and use fonts.
+use crate::core::font::{Family, Font, Script};
+use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
-use crate::Action;
+
 use std::borrow::Cow;
+use std::fmt;
+
+pub use crate::core::font::Error;
+
+/// A font action to be performed by some [`Task`].
+pub enum Action {
+    /// Load a font from its bytes.
+    Load {
+        /// The bytes of the font to load.
+        bytes: Cow<'static, [u8]>,
+        /// The channel to send back the load result.
+        channel: oneshot::Sender<Result<(), Error>>,
+    },
+
+    /// List the names of the available font families.
+    ListFamilies(oneshot::Sender<Vec<String>>),
+
+    /// Query whether the given [`Font`] is available.
+    IsAvailable(Font, oneshot::Sender<bool>),
+
+    /// Set the font family used for a generic [`Family`].
+    SetFamily {
+        /// The generic [`Family`] to configure.
+        generic: Family,
+        /// The name of the font family to use instead.
+        name: String,
+    },
 
-/// An error while loading a font.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
+    /// Set the chain of fallback font families of a [`Script`].
+    SetFallback {
+        /// The [`Script`] to configure.
+        script: Script,
+        /// The names of the font families to try, in order.
+        families: Vec<&'static str>,
+    },
+
+    /// Set whether the fonts installed in the system can be used.
+    UseSystemFonts(bool),
+}
+
+impl fmt::Debug for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load { bytes, .. } => f
+                .debug_struct("Load")
+                .field("bytes", &bytes.len())
+                .finish_non_exhaustive(),
+            Self::ListFamilies(_) => write!(f, "ListFamilies"),
+            Self::IsAvailable(font, _) => {
+                f.debug_tuple("IsAvailable").field(font).finish()
+            }
+            Self::SetFamily { generic, name } => f
+                .debug_struct("SetFamily")
+                .field("generic", generic)
+                .field("name", name)
+                .finish(),
+            Self::SetFallback { script, families } => f
+                .debug_struct("SetFallback")
+                .field("script", script)
+                .field("families", families)
+                .finish(),
+            Self::UseSystemFonts(enabled) => {
+                f.debug_tuple("UseSystemFonts").field(enabled).finish()
+            }
+        }
+    }
+}
 
 /// Load a font from its bytes.
 pub fn load(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
-    task::oneshot(|channel| Action::LoadFont {
-        bytes: bytes.into(),
-        channel,
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::Load {
+            bytes: bytes.into(),
+            channel,
+        })
     })
 }
+
+/// Lists the names of all the available font families, sorted
+/// alphabetically.
+pub fn families() -> Task<Vec<String>> {
+    task::oneshot(|channel| crate::Action::Font(Action::ListFamilies(channel)))
+}
+
+/// Queries whether a font face with the exact family, weight, stretch and
+/// style of the given [`Font`] is available.
+///
+/// Generic families are resolved to their configured family first.
+pub fn is_available(font: Font) -> Task<bool> {
+    task::oneshot(move |channel| {
+        crate::Action::Font(Action::IsAvailable(font, channel))
+    })
+}
+
+/// Sets the font family used to render text with the given generic
+/// [`Family`]; like [`Family::SansSerif`] or [`Family::Monospace`].
+///
+/// Setting the family of a [`Family::Name`] has no effect.
+pub fn set_family<T>(generic: Family, name: impl Into<String>) -> Task<T> {
+    task::effect(crate::Action::Font(Action::SetFamily {
+        generic,
+        name: name.into(),
+    }))
+}
+
+/// Sets the chain of font families used to render text in the given
+/// [`Script`]; like CJK, emoji or Arabic.
+///
+/// The first family of the chain that is available is used for every
+/// character of the [`Script`], regardless of the [`Font`] of the text.
+/// An empty chain restores the default fallback behavior.
+pub fn set_fallback<T>(
+    script: Script,
+    families: impl IntoIterator<Item = &'static str>,
+) -> Task<T> {
+    task::effect(crate::Action::Font(Action::SetFallback {
+        script,
+        families: families.into_iter().collect(),
+    }))
+}
+
+/// Sets whether the fonts installed in the system can be used to render
+/// text.
+///
+/// When disabled, only the fonts loaded by the application are used; both
+/// for matching and for fallback. This makes rendering independent of the
+/// fonts installed in each machine.
+///
+/// System fonts are enabled by default.
+pub fn use_system_fonts<T>(enabled: bool) -> Task<T> {
+    task::effect(crate::Action::Font(Action::UseSystemFonts(enabled)))
+}
@@ -0,0 +1,32 @@
+//! Manage the image caches of the renderer.
+use crate::core::image::{Handle, Metrics};
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+/// An image action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Evict the image with the given [`Handle`] from the caches of
+    /// every renderer.
+    Evict(Handle),
+
+    /// Fetch the [`Metrics`] of the image caches of every renderer.
+    FetchMetrics(oneshot::Sender<Metrics>),
+}
+
+/// Evicts the image with the given [`Handle`] from the caches of the
+/// renderer; freeing its memory.
+///
+/// This is useful to drop large images that you know will not be
+/// displayed again. If the image is drawn afterwards, it will be
+/// loaded again.
+pub fn evict<T>(handle: impl Into<Handle>) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Evict(handle.into())))
+}
+
+/// Fetches the [`Metrics`] of the image caches of the renderer.
+///
+/// Only raster images are accounted for; rasterized SVGs are not included.
+pub fn metrics() -> Task<Metrics> {
+    task::oneshot(|channel| crate::Action::Image(Action::FetchMetrics(channel)))
+}
@@ -11,8 +11,11 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod clipboard;
 pub mod font;
+pub mod image;
 pub mod keyboard;
 pub mod overlay;
+#[cfg(feature = "persistence")]
+pub mod persistence;
 pub mod program;
 pub mod system;
 pub mod task;
@@ -40,9 +43,7 @@ pub use task::Task;
 pub use user_interface::UserInterface;
 
 use crate::core::widget;
-use crate::futures::futures::channel::oneshot;
 
-use std::borrow::Cow;
 use std::fmt;
 
 /// An action that the iced runtime can perform.
@@ -50,13 +51,8 @@ pub enum Action<T> {
     /// Output some value.
     Output(T),
 
-    /// Load a font from its bytes.
-    LoadFont {
-        /// The bytes of the font to load.
-        bytes: Cow<'static, [u8]>,
-        /// The channel to send back the load result.
-        channel: oneshot::Sender<Result<(), font::Error>>,
-    },
+    /// Run a font action.
+    Font(font::Action),
 
     /// Run a widget operation.
     Widget(Box<dyn widget::Operation>),
@@ -70,6 +66,9 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action),
 
+    /// Run an image action.
+    Image(image::Action),
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -86,13 +85,12 @@ impl<T> Action<T> {
     fn output<O>(self) -> Result<T, Action<O>> {
         match self {
             Action::Output(output) => Ok(output),
-            Action::LoadFont { bytes, channel } => {
-                Err(Action::LoadFont { bytes, channel })
-            }
+            Action::Font(action) => Err(Action::Font(action)),
             Action::Widget(operation) => Err(Action::Widget(operation)),
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
+            Action::Image(action) => Err(Action::Image(action)),
             Action::Exit => Err(Action::Exit),
         }
     }
@@ -105,9 +103,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Action::Output(output) => write!(f, "Action::Output({output:?})"),
-            Action::LoadFont { .. } => {
-                write!(f, "Action::LoadFont")
-            }
+            Action::Font(action) => write!(f, "Action::Font({action:?})"),
             Action::Widget { .. } => {
                 write!(f, "Action::Widget")
             }
@@ -116,6 +112,7 @@ where
             }
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
+            Action::Image(action) => write!(f, "Action::Image({action:?})"),
             Action::Exit => write!(f, "Action::Exit"),
         }
     }
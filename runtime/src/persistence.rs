@@ -0,0 +1,300 @@
+//! Save and restore the state of your application.
+//!
+//! A [`Storage`] keeps a set of serializable entries by key. It can be used
+//! to remember any state of an application across restarts; like the
+//! [`Snapshot`] of its windows or the layout of a `PaneGrid`.
+//!
+//! [`Snapshot`]: crate::core::window::Snapshot
+//!
+//! Windows with a [`persistence_key`] are persisted automatically when the
+//! shell is given a [`Storage`]: their [`Snapshot`] is restored when they are
+//! opened and saved when they are closed—or when the application exits.
+//!
+//! [`persistence_key`]: crate::core::window::Settings::persistence_key
+//!
+//! # Example
+//! ```no_run
+//! use iced_runtime::core::window;
+//! use iced_runtime::persistence::Storage;
+//!
+//! let storage = Storage::file("state.json");
+//!
+//! // On startup
+//! let settings = match storage.load::<window::Snapshot>("main") {
+//!     Ok(Some(snapshot)) => snapshot.apply(window::Settings::default()),
+//!     _ => window::Settings::default(),
+//! };
+//!
+//! // Before closing the window, if not persisted automatically
+//! # let snapshot: window::Snapshot = todo!();
+//! storage.save("main", &snapshot).expect("Save window snapshot");
+//! ```
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// A place where the state of an application can be persisted.
+///
+/// A [`Storage`] can be cloned cheaply. Any clones share the same entries.
+#[derive(Clone)]
+pub struct Storage {
+    backend: Backend,
+}
+
+#[derive(Clone)]
+enum Backend {
+    File(PathBuf),
+    Memory(Arc<Mutex<BTreeMap<String, String>>>),
+    Custom {
+        load: Arc<dyn Fn(&str) -> Option<String> + Send + Sync>,
+        save: Arc<dyn Fn(&str, String) + Send + Sync>,
+    },
+}
+
+impl Storage {
+    /// Creates a [`Storage`] that persists its entries as a JSON object in
+    /// the file at the given path.
+    ///
+    /// The file—and any missing parent directories—will be created the first
+    /// time an entry is saved.
+    ///
+    /// The file is replaced atomically on every save. If it cannot be parsed
+    /// when saving—for instance, because it was edited by hand—it is moved
+    /// aside with a `.corrupt` extension and a new file is started.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            backend: Backend::File(path.into()),
+        }
+    }
+
+    /// Creates a [`Storage`] that keeps its entries in memory.
+    ///
+    /// This can be useful for testing.
+    pub fn memory() -> Self {
+        Self {
+            backend: Backend::Memory(Arc::default()),
+        }
+    }
+
+    /// Creates a [`Storage`] with custom `load` and `save` callbacks.
+    ///
+    /// The callbacks receive the key of an entry and deal with its value
+    /// already serialized as JSON.
+    pub fn custom(
+        load: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+        save: impl Fn(&str, String) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            backend: Backend::Custom {
+                load: Arc::new(load),
+                save: Arc::new(save),
+            },
+        }
+    }
+
+    /// Loads the entry with the given key, if it exists.
+    pub fn load<T: DeserializeOwned>(
+        &self,
+        key: &str,
+    ) -> Result<Option<T>, Error> {
+        let Some(value) = self.read(key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(serde_json::from_str(&value)?))
+    }
+
+    /// Saves the given value as the entry with the given key, replacing any
+    /// previous value.
+    pub fn save<T: Serialize + ?Sized>(
+        &self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.write(key, serde_json::to_string(value)?)
+    }
+
+    fn read(&self, key: &str) -> Result<Option<String>, Error> {
+        match &self.backend {
+            Backend::File(path) => {
+                let mut entries = read_file(path)?;
+
+                Ok(entries.remove(key).map(|value| value.to_string()))
+            }
+            Backend::Memory(entries) => Ok(entries
+                .lock()
+                .expect("Lock memory storage")
+                .get(key)
+                .cloned()),
+            Backend::Custom { load, .. } => Ok(load(key)),
+        }
+    }
+
+    fn write(&self, key: &str, value: String) -> Result<(), Error> {
+        match &self.backend {
+            Backend::File(path) => {
+                let mut entries = match read_file(path) {
+                    Ok(entries) => entries,
+                    Err(Error::Format(_)) => {
+                        // Keep the unreadable file around, but do not let it
+                        // break every save that follows
+                        std::fs::rename(path, sibling(path, "corrupt"))?;
+
+                        BTreeMap::new()
+                    }
+                    Err(error) => return Err(error),
+                };
+
+                let _ = entries
+                    .insert(key.to_owned(), serde_json::from_str(&value)?);
+
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+
+                // Write to a temporary file first, so a crash never leaves
+                // a truncated file behind
+                let temporary = sibling(path, "tmp");
+
+                std::fs::write(
+                    &temporary,
+                    serde_json::to_string_pretty(&entries)?,
+                )?;
+                std::fs::rename(temporary, path)?;
+
+                Ok(())
+            }
+            Backend::Memory(entries) => {
+                let _ = entries
+                    .lock()
+                    .expect("Lock memory storage")
+                    .insert(key.to_owned(), value);
+
+                Ok(())
+            }
+            Backend::Custom { save, .. } => {
+                save(key, value);
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn read_file(
+    path: &std::path::Path,
+) -> Result<BTreeMap<String, serde_json::Value>, Error> {
+    match std::fs::read(path) {
+        Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            Ok(BTreeMap::new())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the path of a file next to the given one, with the given
+/// extension appended to its name.
+fn sibling(path: &std::path::Path, extension: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(".");
+    name.push(extension);
+
+    path.with_file_name(name)
+}
+
+impl fmt::Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.backend {
+            Backend::File(path) => {
+                f.debug_tuple("Storage::File").field(path).finish()
+            }
+            Backend::Memory(_) => f.write_str("Storage::Memory"),
+            Backend::Custom { .. } => f.write_str("Storage::Custom"),
+        }
+    }
+}
+
+/// An error that occurred while loading or saving an entry of a [`Storage`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The storage could not be read or written.
+    #[error("the storage could not be accessed: {0}")]
+    Io(Arc<io::Error>),
+
+    /// An entry could not be serialized or deserialized.
+    #[error("the entry could not be (de)serialized: {0}")]
+    Format(Arc<serde_json::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Format(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::window::{Mode, Snapshot};
+    use crate::core::{Point, Size};
+
+    #[test]
+    fn memory_round_trip() {
+        let storage = Storage::memory();
+
+        let snapshot = Snapshot {
+            position: Some(Point::new(100.0, 50.0)),
+            size: Size::new(800.0, 600.0),
+            maximized: false,
+            mode: Mode::Windowed,
+        };
+
+        assert_eq!(storage.load::<Snapshot>("main").unwrap(), None);
+
+        storage.save("main", &snapshot).unwrap();
+
+        assert_eq!(storage.clone().load("main").unwrap(), Some(snapshot));
+        assert!(storage.load::<Vec<u32>>("main").is_err());
+    }
+
+    #[test]
+    fn corrupt_files_are_replaced() {
+        let directory = std::env::temp_dir()
+            .join(format!("iced_persistence_{}", std::process::id()));
+        let path = directory.join("state.json");
+
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(&path, "{\"main\": [1, 2").unwrap();
+
+        let storage = Storage::file(&path);
+
+        assert!(storage.load::<Vec<u32>>("main").is_err());
+
+        storage.save("main", &[1, 2, 3]).unwrap();
+        storage.save("other", &[4]).unwrap();
+
+        assert_eq!(storage.load("main").unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(storage.load("other").unwrap(), Some(vec![4]));
+        assert_eq!(
+            std::fs::read_to_string(directory.join("state.json.corrupt"))
+                .unwrap(),
+            "{\"main\": [1, 2"
+        );
+        assert!(!directory.join("state.json.tmp").exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
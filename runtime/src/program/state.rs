@@ -77,7 +77,7 @@ where
 
     /// Returns the current [`mouse::Interaction`] of the [`State`].
     pub fn mouse_interaction(&self) -> mouse::Interaction {
-        self.mouse_interaction
+        self.mouse_interaction.clone()
     }
 
     /// Processes all the queued events and messages, rebuilding and redrawing
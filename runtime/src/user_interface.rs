@@ -555,7 +555,7 @@ where
                         {
                             overlay_interaction
                         } else {
-                            base_interaction
+                            base_interaction.clone()
                         }
                     })
             })
@@ -5,7 +5,7 @@ pub use screenshot::Screenshot;
 
 use crate::core::time::Instant;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    CursorGrab, Event, Icon, Id, Level, Mode, Settings, Snapshot, UserAttention,
 };
 use crate::core::{Point, Size};
 use crate::futures::event;
@@ -77,6 +77,9 @@ pub enum Action {
     /// Get the current [`Mode`] of the window.
     GetMode(Id, oneshot::Sender<Mode>),
 
+    /// Take a [`Snapshot`] of the window.
+    GetSnapshot(Id, oneshot::Sender<Snapshot>),
+
     /// Toggle the window to maximized or back
     ToggleMaximize(Id),
 
@@ -159,6 +162,17 @@ pub enum Action {
     /// This enables mouse events for the window and stops mouse events
     /// from being passed to whatever is underneath.
     DisableMousePassthrough(Id),
+
+    /// Change the [`CursorGrab`] mode of the window.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android:** Unsupported.
+    /// - **Windows / X11:** [`CursorGrab::Locked`] falls back to
+    ///   [`CursorGrab::Confined`].
+    ChangeCursorGrab(Id, CursorGrab),
+
+    /// Redraw all the windows.
+    RedrawAll,
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -331,6 +345,16 @@ pub fn get_mode(id: Id) -> Task<Mode> {
     })
 }
 
+/// Takes a [`Snapshot`] of the window with the given [`Id`].
+///
+/// Taking a [`Snapshot`] of a window right before closing it—and storing
+/// it somewhere—allows restoring the window the next time it is opened.
+pub fn snapshot(id: Id) -> Task<Snapshot> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetSnapshot(id, channel))
+    })
+}
+
 /// Toggles the window to maximized or back.
 pub fn toggle_maximize<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::ToggleMaximize(id)))
@@ -434,3 +458,14 @@ pub fn enable_mouse_passthrough<Message>(id: Id) -> Task<Message> {
 pub fn disable_mouse_passthrough<Message>(id: Id) -> Task<Message> {
     task::effect(crate::Action::Window(Action::DisableMousePassthrough(id)))
 }
+
+/// Changes the [`CursorGrab`] mode of the window.
+///
+/// Grabbing the cursor can be useful to implement camera controls for a
+/// 3D viewport. While [`CursorGrab::Locked`], the movements of the mouse
+/// will be reported as raw [`mouse::Event::RawMotion`] events.
+///
+/// [`mouse::Event::RawMotion`]: crate::core::mouse::Event::RawMotion
+pub fn change_cursor_grab<Message>(id: Id, grab: CursorGrab) -> Task<Message> {
+    task::effect(crate::Action::Window(Action::ChangeCursorGrab(id, grab)))
+}
@@ -529,6 +529,13 @@ pub mod clipboard {
     };
 }
 
+#[cfg(feature = "image")]
+pub mod image {
+    //! Manage the image caches of the renderer.
+    pub use crate::core::image::{Error, Handle, Metrics, State};
+    pub use crate::runtime::image::{evict, metrics};
+}
+
 pub mod executor {
     //! Choose your preferred executor to power your application.
     pub use iced_futures::Executor;
@@ -563,17 +570,26 @@ pub mod event {
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
-    pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
+    pub use crate::core::keyboard::shortcut;
+    pub use crate::core::keyboard::{
+        Event, Key, Location, Modifiers, Shortcut,
+    };
     pub use iced_futures::keyboard::{on_key_press, on_key_release};
 }
 
 pub mod mouse {
     //! Listen and react to mouse events.
     pub use crate::core::mouse::{
-        Button, Cursor, Event, Interaction, ScrollDelta,
+        Button, Cursor, CustomCursor, Event, Interaction, ScrollDelta,
     };
 }
 
+#[cfg(feature = "persistence")]
+pub mod persistence {
+    //! Save and restore the state of your application.
+    pub use crate::runtime::persistence::*;
+}
+
 #[cfg(feature = "system")]
 pub mod system {
     //! Retrieve system information.
@@ -171,6 +171,7 @@ pub trait Program: Sized {
             } else {
                 None
             },
+            image_budget: settings.image_budget,
             ..crate::graphics::Settings::default()
         };
 
@@ -184,6 +185,9 @@ pub trait Program: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 antialiasing: settings.antialiasing,
+                image_budget: settings.image_budget,
+                #[cfg(feature = "persistence")]
+                storage: settings.storage,
             }
             .into(),
             renderer_settings,
@@ -35,6 +35,33 @@ pub struct Settings {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// The maximum amount of memory, in bytes, that cached images may use.
+    ///
+    /// Once exceeded, the least recently used images that are not being
+    /// displayed will be evicted. Images can also be evicted explicitly
+    /// with [`image::evict`].
+    ///
+    /// Only raster images count towards the budget; rasterized SVGs do not.
+    /// The budget is ignored by the software renderer, which only evicts
+    /// images once they stop being displayed.
+    ///
+    /// By default, it is `None`; and images are only evicted when they
+    /// stop being displayed.
+    ///
+    /// [`image::evict`]: crate::image::evict
+    pub image_budget: Option<u64>,
+
+    /// The [`Storage`] used to persist the [`Snapshot`] of any window with a
+    /// [`persistence_key`].
+    ///
+    /// By default, it is `None`; and windows are not persisted.
+    ///
+    /// [`Storage`]: crate::persistence::Storage
+    /// [`Snapshot`]: crate::window::Snapshot
+    /// [`persistence_key`]: crate::window::Settings::persistence_key
+    #[cfg(feature = "persistence")]
+    pub storage: Option<crate::persistence::Storage>,
 }
 
 impl Default for Settings {
@@ -45,6 +72,9 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            image_budget: None,
+            #[cfg(feature = "persistence")]
+            storage: None,
         }
     }
 }
@@ -54,6 +84,8 @@ impl From<Settings> for iced_winit::Settings {
         iced_winit::Settings {
             id: settings.id,
             fonts: settings.fonts,
+            #[cfg(feature = "persistence")]
+            storage: settings.storage,
         }
     }
 }
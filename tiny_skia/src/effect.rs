@@ -0,0 +1,187 @@
+use crate::core::{Rectangle, Size};
+
+/// Blurs the pixels inside the given physical bounds in place.
+///
+/// The blur approximates a gaussian blur with a standard deviation of half
+/// the given radius by applying three consecutive box blurs.
+pub fn blur(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle,
+    radius: f32,
+) {
+    let sigma = radius / 2.0;
+
+    if sigma < 0.5 {
+        return;
+    }
+
+    let width = pixels.width() as usize;
+    let height = pixels.height() as usize;
+
+    let boxes = box_sizes(sigma);
+    let spread: usize = boxes.iter().sum();
+
+    let clamp = |value: f32, max: usize| (value.max(0.0) as usize).min(max);
+
+    let left = clamp(bounds.x.floor(), width);
+    let top = clamp(bounds.y.floor(), height);
+    let right = clamp((bounds.x + bounds.width).ceil(), width);
+    let bottom = clamp((bounds.y + bounds.height).ceil(), height);
+
+    if left >= right || top >= bottom {
+        return;
+    }
+
+    // Blurring needs the pixels around the bounds, too
+    let region_left = left.saturating_sub(spread);
+    let region_top = top.saturating_sub(spread);
+    let region_right = (right + spread).min(width);
+    let region_bottom = (bottom + spread).min(height);
+
+    let region_width = region_right - region_left;
+    let region_height = region_bottom - region_top;
+
+    let data = pixels.data_mut();
+
+    let mut buffer: Vec<[f32; 4]> =
+        Vec::with_capacity(region_width * region_height);
+
+    for y in region_top..region_bottom {
+        for x in region_left..region_right {
+            let i = (y * width + x) * 4;
+
+            buffer.push([
+                f32::from(data[i]),
+                f32::from(data[i + 1]),
+                f32::from(data[i + 2]),
+                f32::from(data[i + 3]),
+            ]);
+        }
+    }
+
+    let mut scratch = buffer.clone();
+
+    for radius in boxes {
+        box_blur(
+            &buffer,
+            &mut scratch,
+            Size::new(region_width, region_height),
+            Direction::Horizontal,
+            radius,
+        );
+
+        box_blur(
+            &scratch,
+            &mut buffer,
+            Size::new(region_width, region_height),
+            Direction::Vertical,
+            radius,
+        );
+    }
+
+    for y in top..bottom {
+        for x in left..right {
+            let [r, g, b, a] =
+                buffer[(y - region_top) * region_width + (x - region_left)];
+
+            let i = (y * width + x) * 4;
+            let alpha = a.round().clamp(0.0, 255.0);
+
+            // Colors are premultiplied and must never exceed alpha
+            data[i] = r.round().clamp(0.0, alpha) as u8;
+            data[i + 1] = g.round().clamp(0.0, alpha) as u8;
+            data[i + 2] = b.round().clamp(0.0, alpha) as u8;
+            data[i + 3] = alpha as u8;
+        }
+    }
+}
+
+/// Computes the radii of three box blurs approximating a gaussian blur
+/// with the given standard deviation.
+fn box_sizes(sigma: f32) -> [usize; 3] {
+    let ideal = (12.0 * sigma * sigma / 3.0 + 1.0).sqrt();
+
+    let mut lower = ideal.floor() as usize;
+
+    if lower % 2 == 0 {
+        lower = lower.saturating_sub(1);
+    }
+
+    let upper = lower + 2;
+
+    let lower_f = lower as f32;
+    let count = ((12.0 * sigma * sigma
+        - 3.0 * lower_f * lower_f
+        - 12.0 * lower_f
+        - 9.0)
+        / (-4.0 * lower_f - 4.0))
+        .round() as usize;
+
+    let mut sizes = [0; 3];
+
+    for (i, size) in sizes.iter_mut().enumerate() {
+        let diameter = if i < count { lower } else { upper };
+
+        *size = diameter / 2;
+    }
+
+    sizes
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Horizontal,
+    Vertical,
+}
+
+/// Applies a box blur with the given radius in a single [`Direction`],
+/// storing the result in the target.
+fn box_blur(
+    source: &[[f32; 4]],
+    target: &mut [[f32; 4]],
+    size: Size<usize>,
+    direction: Direction,
+    radius: usize,
+) {
+    if radius == 0 {
+        target.copy_from_slice(source);
+        return;
+    }
+
+    let (lines, length, line_stride, step) = match direction {
+        Direction::Horizontal => (size.height, size.width, size.width, 1),
+        Direction::Vertical => (size.width, size.height, 1, size.width),
+    };
+
+    let scale = 1.0 / (2 * radius + 1) as f32;
+
+    for line in 0..lines {
+        let start = line * line_stride;
+        let pixel = |i: isize| {
+            let i = i.clamp(0, length as isize - 1) as usize;
+
+            source[start + i * step]
+        };
+
+        let mut sum = [0.0; 4];
+
+        for i in -(radius as isize)..=radius as isize {
+            for (sum, channel) in sum.iter_mut().zip(pixel(i)) {
+                *sum += channel;
+            }
+        }
+
+        for i in 0..length {
+            target[start + i * step] = sum.map(|channel| channel * scale);
+
+            let next = pixel(i as isize + radius as isize + 1);
+            let previous = pixel(i as isize - radius as isize);
+
+            for ((sum, next), previous) in
+                sum.iter_mut().zip(next).zip(previous)
+            {
+                *sum += next - previous;
+            }
+        }
+    }
+}
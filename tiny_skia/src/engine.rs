@@ -1,8 +1,9 @@
 use crate::core::renderer::Quad;
 use crate::core::{
-    Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
+    Background, Color, Gradient, Rectangle, Shadow, Size, Transformation,
+    Vector,
 };
-use crate::graphics::{Image, Text};
+use crate::graphics::{Image, Mesh, Text};
 use crate::text;
 use crate::Primitive;
 
@@ -73,81 +74,18 @@ impl Engine {
 
         let path = rounded_rectangle(quad.bounds, fill_border_radius);
 
-        let shadow = quad.shadow;
-
-        if shadow.color.a > 0.0 {
-            let shadow_bounds = Rectangle {
-                x: quad.bounds.x + shadow.offset.x - shadow.blur_radius,
-                y: quad.bounds.y + shadow.offset.y - shadow.blur_radius,
-                width: quad.bounds.width + shadow.blur_radius * 2.0,
-                height: quad.bounds.height + shadow.blur_radius * 2.0,
-            } * transformation;
-
-            let radii = fill_border_radius
-                .into_iter()
-                .map(|radius| radius * transformation.scale_factor())
-                .collect::<Vec<_>>();
-            let (x, y, width, height) = (
-                shadow_bounds.x as u32,
-                shadow_bounds.y as u32,
-                shadow_bounds.width as u32,
-                shadow_bounds.height as u32,
-            );
-            let half_width = physical_bounds.width / 2.0;
-            let half_height = physical_bounds.height / 2.0;
-
-            let colors = (y..y + height)
-                .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
-                .filter_map(|(x, y)| {
-                    tiny_skia::Size::from_wh(half_width, half_height).map(
-                        |size| {
-                            let shadow_distance = rounded_box_sdf(
-                                Vector::new(
-                                    x - physical_bounds.position().x
-                                        - (shadow.offset.x
-                                            * transformation.scale_factor())
-                                        - half_width,
-                                    y - physical_bounds.position().y
-                                        - (shadow.offset.y
-                                            * transformation.scale_factor())
-                                        - half_height,
-                                ),
-                                size,
-                                &radii,
-                            )
-                            .max(0.0);
-                            let shadow_alpha = 1.0
-                                - smoothstep(
-                                    -shadow.blur_radius
-                                        * transformation.scale_factor(),
-                                    shadow.blur_radius
-                                        * transformation.scale_factor(),
-                                    shadow_distance,
-                                );
-
-                            let mut color = into_color(shadow.color);
-                            color.apply_opacity(shadow_alpha);
-
-                            color.to_color_u8().premultiply()
-                        },
-                    )
-                })
-                .collect();
-
-            if let Some(pixmap) = tiny_skia::IntSize::from_wh(width, height)
-                .and_then(|size| {
-                    tiny_skia::Pixmap::from_vec(
-                        bytemuck::cast_vec(colors),
-                        size,
-                    )
-                })
-            {
-                pixels.draw_pixmap(
-                    x as i32,
-                    y as i32,
-                    pixmap.as_ref(),
-                    &tiny_skia::PixmapPaint::default(),
-                    tiny_skia::Transform::default(),
+        let radii = fill_border_radius
+            .map(|radius| radius * transformation.scale_factor());
+
+        // The first shadow is drawn on top
+        for shadow in quad.shadows.iter().rev() {
+            if !shadow.inset && shadow.color.a > 0.0 {
+                draw_shadow(
+                    shadow,
+                    physical_bounds,
+                    &radii,
+                    transformation,
+                    pixels,
                     None,
                 );
             }
@@ -210,6 +148,19 @@ impl Engine {
             clip_mask,
         );
 
+        for shadow in quad.shadows.iter().rev() {
+            if shadow.inset && shadow.color.a > 0.0 {
+                draw_shadow(
+                    shadow,
+                    physical_bounds,
+                    &radii,
+                    transformation,
+                    pixels,
+                    clip_mask,
+                );
+            }
+        }
+
         if border_width > 0.0 {
             // Border path is offset by half the border width
             let border_bounds = Rectangle {
@@ -572,6 +523,7 @@ impl Engine {
                 self.raster_pipeline.draw(
                     &raster.handle,
                     raster.filter_method,
+                    raster.source,
                     *bounds,
                     raster.opacity,
                     _pixels,
@@ -624,6 +576,51 @@ impl Engine {
         }
     }
 
+    pub fn draw_mesh(
+        &mut self,
+        mesh: &Mesh,
+        _transformation: Transformation,
+        _pixels: &mut tiny_skia::PixmapMut<'_>,
+        _clip_mask: &mut tiny_skia::Mask,
+        _clip_bounds: Rectangle,
+    ) {
+        match mesh {
+            #[cfg(feature = "image")]
+            Mesh::Textured {
+                buffers,
+                image,
+                transformation,
+                ..
+            } => {
+                let Some(clip_bounds) = _clip_bounds
+                    .intersection(&(mesh.clip_bounds() * _transformation))
+                else {
+                    return;
+                };
+
+                adjust_clip_mask(_clip_mask, clip_bounds);
+
+                self.raster_pipeline.draw_mesh(
+                    &image.handle,
+                    image.filter_method,
+                    image.source,
+                    buffers,
+                    image.opacity,
+                    _pixels,
+                    into_transform(*transformation * _transformation),
+                    Some(_clip_mask),
+                );
+
+                adjust_clip_mask(_clip_mask, _clip_bounds);
+            }
+            _ => {
+                log::warn!(
+                    "Unsupported primitive in `iced_tiny_skia`: {mesh:?}",
+                );
+            }
+        }
+    }
+
     pub fn trim(&mut self) {
         self.text_pipeline.trim_cache();
 
@@ -797,6 +794,91 @@ fn arc_to(
     }
 }
 
+fn draw_shadow(
+    shadow: &Shadow,
+    physical_bounds: Rectangle,
+    radii: &[f32],
+    transformation: Transformation,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let scale_factor = transformation.scale_factor();
+    let blur_radius = shadow.blur_radius * scale_factor;
+    let offset = shadow.offset * scale_factor;
+
+    // Inner shadows are only visible inside the bounds of the quad
+    let shadow_bounds = if shadow.inset {
+        physical_bounds
+    } else {
+        Rectangle {
+            x: physical_bounds.x + offset.x - blur_radius,
+            y: physical_bounds.y + offset.y - blur_radius,
+            width: physical_bounds.width + blur_radius * 2.0,
+            height: physical_bounds.height + blur_radius * 2.0,
+        }
+    };
+
+    let (x, y, width, height) = (
+        shadow_bounds.x as u32,
+        shadow_bounds.y as u32,
+        shadow_bounds.width as u32,
+        shadow_bounds.height as u32,
+    );
+    let half_width = physical_bounds.width / 2.0;
+    let half_height = physical_bounds.height / 2.0;
+
+    let Some(size) = tiny_skia::Size::from_wh(half_width, half_height) else {
+        return;
+    };
+
+    let colors = (y..y + height)
+        .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
+        .map(|(x, y)| {
+            let to_center = Vector::new(
+                x - physical_bounds.x - half_width,
+                y - physical_bounds.y - half_height,
+            );
+
+            let shadow_alpha = if shadow.inset {
+                let blur_radius = blur_radius.max(0.5);
+
+                let quad_distance =
+                    signed_rounded_box_sdf(to_center, size, radii);
+                let shadow_distance =
+                    signed_rounded_box_sdf(to_center - offset, size, radii);
+
+                (1.0 - smoothstep(-0.5, 0.5, quad_distance))
+                    * smoothstep(-blur_radius, blur_radius, shadow_distance)
+            } else {
+                let shadow_distance =
+                    rounded_box_sdf(to_center - offset, size, radii).max(0.0);
+
+                1.0 - smoothstep(-blur_radius, blur_radius, shadow_distance)
+            };
+
+            let mut color = into_color(shadow.color);
+            color.apply_opacity(shadow_alpha);
+
+            color.to_color_u8().premultiply()
+        })
+        .collect();
+
+    if let Some(pixmap) =
+        tiny_skia::IntSize::from_wh(width, height).and_then(|size| {
+            tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(colors), size)
+        })
+    {
+        pixels.draw_pixmap(
+            x as i32,
+            y as i32,
+            pixmap.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::default(),
+            clip_mask,
+        );
+    }
+}
+
 fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
     let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
 
@@ -821,6 +903,27 @@ fn rounded_box_sdf(
     (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
 }
 
+fn signed_rounded_box_sdf(
+    to_center: Vector,
+    size: tiny_skia::Size,
+    radii: &[f32],
+) -> f32 {
+    let radius = match (to_center.x > 0.0, to_center.y > 0.0) {
+        (true, true) => radii[2],
+        (true, false) => radii[1],
+        (false, true) => radii[3],
+        (false, false) => radii[0],
+    };
+
+    let x = to_center.x.abs() - size.width() + radius;
+    let y = to_center.y.abs() - size.height() + radius;
+
+    let outside = (x.max(0.0).powf(2.0) + y.max(0.0).powf(2.0)).sqrt();
+    let inside = x.max(y).min(0.0);
+
+    outside + inside - radius
+}
+
 pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
     clip_mask.clear();
 
@@ -846,3 +949,101 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GREEN: Color = Color::from_rgb(0.0, 1.0, 0.0);
+
+    fn shadow(color: Color, offset: f32) -> Shadow {
+        Shadow {
+            color,
+            offset: Vector::new(offset, offset),
+            blur_radius: 0.0,
+            inset: false,
+        }
+    }
+
+    /// Draws a white 40x40 quad at (30, 30) with the given shadows.
+    fn draw(shadows: impl Into<crate::core::Shadows>) -> tiny_skia::Pixmap {
+        let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+        let mut clip_mask = tiny_skia::Mask::new(100, 100).unwrap();
+        clip_mask.fill_path(
+            &tiny_skia::PathBuilder::from_rect(
+                tiny_skia::Rect::from_xywh(0.0, 0.0, 100.0, 100.0).unwrap(),
+            ),
+            tiny_skia::FillRule::EvenOdd,
+            false,
+            tiny_skia::Transform::identity(),
+        );
+
+        Engine::new().draw_quad(
+            &Quad {
+                bounds: Rectangle::new(
+                    crate::core::Point::new(30.0, 30.0),
+                    Size::new(40.0, 40.0),
+                ),
+                shadows: shadows.into(),
+                ..Quad::default()
+            },
+            &Background::Color(Color::WHITE),
+            Transformation::IDENTITY,
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            Rectangle::with_size(Size::new(100.0, 100.0)),
+        );
+
+        pixmap
+    }
+
+    fn pixel(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let pixel = pixmap.pixel(x, y).unwrap();
+
+        [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+    }
+
+    #[test]
+    fn first_outer_shadow_is_drawn_on_top() {
+        let pixmap = draw([shadow(GREEN, 10.0), shadow(Color::BLACK, 10.0)]);
+
+        assert_eq!(pixel(&pixmap, 75, 75), [0, 255, 0, 255]);
+        assert_eq!(pixel(&pixmap, 50, 50), [255, 255, 255, 255]);
+
+        let pixmap = draw([shadow(Color::BLACK, 10.0), shadow(GREEN, 10.0)]);
+
+        assert_eq!(pixel(&pixmap, 75, 75), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn outer_shadows_are_composed() {
+        let pixmap = draw([shadow(GREEN, 5.0), shadow(Color::BLACK, 15.0)]);
+
+        // The closest shadow is only covered by the quad
+        assert_eq!(pixel(&pixmap, 72, 72), [0, 255, 0, 255]);
+        // The farthest shadow is only visible past the closest one
+        assert_eq!(pixel(&pixmap, 80, 80), [0, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 90, 90), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn inner_shadows_stay_inside_the_quad() {
+        let pixmap = draw(shadow(Color::BLACK, 5.0).inset());
+
+        // The edge not covered by the offset shadow shape is shadowed
+        assert_eq!(pixel(&pixmap, 32, 32), [0, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 50, 50), [255, 255, 255, 255]);
+        assert_eq!(pixel(&pixmap, 28, 28), [0, 0, 0, 0]);
+        assert_eq!(pixel(&pixmap, 72, 72), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn inner_and_outer_shadows_are_composed() {
+        let pixmap =
+            draw([shadow(Color::BLACK, 5.0).inset(), shadow(GREEN, 10.0)]);
+
+        assert_eq!(pixel(&pixmap, 32, 32), [0, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 50, 50), [255, 255, 255, 255]);
+        assert_eq!(pixel(&pixmap, 75, 75), [0, 255, 0, 255]);
+    }
+}
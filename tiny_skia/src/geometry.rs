@@ -4,6 +4,7 @@ use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{self, Path, Style};
+use crate::graphics::mesh::{self, Mesh};
 use crate::graphics::{self, Gradient, Image, Text};
 use crate::Primitive;
 
@@ -14,6 +15,7 @@ pub enum Geometry {
     Live {
         text: Vec<Text>,
         images: Vec<graphics::Image>,
+        meshes: Vec<Mesh>,
         primitives: Vec<Primitive>,
         clip_bounds: Rectangle,
     },
@@ -24,6 +26,7 @@ pub enum Geometry {
 pub struct Cache {
     pub text: Rc<[Text]>,
     pub images: Rc<[graphics::Image]>,
+    pub meshes: Rc<[Mesh]>,
     pub primitives: Rc<[Primitive]>,
     pub clip_bounds: Rectangle,
 }
@@ -40,11 +43,13 @@ impl Cached for Geometry {
             Self::Live {
                 primitives,
                 images,
+                meshes,
                 text,
                 clip_bounds,
             } => Cache {
                 primitives: Rc::from(primitives),
                 images: Rc::from(images),
+                meshes: Rc::from(meshes),
                 text: Rc::from(text),
                 clip_bounds,
             },
@@ -60,6 +65,7 @@ pub struct Frame {
     stack: Vec<tiny_skia::Transform>,
     primitives: Vec<Primitive>,
     images: Vec<graphics::Image>,
+    meshes: Vec<Mesh>,
     text: Vec<Text>,
 }
 
@@ -74,6 +80,7 @@ impl Frame {
             stack: Vec::new(),
             primitives: Vec::new(),
             images: Vec::new(),
+            meshes: Vec::new(),
             text: Vec::new(),
             transform: tiny_skia::Transform::from_translate(
                 clip_bounds.x,
@@ -257,6 +264,7 @@ impl geometry::frame::Backend for Frame {
         self.primitives.extend(frame.primitives);
         self.text.extend(frame.text);
         self.images.extend(frame.images);
+        self.meshes.extend(frame.meshes);
     }
 
     fn translate(&mut self, translation: Vector) {
@@ -286,6 +294,7 @@ impl geometry::frame::Backend for Frame {
         Geometry::Live {
             primitives: self.primitives,
             images: self.images,
+            meshes: self.meshes,
             text: self.text,
             clip_bounds: self.clip_bounds,
         }
@@ -302,6 +311,30 @@ impl geometry::frame::Backend for Frame {
         self.images.push(graphics::Image::Raster(image, bounds));
     }
 
+    fn draw_textured_mesh(
+        &mut self,
+        mut buffers: mesh::Indexed<mesh::TexturedVertex2D>,
+        image: impl Into<core::Image>,
+    ) {
+        for vertex in &mut buffers.vertices {
+            let mut position = tiny_skia::Point {
+                x: vertex.position[0],
+                y: vertex.position[1],
+            };
+
+            self.transform.map_point(&mut position);
+
+            vertex.position = [position.x, position.y];
+        }
+
+        self.meshes.push(Mesh::Textured {
+            buffers,
+            image: image.into(),
+            transformation: core::Transformation::IDENTITY,
+            clip_bounds: self.clip_bounds,
+        });
+    }
+
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
         let mut svg = svg.into();
 
@@ -5,7 +5,7 @@ use crate::core::{
 use crate::graphics::damage;
 use crate::graphics::layer;
 use crate::graphics::text::{Editor, Paragraph, Text};
-use crate::graphics::{self, Image};
+use crate::graphics::{self, Image, Mesh};
 use crate::Primitive;
 
 use std::rc::Rc;
@@ -19,6 +19,7 @@ pub struct Layer {
     pub primitives: Vec<Item<Primitive>>,
     pub text: Vec<Item<Text>>,
     pub images: Vec<Image>,
+    pub meshes: Vec<Mesh>,
 }
 
 impl Layer {
@@ -148,6 +149,31 @@ impl Layer {
         self.images.push(svg);
     }
 
+    pub fn draw_mesh(
+        &mut self,
+        mut mesh: Mesh,
+        transformation: Transformation,
+    ) {
+        match &mut mesh {
+            Mesh::Solid {
+                transformation: local_transformation,
+                ..
+            }
+            | Mesh::Gradient {
+                transformation: local_transformation,
+                ..
+            }
+            | Mesh::Textured {
+                transformation: local_transformation,
+                ..
+            } => {
+                *local_transformation = *local_transformation * transformation;
+            }
+        }
+
+        self.meshes.push(mesh);
+    }
+
     pub fn draw_primitive_group(
         &mut self,
         primitives: Vec<Primitive>,
@@ -257,9 +283,23 @@ impl Layer {
             Image::eq,
         );
 
+        let meshes = damage::list(
+            &previous.meshes,
+            &current.meshes,
+            |mesh| {
+                mesh.clip_bounds()
+                    .expand(1.0)
+                    .intersection(&current.bounds)
+                    .into_iter()
+                    .collect()
+            },
+            Mesh::eq,
+        );
+
         damage.extend(text);
         damage.extend(primitives);
         damage.extend(images);
+        damage.extend(meshes);
         damage
     }
 }
@@ -272,6 +312,7 @@ impl Default for Layer {
             primitives: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            meshes: Vec::new(),
         }
     }
 }
@@ -297,6 +338,7 @@ impl graphics::Layer for Layer {
         self.primitives.clear();
         self.text.clear();
         self.images.clear();
+        self.meshes.clear();
     }
 }
 
@@ -2,6 +2,7 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod window;
 
+mod effect;
 mod engine;
 mod layer;
 mod primitive;
@@ -33,9 +34,12 @@ use crate::core::{
 };
 use crate::engine::Engine;
 use crate::graphics::compositor;
+use crate::graphics::layer::Group;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::Viewport;
 
+use std::ops::Range;
+
 /// A [`tiny-skia`] graphics renderer for [`iced`].
 ///
 /// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
@@ -63,6 +67,32 @@ impl Renderer {
         self.layers.as_slice()
     }
 
+    pub fn groups(&self) -> &[Group] {
+        self.layers.groups()
+    }
+
+    /// Evicts the image with the given [`Handle`] from the caches of the
+    /// [`Renderer`].
+    ///
+    /// [`Handle`]: core::image::Handle
+    pub fn evict_image(&mut self, _handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        self.engine.raster_pipeline.evict(_handle);
+    }
+
+    /// Returns the [`Metrics`] of the image caches of the [`Renderer`].
+    ///
+    /// [`Metrics`]: core::image::Metrics
+    pub fn image_metrics(&self) -> core::image::Metrics {
+        #[cfg(feature = "image")]
+        let metrics = self.engine.raster_pipeline.metrics();
+
+        #[cfg(not(feature = "image"))]
+        let metrics = core::image::Metrics::default();
+
+        metrics
+    }
+
     pub fn draw<T: AsRef<str>>(
         &mut self,
         pixels: &mut tiny_skia::PixmapMut<'_>,
@@ -134,73 +164,16 @@ impl Renderer {
                 None,
             );
 
-            for layer in self.layers.iter() {
-                let Some(clip_bounds) =
-                    region.intersection(&(layer.bounds * scale_factor))
-                else {
-                    continue;
-                };
-
-                engine::adjust_clip_mask(clip_mask, clip_bounds);
-
-                for (quad, background) in &layer.quads {
-                    self.engine.draw_quad(
-                        quad,
-                        background,
-                        Transformation::scale(scale_factor),
-                        pixels,
-                        clip_mask,
-                        clip_bounds,
-                    );
-                }
-
-                for group in &layer.primitives {
-                    let Some(new_clip_bounds) = (group.clip_bounds()
-                        * scale_factor)
-                        .intersection(&clip_bounds)
-                    else {
-                        continue;
-                    };
-
-                    engine::adjust_clip_mask(clip_mask, new_clip_bounds);
-
-                    for primitive in group.as_slice() {
-                        self.engine.draw_primitive(
-                            primitive,
-                            group.transformation()
-                                * Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-
-                    engine::adjust_clip_mask(clip_mask, clip_bounds);
-                }
-
-                for image in &layer.images {
-                    self.engine.draw_image(
-                        image,
-                        Transformation::scale(scale_factor),
-                        pixels,
-                        clip_mask,
-                        clip_bounds,
-                    );
-                }
-
-                for group in &layer.text {
-                    for text in group.as_slice() {
-                        self.engine.draw_text(
-                            text,
-                            group.transformation()
-                                * Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-                }
-            }
+            draw_layers(
+                &mut self.engine,
+                self.layers.as_slice(),
+                self.layers.groups(),
+                0..self.layers.as_slice().len(),
+                pixels,
+                clip_mask,
+                region,
+                scale_factor,
+            );
 
             if !overlay.is_empty() {
                 pixels.stroke_path(
@@ -226,11 +199,224 @@ impl Renderer {
     }
 }
 
+fn draw_layers(
+    engine: &mut Engine,
+    layers: &[Layer],
+    mut groups: &[Group],
+    range: Range<usize>,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+    region: Rectangle,
+    scale_factor: f32,
+) {
+    let mut index = range.start;
+
+    while index < range.end {
+        match groups.split_first() {
+            Some((group, rest)) if group.layers.start == index => {
+                let nested = rest
+                    .iter()
+                    .take_while(|nested| nested.layers.start < group.layers.end)
+                    .count();
+
+                draw_group(
+                    engine,
+                    layers,
+                    group,
+                    &rest[..nested],
+                    pixels,
+                    clip_mask,
+                    region,
+                    scale_factor,
+                );
+
+                groups = &rest[nested..];
+                index = group.layers.end;
+            }
+            _ => {
+                draw_layer(
+                    engine,
+                    &layers[index],
+                    pixels,
+                    clip_mask,
+                    region,
+                    scale_factor,
+                );
+
+                index += 1;
+            }
+        }
+    }
+}
+
+fn draw_group(
+    engine: &mut Engine,
+    layers: &[Layer],
+    group: &Group,
+    nested: &[Group],
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+    region: Rectangle,
+    scale_factor: f32,
+) {
+    let Some(clip_bounds) = region.intersection(&(group.bounds * scale_factor))
+    else {
+        return;
+    };
+
+    if group.effect.backdrop_blur > 0.0 {
+        effect::blur(
+            pixels,
+            clip_bounds,
+            group.effect.backdrop_blur * scale_factor,
+        );
+    }
+
+    if group.effect.opacity <= 0.0 {
+        return;
+    }
+
+    if group.effect.opacity >= 1.0 {
+        draw_layers(
+            engine,
+            layers,
+            nested,
+            group.layers.clone(),
+            pixels,
+            clip_mask,
+            clip_bounds,
+            scale_factor,
+        );
+
+        return;
+    }
+
+    let Some(mut offscreen) =
+        tiny_skia::Pixmap::new(pixels.width(), pixels.height())
+    else {
+        return;
+    };
+
+    draw_layers(
+        engine,
+        layers,
+        nested,
+        group.layers.clone(),
+        &mut offscreen.as_mut(),
+        clip_mask,
+        clip_bounds,
+        scale_factor,
+    );
+
+    engine::adjust_clip_mask(clip_mask, clip_bounds);
+
+    pixels.draw_pixmap(
+        0,
+        0,
+        offscreen.as_ref(),
+        &tiny_skia::PixmapPaint {
+            opacity: group.effect.opacity,
+            ..tiny_skia::PixmapPaint::default()
+        },
+        tiny_skia::Transform::identity(),
+        Some(clip_mask),
+    );
+}
+
+fn draw_layer(
+    engine: &mut Engine,
+    layer: &Layer,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+    region: Rectangle,
+    scale_factor: f32,
+) {
+    let Some(clip_bounds) = region.intersection(&(layer.bounds * scale_factor))
+    else {
+        return;
+    };
+
+    engine::adjust_clip_mask(clip_mask, clip_bounds);
+
+    for (quad, background) in &layer.quads {
+        engine.draw_quad(
+            quad,
+            background,
+            Transformation::scale(scale_factor),
+            pixels,
+            clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for group in &layer.primitives {
+        let Some(new_clip_bounds) =
+            (group.clip_bounds() * scale_factor).intersection(&clip_bounds)
+        else {
+            continue;
+        };
+
+        engine::adjust_clip_mask(clip_mask, new_clip_bounds);
+
+        for primitive in group.as_slice() {
+            engine.draw_primitive(
+                primitive,
+                group.transformation() * Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+
+        engine::adjust_clip_mask(clip_mask, clip_bounds);
+    }
+
+    for image in &layer.images {
+        engine.draw_image(
+            image,
+            Transformation::scale(scale_factor),
+            pixels,
+            clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for mesh in &layer.meshes {
+        engine.draw_mesh(
+            mesh,
+            Transformation::scale(scale_factor),
+            pixels,
+            clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for group in &layer.text {
+        for text in group.as_slice() {
+            engine.draw_text(
+                text,
+                group.transformation() * Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
     }
 
+    fn start_layer_with_effect(
+        &mut self,
+        bounds: Rectangle,
+        effect: renderer::Effect,
+    ) {
+        self.layers.push_clip_with_effect(bounds, effect);
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
@@ -331,6 +517,7 @@ impl graphics::geometry::Renderer for Renderer {
             Geometry::Live {
                 primitives,
                 images,
+                meshes,
                 text,
                 clip_bounds,
             } => {
@@ -344,6 +531,10 @@ impl graphics::geometry::Renderer for Renderer {
                     layer.draw_image(image, transformation);
                 }
 
+                for mesh in meshes {
+                    layer.draw_mesh(mesh, transformation);
+                }
+
                 layer.draw_text_group(text, clip_bounds, transformation);
             }
             Geometry::Cache(cache) => {
@@ -357,6 +548,10 @@ impl graphics::geometry::Renderer for Renderer {
                     layer.draw_image(image.clone(), transformation);
                 }
 
+                for mesh in cache.meshes.iter() {
+                    layer.draw_mesh(mesh.clone(), transformation);
+                }
+
                 layer.draw_text_cache(
                     cache.text,
                     cache.clip_bounds,
@@ -368,8 +563,18 @@ impl graphics::geometry::Renderer for Renderer {
 }
 
 impl graphics::mesh::Renderer for Renderer {
-    fn draw_mesh(&mut self, _mesh: graphics::Mesh) {
-        log::warn!("iced_tiny_skia does not support drawing meshes");
+    fn draw_mesh(&mut self, mesh: graphics::Mesh) {
+        match mesh {
+            graphics::Mesh::Textured { .. } => {
+                let (layer, transformation) = self.layers.current_mut();
+                layer.draw_mesh(mesh, transformation);
+            }
+            graphics::Mesh::Solid { .. } | graphics::Mesh::Gradient { .. } => {
+                log::warn!(
+                    "iced_tiny_skia does not support drawing untextured meshes"
+                );
+            }
+        }
     }
 }
 
@@ -381,6 +586,10 @@ impl core::image::Renderer for Renderer {
         self.engine.raster_pipeline.dimensions(handle)
     }
 
+    fn image_state(&self, handle: &Self::Handle) -> core::image::State {
+        self.engine.raster_pipeline.state(handle)
+    }
+
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, transformation);
@@ -1,6 +1,7 @@
 use crate::core::image as raster;
 use crate::core::{Rectangle, Size};
 use crate::graphics;
+use crate::graphics::mesh::{Indexed, TexturedVertex2D};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::cell::RefCell;
@@ -19,10 +20,18 @@ impl Pipeline {
     }
 
     pub fn dimensions(&self, handle: &raster::Handle) -> Size<u32> {
-        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
-            Size::new(image.width(), image.height())
-        } else {
-            Size::new(0, 0)
+        match self.cache.borrow_mut().load(handle) {
+            Memory::Loading(size) => *size,
+            Memory::Ready(entry) => Size::new(entry.width, entry.height),
+            Memory::Failed(_) => Size::new(0, 0),
+        }
+    }
+
+    pub fn state(&self, handle: &raster::Handle) -> raster::State {
+        match self.cache.borrow_mut().load(handle) {
+            Memory::Loading(_) => raster::State::Loading,
+            Memory::Ready(_) => raster::State::Loaded,
+            Memory::Failed(error) => raster::State::Failed(*error),
         }
     }
 
@@ -30,6 +39,7 @@ impl Pipeline {
         &mut self,
         handle: &raster::Handle,
         filter_method: raster::FilterMethod,
+        source: Option<Rectangle<u32>>,
         bounds: Rectangle,
         opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
@@ -37,20 +47,52 @@ impl Pipeline {
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
         if let Some(image) = self.cache.borrow_mut().allocate(handle) {
+            let quality = into_quality(filter_method);
+
+            if let Some(source) = source {
+                let Some(rect) = tiny_skia::Rect::from_xywh(
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                ) else {
+                    return;
+                };
+
+                let pattern = tiny_skia::Transform::from_translate(
+                    -(source.x as f32),
+                    -(source.y as f32),
+                )
+                .post_scale(
+                    bounds.width / source.width as f32,
+                    bounds.height / source.height as f32,
+                )
+                .post_translate(bounds.x, bounds.y);
+
+                pixels.fill_rect(
+                    rect,
+                    &tiny_skia::Paint {
+                        shader: tiny_skia::Pattern::new(
+                            image,
+                            tiny_skia::SpreadMode::Pad,
+                            quality,
+                            opacity,
+                            pattern,
+                        ),
+                        ..Default::default()
+                    },
+                    transform,
+                    clip_mask,
+                );
+
+                return;
+            }
+
             let width_scale = bounds.width / image.width() as f32;
             let height_scale = bounds.height / image.height() as f32;
 
             let transform = transform.pre_scale(width_scale, height_scale);
 
-            let quality = match filter_method {
-                raster::FilterMethod::Linear => {
-                    tiny_skia::FilterQuality::Bilinear
-                }
-                raster::FilterMethod::Nearest => {
-                    tiny_skia::FilterQuality::Nearest
-                }
-            };
-
             pixels.draw_pixmap(
                 (bounds.x / width_scale) as i32,
                 (bounds.y / height_scale) as i32,
@@ -66,15 +108,140 @@ impl Pipeline {
         }
     }
 
+    pub fn draw_mesh(
+        &mut self,
+        handle: &raster::Handle,
+        filter_method: raster::FilterMethod,
+        source: Option<Rectangle<u32>>,
+        buffers: &Indexed<TexturedVertex2D>,
+        opacity: f32,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        transform: tiny_skia::Transform,
+        clip_mask: Option<&tiny_skia::Mask>,
+    ) {
+        let mut cache = self.cache.borrow_mut();
+
+        let Some(image) = cache.allocate(handle) else {
+            return;
+        };
+
+        let source = source.unwrap_or(Rectangle {
+            x: 0,
+            y: 0,
+            width: image.width(),
+            height: image.height(),
+        });
+
+        let affine = |[a, b, c]: [tiny_skia::Point; 3]| {
+            tiny_skia::Transform::from_row(
+                b.x - a.x,
+                b.y - a.y,
+                c.x - a.x,
+                c.y - a.y,
+                a.x,
+                a.y,
+            )
+        };
+
+        let vertex = |index: u32| buffers.vertices.get(index as usize);
+
+        for triangle in buffers.indices.chunks_exact(3) {
+            let (Some(a), Some(b), Some(c)) = (
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+            ) else {
+                continue;
+            };
+
+            let positions = [a, b, c].map(|vertex| {
+                let [x, y] = vertex.position;
+
+                tiny_skia::Point { x, y }
+            });
+
+            let texels = [a, b, c].map(|vertex| {
+                let [u, v] = vertex.uv;
+
+                tiny_skia::Point {
+                    x: source.x as f32 + u * source.width as f32,
+                    y: source.y as f32 + v * source.height as f32,
+                }
+            });
+
+            let Some(pattern) = affine(texels)
+                .invert()
+                .map(|texels| texels.post_concat(affine(positions)))
+            else {
+                continue;
+            };
+
+            let path = {
+                let mut builder = tiny_skia::PathBuilder::new();
+
+                builder.move_to(positions[0].x, positions[0].y);
+                builder.line_to(positions[1].x, positions[1].y);
+                builder.line_to(positions[2].x, positions[2].y);
+                builder.close();
+
+                builder.finish()
+            };
+
+            let Some(path) = path else {
+                continue;
+            };
+
+            pixels.fill_path(
+                &path,
+                &tiny_skia::Paint {
+                    shader: tiny_skia::Pattern::new(
+                        image,
+                        tiny_skia::SpreadMode::Pad,
+                        into_quality(filter_method),
+                        opacity,
+                        pattern,
+                    ),
+                    // Anti-aliasing would show the seams between
+                    // adjacent triangles
+                    anti_alias: false,
+                    ..Default::default()
+                },
+                tiny_skia::FillRule::Winding,
+                transform,
+                clip_mask,
+            );
+        }
+    }
+
+    pub fn evict(&mut self, handle: &raster::Handle) {
+        let _ = self.cache.get_mut().entries.remove(&handle.id());
+    }
+
+    pub fn metrics(&self) -> raster::Metrics {
+        self.cache.borrow().metrics()
+    }
+
     pub fn trim_cache(&mut self) {
         self.cache.borrow_mut().trim();
     }
 }
 
+fn into_quality(
+    filter_method: raster::FilterMethod,
+) -> tiny_skia::FilterQuality {
+    match filter_method {
+        raster::FilterMethod::Linear => tiny_skia::FilterQuality::Bilinear,
+        raster::FilterMethod::Nearest => tiny_skia::FilterQuality::Nearest,
+    }
+}
+
 #[derive(Debug, Default)]
 struct Cache {
-    entries: FxHashMap<raster::Id, Option<Entry>>,
+    entries: FxHashMap<raster::Id, Memory>,
     hits: FxHashSet<raster::Id>,
+    hit_count: u64,
+    miss_count: u64,
+    decoder: graphics::image::Decoder,
 }
 
 impl Cache {
@@ -82,38 +249,79 @@ impl Cache {
         &mut self,
         handle: &raster::Handle,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
+        let Memory::Ready(entry) = self.load(handle) else {
+            return None;
+        };
+
+        Some(
+            tiny_skia::PixmapRef::from_bytes(
+                bytemuck::cast_slice(&entry.pixels),
+                entry.width,
+                entry.height,
+            )
+            .expect("Build pixmap from image bytes"),
+        )
+    }
+
+    /// Loads the image of the given handle, decoding it in the background
+    /// if necessary.
+    fn load(&mut self, handle: &raster::Handle) -> &Memory {
+        self.receive();
+
         let id = handle.id();
 
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
-            let image = graphics::image::load(handle).ok()?;
-
-            let mut buffer =
-                vec![0u32; image.width() as usize * image.height() as usize];
+            self.miss_count += 1;
 
-            for (i, pixel) in image.pixels().enumerate() {
-                let [r, g, b, a] = pixel.0;
+            let memory = match handle {
+                raster::Handle::Rgba { .. } => {
+                    Memory::from_result(graphics::image::load(handle))
+                }
+                raster::Handle::Path(..) | raster::Handle::Bytes(..) => {
+                    match graphics::image::dimensions(handle) {
+                        Ok(size) => {
+                            self.decoder.decode(handle);
 
-                buffer[i] = bytemuck::cast(
-                    tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply(),
-                );
-            }
+                            Memory::Loading(size)
+                        }
+                        Err(error) => Memory::from_result(Err(error)),
+                    }
+                }
+            };
 
-            let _ = entry.insert(Some(Entry {
-                width: image.width(),
-                height: image.height(),
-                pixels: buffer,
-            }));
+            let _ = entry.insert(memory);
+        } else {
+            self.hit_count += 1;
         }
 
         let _ = self.hits.insert(id);
-        self.entries.get(&id).unwrap().as_ref().map(|entry| {
-            tiny_skia::PixmapRef::from_bytes(
-                bytemuck::cast_slice(&entry.pixels),
-                entry.width,
-                entry.height,
-            )
-            .expect("Build pixmap from image bytes")
-        })
+        self.entries.get(&id).unwrap()
+    }
+
+    /// Stores the images decoded in the background.
+    fn receive(&mut self) {
+        for (id, result) in self.decoder.poll() {
+            if let Some(memory @ Memory::Loading(_)) = self.entries.get_mut(&id)
+            {
+                *memory = Memory::from_result(result);
+            }
+        }
+    }
+
+    fn metrics(&self) -> raster::Metrics {
+        raster::Metrics {
+            bytes: self
+                .entries
+                .values()
+                .map(|memory| match memory {
+                    Memory::Ready(entry) => 4 * entry.pixels.len() as u64,
+                    Memory::Loading(_) | Memory::Failed(_) => 0,
+                })
+                .sum(),
+            entries: self.entries.len(),
+            hits: self.hit_count,
+            misses: self.miss_count,
+        }
     }
 
     fn trim(&mut self) {
@@ -122,6 +330,49 @@ impl Cache {
     }
 }
 
+#[derive(Debug)]
+enum Memory {
+    Loading(Size<u32>),
+    Ready(Entry),
+    Failed(raster::Error),
+}
+
+impl Memory {
+    fn from_result(
+        result: graphics::image::image_rs::ImageResult<graphics::image::Buffer>,
+    ) -> Self {
+        use graphics::image::image_rs::error::ImageError;
+
+        match result {
+            Ok(image) => {
+                let mut pixels = vec![
+                    0u32;
+                    image.width() as usize
+                        * image.height() as usize
+                ];
+
+                for (i, pixel) in image.pixels().enumerate() {
+                    let [r, g, b, a] = pixel.0;
+
+                    pixels[i] = bytemuck::cast(
+                        tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply(),
+                    );
+                }
+
+                Memory::Ready(Entry {
+                    width: image.width(),
+                    height: image.height(),
+                    pixels,
+                })
+            }
+            Err(ImageError::IoError(_)) => {
+                Memory::Failed(raster::Error::NotFound)
+            }
+            Err(_) => Memory::Failed(raster::Error::Invalid),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Entry {
     width: u32,
@@ -1,4 +1,5 @@
 use crate::core::alignment;
+use crate::core::font;
 use crate::core::text::Shaping;
 use crate::core::{
     Color, Font, Pixels, Point, Rectangle, Size, Transformation,
@@ -29,13 +30,18 @@ impl Pipeline {
 
     // TODO: Shared engine
     #[allow(dead_code)]
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
         font_system()
             .write()
             .expect("Write font system")
-            .load_font(bytes);
+            .load_font(bytes)?;
 
         self.cache = RefCell::new(Cache::new());
+
+        Ok(())
     }
 
     pub fn draw_paragraph(
@@ -118,7 +124,6 @@ impl Pipeline {
         let line_height = f32::from(line_height);
 
         let mut font_system = font_system().write().expect("Write font system");
-        let font_system = font_system.raw();
 
         let key = cache::Key {
             bounds: bounds.size(),
@@ -129,13 +134,13 @@ impl Pipeline {
             shaping,
         };
 
-        let (_, entry) = self.cache.get_mut().allocate(font_system, key);
+        let (_, entry) = self.cache.get_mut().allocate(&mut font_system, key);
 
         let width = entry.min_bounds.width;
         let height = entry.min_bounds.height;
 
         draw(
-            font_system,
+            font_system.raw(),
             &mut self.glyph_cache,
             &entry.buffer,
             Rectangle {
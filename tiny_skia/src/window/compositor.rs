@@ -1,7 +1,8 @@
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{self, Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
 use crate::graphics::error::{self, Error};
+use crate::graphics::layer::Group;
 use crate::graphics::{self, Viewport};
 use crate::{Layer, Renderer, Settings};
 
@@ -21,7 +22,7 @@ pub struct Surface {
         Box<dyn compositor::Window>,
     >,
     clip_mask: tiny_skia::Mask,
-    layer_stack: VecDeque<Vec<Layer>>,
+    layer_stack: VecDeque<(Vec<Layer>, Vec<Group>)>,
     background_color: Color,
     max_age: u8,
 }
@@ -107,6 +108,18 @@ impl crate::graphics::Compositor for Compositor {
         }
     }
 
+    fn evict_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &core::image::Handle,
+    ) {
+        renderer.evict_image(handle);
+    }
+
+    fn image_metrics(&self, renderer: &Self::Renderer) -> core::image::Metrics {
+        renderer.image_metrics()
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -169,14 +182,34 @@ pub fn present<T: AsRef<str>>(
     };
 
     let damage = last_layers
-        .and_then(|last_layers| {
+        .and_then(|(last_layers, last_groups)| {
             (surface.background_color == background_color).then(|| {
-                damage::diff(
+                let mut damage = damage::diff(
                     last_layers,
                     renderer.layers(),
                     |layer| vec![layer.bounds],
                     Layer::damage,
-                )
+                );
+
+                damage.extend(damage::list(
+                    last_groups,
+                    renderer.groups(),
+                    |group| vec![group.bounds],
+                    Group::eq,
+                ));
+
+                // Any change beneath a backdrop blur spreads across its bounds
+                for group in renderer.groups() {
+                    if group.effect.backdrop_blur > 0.0
+                        && damage
+                            .iter()
+                            .any(|region| region.intersects(&group.bounds))
+                    {
+                        damage.push(group.bounds);
+                    }
+                }
+
+                damage
             })
         })
         .unwrap_or_else(|| vec![Rectangle::with_size(viewport.logical_size())]);
@@ -185,7 +218,9 @@ pub fn present<T: AsRef<str>>(
         return Ok(());
     }
 
-    surface.layer_stack.push_front(renderer.layers().to_vec());
+    surface
+        .layer_stack
+        .push_front((renderer.layers().to_vec(), renderer.groups().to_vec()));
     surface.background_color = background_color;
 
     let damage =
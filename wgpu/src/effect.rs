@@ -0,0 +1,505 @@
+//! Composite groups of layers with effects.
+use crate::core::{Rectangle, Size};
+use crate::graphics::layer::Group;
+
+use bytemuck::{Pod, Zeroable};
+
+use std::mem;
+use std::num::NonZeroU64;
+
+/// The stride of each entry in the uniforms buffer.
+///
+/// It satisfies the minimum uniform buffer offset alignment of any device.
+const UNIFORMS_STRIDE: u64 = 256;
+
+/// The amount of uniform entries every [`Group`] uses.
+///
+/// Horizontal blur, vertical blur, and composition.
+const ENTRIES_PER_GROUP: usize = 3;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    blur: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    uniforms_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    uniforms: Uniforms,
+    targets: Vec<Target>,
+    scratch: Option<Target>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let uniforms_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::effect uniforms layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(
+                            mem::size_of::<Entry>() as wgpu::BufferAddress,
+                        ),
+                    },
+                    count: None,
+                }],
+            });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::effect texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: false,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::effect pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&uniforms_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::effect shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/effect.wgsl"),
+                )),
+            });
+
+        let pipeline = |entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::effect pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+                cache: None,
+            })
+        };
+
+        let blur = pipeline("fs_blur", None);
+        let composite = pipeline(
+            "fs_composite",
+            Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+        );
+
+        let uniforms = Uniforms::new(device, &uniforms_layout, 16);
+
+        Self {
+            format,
+            blur,
+            composite,
+            uniforms_layout,
+            texture_layout,
+            uniforms,
+            targets: Vec::new(),
+            scratch: None,
+        }
+    }
+
+    /// Prepares the offscreen targets and uniforms needed to render the
+    /// given groups.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        groups: &[Group],
+        physical_size: Size<u32>,
+        scale_factor: f32,
+    ) {
+        if groups.is_empty() {
+            return;
+        }
+
+        // The canvas plus one target per nesting level
+        let depth = 1 + max_depth(groups);
+
+        self.targets.retain(|target| target.size == physical_size);
+
+        while self.targets.len() < depth {
+            self.targets.push(Target::new(
+                device,
+                self.format,
+                &self.texture_layout,
+                physical_size,
+            ));
+        }
+
+        let needs_scratch =
+            groups.iter().any(|group| group.effect.backdrop_blur > 0.0);
+
+        if needs_scratch
+            && self
+                .scratch
+                .as_ref()
+                .map_or(true, |scratch| scratch.size != physical_size)
+        {
+            self.scratch = Some(Target::new(
+                device,
+                self.format,
+                &self.texture_layout,
+                physical_size,
+            ));
+        }
+
+        let mut entries = Vec::with_capacity(
+            (groups.len() * ENTRIES_PER_GROUP + 1) * UNIFORMS_STRIDE as usize,
+        );
+
+        let mut push = |entry: Entry| {
+            entries.extend_from_slice(bytemuck::bytes_of(&entry));
+            entries.resize(
+                entries.len() + UNIFORMS_STRIDE as usize
+                    - mem::size_of::<Entry>(),
+                0,
+            );
+        };
+
+        for group in groups {
+            let radius = group.effect.backdrop_blur * scale_factor;
+
+            push(Entry {
+                direction: [1.0, 0.0],
+                radius,
+                opacity: 1.0,
+            });
+
+            push(Entry {
+                direction: [0.0, 1.0],
+                radius,
+                opacity: 1.0,
+            });
+
+            push(Entry {
+                direction: [0.0, 0.0],
+                radius: 0.0,
+                opacity: group.effect.opacity.clamp(0.0, 1.0),
+            });
+        }
+
+        // The final composition of the canvas
+        push(Entry {
+            direction: [0.0, 0.0],
+            radius: 0.0,
+            opacity: 1.0,
+        });
+
+        let count = groups.len() * ENTRIES_PER_GROUP + 1;
+
+        if self.uniforms.capacity < count {
+            self.uniforms = Uniforms::new(device, &self.uniforms_layout, count);
+        }
+
+        belt.write_buffer(
+            encoder,
+            &self.uniforms.buffer,
+            0,
+            NonZeroU64::new(entries.len() as u64).expect("non-empty uniforms"),
+            device,
+        )
+        .copy_from_slice(&entries);
+    }
+
+    /// Returns the offscreen target of the given nesting level.
+    ///
+    /// The target at depth `0` is the canvas where the whole frame is drawn
+    /// before being composited onto the final surface.
+    pub fn target(&self, depth: usize) -> &wgpu::TextureView {
+        &self.targets[depth].view
+    }
+
+    /// Blurs the contents of the target at the given depth inside the
+    /// physical bounds of a [`Group`].
+    pub fn blur(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        depth: usize,
+        group: usize,
+        radius: f32,
+        bounds: Rectangle<u32>,
+    ) {
+        let Some(scratch) = &self.scratch else {
+            return;
+        };
+
+        let target = &self.targets[depth];
+
+        // The vertical pass needs the horizontal blur of the pixels
+        // around the bounds, too
+        let spread = (radius * 1.5).ceil() as u32;
+
+        let top = bounds.y.saturating_sub(spread);
+        let bottom =
+            (bounds.y + bounds.height + spread).min(target.size.height);
+
+        self.draw(
+            encoder,
+            &self.blur,
+            target,
+            &scratch.view,
+            wgpu::LoadOp::Load,
+            group * ENTRIES_PER_GROUP,
+            Rectangle {
+                x: bounds.x,
+                y: top,
+                width: bounds.width,
+                height: bottom - top,
+            },
+        );
+
+        self.draw(
+            encoder,
+            &self.blur,
+            scratch,
+            &target.view,
+            wgpu::LoadOp::Load,
+            group * ENTRIES_PER_GROUP + 1,
+            bounds,
+        );
+    }
+
+    /// Composites the target at the given depth onto some target view,
+    /// applying the opacity of a [`Group`].
+    pub fn composite(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        depth: usize,
+        group: usize,
+        target: &wgpu::TextureView,
+        bounds: Rectangle<u32>,
+    ) {
+        self.draw(
+            encoder,
+            &self.composite,
+            &self.targets[depth],
+            target,
+            wgpu::LoadOp::Load,
+            group * ENTRIES_PER_GROUP + 2,
+            bounds,
+        );
+    }
+
+    /// Composites the canvas onto the given frame.
+    pub fn present(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        groups: usize,
+        frame: &wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) {
+        let canvas = &self.targets[0];
+
+        self.draw(
+            encoder,
+            &self.composite,
+            canvas,
+            frame,
+            load,
+            groups * ENTRIES_PER_GROUP,
+            Rectangle::with_size(canvas.size),
+        );
+    }
+
+    fn draw(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: &wgpu::RenderPipeline,
+        source: &Target,
+        target: &wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+        entry: usize,
+        bounds: Rectangle<u32>,
+    ) {
+        if bounds.width == 0 || bounds.height == 0 {
+            return;
+        }
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::effect render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(
+            0,
+            &self.uniforms.bind_group,
+            &[(entry as u64 * UNIFORMS_STRIDE) as u32],
+        );
+        render_pass.set_bind_group(1, &source.bind_group, &[]);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+/// Returns the maximum nesting level of the given groups.
+fn max_depth(groups: &[Group]) -> usize {
+    let mut open: Vec<usize> = Vec::new();
+    let mut depth = 0;
+
+    for group in groups {
+        while open.last().is_some_and(|end| *end <= group.layers.start) {
+            let _ = open.pop();
+        }
+
+        open.push(group.layers.end);
+        depth = depth.max(open.len());
+    }
+
+    depth
+}
+
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct Entry {
+    direction: [f32; 2],
+    radius: f32,
+    opacity: f32,
+}
+
+#[derive(Debug)]
+struct Uniforms {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    capacity: usize,
+}
+
+impl Uniforms {
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        capacity: usize,
+    ) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::effect uniforms buffer"),
+            size: capacity as u64 * UNIFORMS_STRIDE,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::effect uniforms bind group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(mem::size_of::<Entry>() as u64),
+                }),
+            }],
+        });
+
+        Self {
+            buffer,
+            bind_group,
+            capacity,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::effect target"),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::effect target bind group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Self {
+            view,
+            bind_group,
+            size,
+        }
+    }
+}
@@ -25,6 +25,7 @@ use std::sync::Arc;
 pub enum Geometry {
     Live {
         meshes: Vec<Mesh>,
+        textured: Vec<Mesh>,
         images: Vec<Image>,
         text: Vec<Text>,
     },
@@ -34,6 +35,7 @@ pub enum Geometry {
 #[derive(Debug, Clone, Default)]
 pub struct Cache {
     pub meshes: Option<triangle::Cache>,
+    pub textured: Option<Arc<[Mesh]>>,
     pub images: Option<Arc<[Image]>>,
     pub text: Option<text::Cache>,
 }
@@ -53,9 +55,16 @@ impl Cached for Geometry {
         match self {
             Self::Live {
                 meshes,
+                textured,
                 images,
                 text,
             } => {
+                let textured = if textured.is_empty() {
+                    None
+                } else {
+                    Some(Arc::from(textured))
+                };
+
                 let images = if images.is_empty() {
                     None
                 } else {
@@ -75,12 +84,14 @@ impl Cached for Geometry {
                         previous.text = text::Cache::new(group, text);
                     }
 
+                    previous.textured = textured;
                     previous.images = images;
 
                     previous
                 } else {
                     Cache {
                         meshes: triangle::Cache::new(meshes),
+                        textured,
                         images,
                         text: text::Cache::new(group, text),
                     }
@@ -97,6 +108,7 @@ pub struct Frame {
     clip_bounds: Rectangle,
     buffers: BufferStack,
     meshes: Vec<Mesh>,
+    textured: Vec<Mesh>,
     images: Vec<Image>,
     text: Vec<Text>,
     transforms: Transforms,
@@ -116,6 +128,7 @@ impl Frame {
             clip_bounds: bounds,
             buffers: BufferStack::new(),
             meshes: Vec::new(),
+            textured: Vec::new(),
             images: Vec::new(),
             text: Vec::new(),
             transforms: Transforms {
@@ -399,6 +412,7 @@ impl geometry::frame::Backend for Frame {
         self.meshes
             .extend(frame.buffers.into_meshes(frame.clip_bounds));
 
+        self.textured.extend(frame.textured);
         self.images.extend(frame.images);
         self.text.extend(frame.text);
     }
@@ -409,6 +423,7 @@ impl geometry::frame::Backend for Frame {
 
         Geometry::Live {
             meshes: self.meshes,
+            textured: self.textured,
             images: self.images,
             text: self.text,
         }
@@ -425,6 +440,28 @@ impl geometry::frame::Backend for Frame {
         self.images.push(Image::Raster(image, bounds));
     }
 
+    fn draw_textured_mesh(
+        &mut self,
+        mut buffers: mesh::Indexed<mesh::TexturedVertex2D>,
+        image: impl Into<core::Image>,
+    ) {
+        for vertex in &mut buffers.vertices {
+            let position = self.transforms.current.transform_point(Point::new(
+                vertex.position[0],
+                vertex.position[1],
+            ));
+
+            vertex.position = [position.x, position.y];
+        }
+
+        self.textured.push(Mesh::Textured {
+            buffers,
+            image: image.into(),
+            transformation: Transformation::IDENTITY,
+            clip_bounds: self.clip_bounds,
+        });
+    }
+
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
         let mut svg = svg.into();
 
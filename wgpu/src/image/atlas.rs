@@ -24,6 +24,7 @@ pub struct Atlas {
     texture_bind_group: wgpu::BindGroup,
     texture_layout: Arc<wgpu::BindGroupLayout>,
     layers: Vec<Layer>,
+    minimum_layers: usize,
 }
 
 impl Atlas {
@@ -83,6 +84,7 @@ impl Atlas {
             texture_view,
             texture_bind_group,
             texture_layout,
+            minimum_layers: layers.len(),
             layers,
         }
     }
@@ -194,6 +196,33 @@ impl Atlas {
         }
     }
 
+    /// Drops the empty layers at the end of the [`Atlas`]; releasing
+    /// their memory.
+    pub fn shrink(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let used = self
+            .layers
+            .iter()
+            .rposition(|layer| !layer.is_empty())
+            .map_or(0, |last| last + 1)
+            .max(self.minimum_layers);
+
+        if used == self.layers.len() {
+            return;
+        }
+
+        log::debug!(
+            "Shrinking atlas from {} to {used} layers",
+            self.layers.len()
+        );
+
+        self.layers.truncate(used);
+        self.reallocate(used, device, encoder);
+    }
+
     fn allocate(&mut self, width: u32, height: u32) -> Option<Entry> {
         // Allocate one layer if texture fits perfectly
         if width == SIZE && height == SIZE {
@@ -376,6 +405,17 @@ impl Atlas {
             return;
         }
 
+        self.reallocate(self.layers.len() - amount, device, encoder);
+    }
+
+    /// Creates a new texture for the current layers, copying the contents
+    /// of the first layers of the current texture.
+    fn reallocate(
+        &mut self,
+        amount_to_copy: usize,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
         let new_texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("iced_wgpu::image texture atlas"),
             size: wgpu::Extent3d {
@@ -397,8 +437,6 @@ impl Atlas {
             view_formats: &[],
         });
 
-        let amount_to_copy = self.layers.len() - amount;
-
         for (i, layer) in
             self.layers.iter_mut().take(amount_to_copy).enumerate()
         {
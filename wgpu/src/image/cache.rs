@@ -40,6 +40,14 @@ impl Cache {
         self.raster.load(handle).dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn image_state(
+        &mut self,
+        handle: &core::image::Handle,
+    ) -> core::image::State {
+        self.raster.state(handle)
+    }
+
     #[cfg(feature = "svg")]
     pub fn measure_svg(&mut self, handle: &core::svg::Handle) -> Size<u32> {
         self.vector.load(handle).viewport_dimensions()
@@ -76,11 +84,32 @@ impl Cache {
         )
     }
 
-    pub fn trim(&mut self) {
+    #[cfg(feature = "image")]
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.raster.set_budget(budget);
+    }
+
+    #[cfg(feature = "image")]
+    pub fn evict(&mut self, handle: &core::image::Handle) {
+        self.raster.evict(handle, &mut self.atlas);
+    }
+
+    #[cfg(feature = "image")]
+    pub fn metrics(&self) -> core::image::Metrics {
+        self.raster.metrics()
+    }
+
+    pub fn trim(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
         #[cfg(feature = "image")]
         self.raster.trim(&mut self.atlas);
 
         #[cfg(feature = "svg")]
         self.vector.trim(&mut self.atlas);
+
+        self.atlas.shrink(device, encoder);
     }
 }
@@ -10,20 +10,47 @@ mod raster;
 mod vector;
 
 use crate::core::{Rectangle, Size, Transformation};
+use crate::graphics::Mesh;
 use crate::Buffer;
 
 use bytemuck::{Pod, Zeroable};
 
 use std::mem;
+use std::ops::Range;
 use std::sync::Arc;
 
 pub use crate::graphics::Image;
 
-pub type Batch = Vec<Image>;
+/// The images and textured meshes of a layer.
+#[derive(Debug, Default)]
+pub struct Batch {
+    images: Vec<Image>,
+    meshes: Vec<Mesh>,
+}
+
+impl Batch {
+    pub fn push(&mut self, image: Image) {
+        self.images.push(image);
+    }
+
+    pub fn push_mesh(&mut self, mesh: Mesh) {
+        self.meshes.push(mesh);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty() && self.meshes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.images.clear();
+        self.meshes.clear();
+    }
+}
 
 #[derive(Debug)]
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    mesh_pipeline: wgpu::RenderPipeline,
     backend: wgpu::Backend,
     nearest_sampler: wgpu::Sampler,
     linear_sampler: wgpu::Sampler,
@@ -123,78 +150,108 @@ impl Pipeline {
             });
 
         let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("iced_wgpu::image pipeline"),
-                layout: Some(&layout),
-                vertex: wgpu::VertexState {
-                    module: &shader,
-                    entry_point: "vs_main",
-                    buffers: &[wgpu::VertexBufferLayout {
-                        array_stride: mem::size_of::<Instance>() as u64,
-                        step_mode: wgpu::VertexStepMode::Instance,
-                        attributes: &wgpu::vertex_attr_array!(
-                            // Position
-                            0 => Float32x2,
-                            // Center
-                            1 => Float32x2,
-                            // Scale
-                            2 => Float32x2,
-                            // Rotation
-                            3 => Float32,
-                            // Opacity
-                            4 => Float32,
-                            // Atlas position
-                            5 => Float32x2,
-                            // Atlas scale
-                            6 => Float32x2,
-                            // Layer
-                            7 => Sint32,
-                            // Snap
-                            8 => Uint32,
-                        ),
-                    }],
-                    compilation_options:
-                        wgpu::PipelineCompilationOptions::default(),
-                },
-                fragment: Some(wgpu::FragmentState {
-                    module: &shader,
-                    entry_point: "fs_main",
-                    targets: &[Some(wgpu::ColorTargetState {
-                        format,
-                        blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::SrcAlpha,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                            alpha: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                        }),
-                        write_mask: wgpu::ColorWrites::ALL,
-                    })],
-                    compilation_options:
-                        wgpu::PipelineCompilationOptions::default(),
-                }),
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList,
-                    front_face: wgpu::FrontFace::Cw,
-                    ..Default::default()
-                },
-                depth_stencil: None,
-                multisample: wgpu::MultisampleState {
-                    count: 1,
-                    mask: !0,
-                    alpha_to_coverage_enabled: false,
-                },
-                multiview: None,
-                cache: None,
-            });
+            |label, entry_point, buffer: wgpu::VertexBufferLayout<'_>| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some(label),
+                    layout: Some(&layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point,
+                        buffers: &[buffer],
+                        compilation_options:
+                            wgpu::PipelineCompilationOptions::default(),
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: "fs_main",
+                        targets: &[Some(wgpu::ColorTargetState {
+                            format,
+                            blend: Some(wgpu::BlendState {
+                                color: wgpu::BlendComponent {
+                                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                                    dst_factor:
+                                        wgpu::BlendFactor::OneMinusSrcAlpha,
+                                    operation: wgpu::BlendOperation::Add,
+                                },
+                                alpha: wgpu::BlendComponent {
+                                    src_factor: wgpu::BlendFactor::One,
+                                    dst_factor:
+                                        wgpu::BlendFactor::OneMinusSrcAlpha,
+                                    operation: wgpu::BlendOperation::Add,
+                                },
+                            }),
+                            write_mask: wgpu::ColorWrites::ALL,
+                        })],
+                        compilation_options:
+                            wgpu::PipelineCompilationOptions::default(),
+                    }),
+                    primitive: wgpu::PrimitiveState {
+                        topology: wgpu::PrimitiveTopology::TriangleList,
+                        front_face: wgpu::FrontFace::Cw,
+                        ..Default::default()
+                    },
+                    depth_stencil: None,
+                    multisample: wgpu::MultisampleState {
+                        count: 1,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                    multiview: None,
+                    cache: None,
+                })
+            };
+
+        let mesh_pipeline = pipeline(
+            "iced_wgpu::image mesh pipeline",
+            "vs_mesh",
+            wgpu::VertexBufferLayout {
+                array_stride: mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &wgpu::vertex_attr_array!(
+                    // Position
+                    0 => Float32x2,
+                    // Atlas position
+                    1 => Float32x2,
+                    // Layer
+                    2 => Sint32,
+                    // Opacity
+                    3 => Float32,
+                ),
+            },
+        );
+
+        let pipeline = pipeline(
+            "iced_wgpu::image pipeline",
+            "vs_main",
+            wgpu::VertexBufferLayout {
+                array_stride: mem::size_of::<Instance>() as u64,
+                step_mode: wgpu::VertexStepMode::Instance,
+                attributes: &wgpu::vertex_attr_array!(
+                    // Position
+                    0 => Float32x2,
+                    // Center
+                    1 => Float32x2,
+                    // Scale
+                    2 => Float32x2,
+                    // Rotation
+                    3 => Float32,
+                    // Opacity
+                    4 => Float32,
+                    // Atlas position
+                    5 => Float32x2,
+                    // Atlas scale
+                    6 => Float32x2,
+                    // Layer
+                    7 => Sint32,
+                    // Snap
+                    8 => Uint32,
+                ),
+            },
+        );
 
         Pipeline {
             pipeline,
+            mesh_pipeline,
             backend,
             nearest_sampler,
             linear_sampler,
@@ -215,14 +272,18 @@ impl Pipeline {
         encoder: &mut wgpu::CommandEncoder,
         belt: &mut wgpu::util::StagingBelt,
         cache: &mut Cache,
-        images: &Batch,
+        batch: &Batch,
         transformation: Transformation,
         scale: f32,
     ) {
         let nearest_instances: &mut Vec<Instance> = &mut Vec::new();
         let linear_instances: &mut Vec<Instance> = &mut Vec::new();
+        #[cfg_attr(not(feature = "image"), allow(unused_mut))]
+        let mut vertices = Vec::new();
+        #[cfg_attr(not(feature = "image"), allow(unused_mut))]
+        let mut meshes = Vec::new();
 
-        for image in images {
+        for image in &batch.images {
             match &image {
                 #[cfg(feature = "image")]
                 Image::Raster(image, bounds) => {
@@ -232,6 +293,7 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            image.source,
                             f32::from(image.rotation),
                             image.opacity,
                             image.snap,
@@ -265,6 +327,7 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            None,
                             f32::from(svg.rotation),
                             svg.opacity,
                             true,
@@ -278,7 +341,49 @@ impl Pipeline {
             }
         }
 
-        if nearest_instances.is_empty() && linear_instances.is_empty() {
+        #[cfg(feature = "image")]
+        for mesh in &batch.meshes {
+            let Mesh::Textured { buffers, image, .. } = mesh else {
+                continue;
+            };
+
+            let Some(atlas_entry) =
+                cache.upload_raster(device, encoder, &image.handle)
+            else {
+                continue;
+            };
+
+            let atlas::Entry::Contiguous(allocation) = atlas_entry else {
+                log::warn!(
+                    "Textured meshes do not support images larger \
+                    than the texture atlas: {:?}",
+                    image.handle
+                );
+                continue;
+            };
+
+            let start = vertices.len() as u32;
+
+            add_vertices(
+                buffers,
+                mesh.transformation(),
+                image.source,
+                image.opacity,
+                allocation,
+                &mut vertices,
+            );
+
+            meshes.push(Draw {
+                vertices: start..vertices.len() as u32,
+                clip_bounds: mesh.clip_bounds() * Transformation::scale(scale),
+                filter_method: image.filter_method,
+            });
+        }
+
+        if nearest_instances.is_empty()
+            && linear_instances.is_empty()
+            && meshes.is_empty()
+        {
             return;
         }
 
@@ -299,6 +404,8 @@ impl Pipeline {
             belt,
             nearest_instances,
             linear_instances,
+            &vertices,
+            meshes,
             transformation,
             scale,
         );
@@ -326,6 +433,12 @@ impl Pipeline {
             render_pass.set_bind_group(1, cache.bind_group(), &[]);
 
             layer.render(render_pass);
+
+            if !layer.meshes.is_empty() {
+                render_pass.set_pipeline(&self.mesh_pipeline);
+
+                layer.render_meshes(bounds, render_pass);
+            }
         }
     }
 
@@ -339,6 +452,8 @@ struct Layer {
     uniforms: wgpu::Buffer,
     nearest: Data,
     linear: Data,
+    vertices: Buffer<Vertex>,
+    meshes: Vec<Draw>,
 }
 
 impl Layer {
@@ -361,10 +476,19 @@ impl Layer {
         let linear =
             Data::new(device, constant_layout, linear_sampler, &uniforms);
 
+        let vertices = Buffer::new(
+            device,
+            "iced_wgpu::image mesh vertex buffer",
+            Vertex::INITIAL,
+            wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        );
+
         Self {
             uniforms,
             nearest,
             linear,
+            vertices,
+            meshes: Vec::new(),
         }
     }
 
@@ -375,6 +499,8 @@ impl Layer {
         belt: &mut wgpu::util::StagingBelt,
         nearest_instances: &[Instance],
         linear_instances: &[Instance],
+        vertices: &[Vertex],
+        meshes: Vec<Draw>,
         transformation: Transformation,
         scale_factor: f32,
     ) {
@@ -399,12 +525,63 @@ impl Layer {
             .upload(device, encoder, belt, nearest_instances);
 
         self.linear.upload(device, encoder, belt, linear_instances);
+
+        self.meshes = meshes;
+
+        if !vertices.is_empty() {
+            let _ = self.vertices.resize(device, vertices.len());
+            let _ = self.vertices.write(device, encoder, belt, 0, vertices);
+        }
     }
 
     fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
         self.nearest.render(render_pass);
         self.linear.render(render_pass);
     }
+
+    fn render_meshes<'a>(
+        &'a self,
+        bounds: Rectangle<u32>,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+
+        for mesh in &self.meshes {
+            let Some(clip_bounds) = Rectangle::<f32>::from(bounds)
+                .intersection(&mesh.clip_bounds)
+                .and_then(Rectangle::snap)
+            else {
+                continue;
+            };
+
+            render_pass.set_scissor_rect(
+                clip_bounds.x,
+                clip_bounds.y,
+                clip_bounds.width,
+                clip_bounds.height,
+            );
+
+            let constants = match mesh.filter_method {
+                crate::core::image::FilterMethod::Nearest => {
+                    &self.nearest.constants
+                }
+                crate::core::image::FilterMethod::Linear => {
+                    &self.linear.constants
+                }
+            };
+
+            render_pass.set_bind_group(0, constants, &[]);
+            render_pass.draw(mesh.vertices.clone(), 0..1);
+        }
+    }
+}
+
+/// A textured mesh ready to be drawn.
+#[derive(Debug)]
+struct Draw {
+    vertices: Range<u32>,
+    clip_bounds: Rectangle,
+    filter_method: crate::core::image::FilterMethod,
 }
 
 #[derive(Debug)]
@@ -503,6 +680,19 @@ impl Instance {
     pub const INITIAL: usize = 20;
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Vertex {
+    _position: [f32; 2],
+    _position_in_atlas: [f32; 2],
+    _layer: u32,
+    _opacity: f32,
+}
+
+impl Vertex {
+    pub const INITIAL: usize = 60;
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct Uniforms {
@@ -516,6 +706,7 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    source: Option<Rectangle<u32>>,
     rotation: f32,
     opacity: f32,
     snap: bool,
@@ -527,47 +718,68 @@ fn add_instances(
         image_position[1] + image_size[1] / 2.0,
     ];
 
+    let size = entry.size();
+
+    let source = source.unwrap_or(Rectangle {
+        x: 0,
+        y: 0,
+        width: size.width,
+        height: size.height,
+    });
+
+    if source.width == 0 || source.height == 0 {
+        return;
+    }
+
+    let scaling_x = image_size[0] / source.width as f32;
+    let scaling_y = image_size[1] / source.height as f32;
+
+    let mut add_region = |allocation: &atlas::Allocation,
+                          offset: (u32, u32)| {
+        let Size { width, height } = allocation.size();
+
+        // The part of the source region covered by the allocation
+        let left = source.x.max(offset.0);
+        let top = source.y.max(offset.1);
+        let right = (source.x + source.width).min(offset.0 + width);
+        let bottom = (source.y + source.height).min(offset.1 + height);
+
+        if left >= right || top >= bottom {
+            return;
+        }
+
+        let [x, y] = image_position;
+
+        let position = [
+            x + (left - source.x) as f32 * scaling_x,
+            y + (top - source.y) as f32 * scaling_y,
+        ];
+
+        let size = [
+            (right - left) as f32 * scaling_x,
+            (bottom - top) as f32 * scaling_y,
+        ];
+
+        let region = Rectangle {
+            x: left - offset.0,
+            y: top - offset.1,
+            width: right - left,
+            height: bottom - top,
+        };
+
+        add_instance(
+            position, center, size, rotation, opacity, snap, allocation,
+            region, instances,
+        );
+    };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(
-                image_position,
-                center,
-                image_size,
-                rotation,
-                opacity,
-                snap,
-                allocation,
-                instances,
-            );
+            add_region(allocation, (0, 0));
         }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.width as f32;
-            let scaling_y = image_size[1] / size.height as f32;
-
+        atlas::Entry::Fragmented { fragments, .. } => {
             for fragment in fragments {
-                let allocation = &fragment.allocation;
-
-                let [x, y] = image_position;
-                let (fragment_x, fragment_y) = fragment.position;
-                let Size {
-                    width: fragment_width,
-                    height: fragment_height,
-                } = allocation.size();
-
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
-
-                let size = [
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(
-                    position, center, size, rotation, opacity, snap,
-                    allocation, instances,
-                );
+                add_region(&fragment.allocation, fragment.position);
             }
         }
     }
@@ -582,10 +794,10 @@ fn add_instance(
     opacity: f32,
     snap: bool,
     allocation: &atlas::Allocation,
+    region: Rectangle<u32>,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let Size { width, height } = allocation.size();
     let layer = allocation.layer();
 
     let instance = Instance {
@@ -595,12 +807,12 @@ fn add_instance(
         _rotation: rotation,
         _opacity: opacity,
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + region.x as f32 + 0.5) / atlas::SIZE as f32,
+            (y as f32 + region.y as f32 + 0.5) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (region.width as f32 - 1.0) / atlas::SIZE as f32,
+            (region.height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
         _snap: snap as u32,
@@ -608,3 +820,68 @@ fn add_instance(
 
     instances.push(instance);
 }
+
+#[cfg(feature = "image")]
+fn add_vertices(
+    buffers: &crate::graphics::mesh::Indexed<
+        crate::graphics::mesh::TexturedVertex2D,
+    >,
+    transformation: Transformation,
+    source: Option<Rectangle<u32>>,
+    opacity: f32,
+    allocation: &atlas::Allocation,
+    vertices: &mut Vec<Vertex>,
+) {
+    let (x, y) = allocation.position();
+    let Size { width, height } = allocation.size();
+    let layer = allocation.layer();
+
+    let source = source.unwrap_or(Rectangle {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    });
+
+    // Map the texture coordinates to the centers of the edge texels,
+    // like image instances do, to avoid bleeding into the atlas
+    let atlas = |[u, v]: [f32; 2]| {
+        [
+            (x as f32
+                + source.x as f32
+                + 0.5
+                + u * (source.width as f32 - 1.0))
+                / atlas::SIZE as f32,
+            (y as f32
+                + source.y as f32
+                + 0.5
+                + v * (source.height as f32 - 1.0))
+                / atlas::SIZE as f32,
+        ]
+    };
+
+    let vertex = |index: u32| buffers.vertices.get(index as usize);
+
+    for triangle in buffers.indices.chunks_exact(3) {
+        let (Some(a), Some(b), Some(c)) = (
+            vertex(triangle[0]),
+            vertex(triangle[1]),
+            vertex(triangle[2]),
+        ) else {
+            continue;
+        };
+
+        vertices.extend([a, b, c].map(|vertex| {
+            let position =
+                crate::core::Point::new(vertex.position[0], vertex.position[1])
+                    * transformation;
+
+            Vertex {
+                _position: [position.x, position.y],
+                _position_in_atlas: atlas(vertex.uv),
+                _layer: layer as u32,
+                _opacity: opacity,
+            }
+        }));
+    }
+}
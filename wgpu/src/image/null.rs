@@ -1,4 +1,4 @@
-pub use crate::graphics::Image;
+pub use crate::graphics::{Image, Mesh};
 
 #[derive(Debug, Default)]
 pub struct Batch;
@@ -6,5 +6,7 @@ pub struct Batch;
 impl Batch {
     pub fn push(&mut self, _image: Image) {}
 
+    pub fn push_mesh(&mut self, _mesh: Mesh) {}
+
     pub fn clear(&mut self) {}
 }
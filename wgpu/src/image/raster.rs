@@ -4,11 +4,13 @@ use crate::graphics;
 use crate::graphics::image::image_rs;
 use crate::image::atlas::{self, Atlas};
 
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
 pub enum Memory {
+    /// Image data being decoded
+    Loading(Size<u32>),
     /// Image data on host
     Host(image_rs::ImageBuffer<image_rs::Rgba<u8>, image::Bytes>),
     /// Storage entry
@@ -29,33 +31,104 @@ impl Memory {
                 Size::new(width, height)
             }
             Memory::Device(entry) => entry.size(),
+            Memory::Loading(size) => *size,
             Memory::NotFound => Size::new(1, 1),
             Memory::Invalid => Size::new(1, 1),
         }
     }
+
+    /// Amount of bytes used by the image data
+    pub fn bytes(&self) -> u64 {
+        match self {
+            Memory::Host(_) | Memory::Device(_) => {
+                let Size { width, height } = self.dimensions();
+
+                4 * u64::from(width) * u64::from(height)
+            }
+            Memory::Loading(_) | Memory::NotFound | Memory::Invalid => 0,
+        }
+    }
+
+    /// Loading state of image
+    pub fn state(&self) -> image::State {
+        match self {
+            Memory::Loading(_) => image::State::Loading,
+            Memory::Host(_) | Memory::Device(_) => image::State::Loaded,
+            Memory::NotFound => image::State::Failed(image::Error::NotFound),
+            Memory::Invalid => image::State::Failed(image::Error::Invalid),
+        }
+    }
+
+    fn from_result(
+        result: image_rs::ImageResult<
+            image_rs::ImageBuffer<image_rs::Rgba<u8>, image::Bytes>,
+        >,
+    ) -> Self {
+        match result {
+            Ok(image) => Memory::Host(image),
+            Err(image_rs::error::ImageError::IoError(_)) => Memory::NotFound,
+            Err(_) => Memory::Invalid,
+        }
+    }
 }
 
 /// Caches image raster data
 #[derive(Debug, Default)]
 pub struct Cache {
-    map: FxHashMap<image::Id, Memory>,
-    hits: FxHashSet<image::Id>,
+    map: FxHashMap<image::Id, Entry>,
+    decoder: graphics::image::Decoder,
+    budget: Option<u64>,
+    bytes: u64,
+    frame: u64,
+    last_trim: u64,
+    hits: u64,
+    misses: u64,
     should_trim: bool,
 }
 
+#[derive(Debug)]
+struct Entry {
+    memory: Memory,
+    last_used: u64,
+}
+
 impl Cache {
+    /// Sets the maximum amount of bytes the cached images may use
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.budget = budget;
+        self.should_trim = true;
+    }
+
     /// Load image
+    ///
+    /// Encoded images are decoded in the background; in the meantime,
+    /// only their dimensions are available.
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+        self.receive();
+
         if self.contains(handle) {
+            self.hits += 1;
+
             return self.get(handle).unwrap();
         }
 
-        let memory = match graphics::image::load(handle) {
-            Ok(image) => Memory::Host(image),
-            Err(image_rs::error::ImageError::IoError(_)) => Memory::NotFound,
-            Err(_) => Memory::Invalid,
+        let memory = match handle {
+            image::Handle::Rgba { .. } => {
+                Memory::from_result(graphics::image::load(handle))
+            }
+            image::Handle::Path(..) | image::Handle::Bytes(..) => {
+                match graphics::image::dimensions(handle) {
+                    Ok(size) => {
+                        self.decoder.decode(handle);
+
+                        Memory::Loading(size)
+                    }
+                    Err(error) => Memory::from_result(Err(error)),
+                }
+            }
         };
 
+        self.misses += 1;
         self.should_trim = true;
 
         self.insert(handle, memory);
@@ -87,39 +160,129 @@ impl Cache {
         }
     }
 
+    /// Loading state of image
+    pub fn state(&mut self, handle: &image::Handle) -> image::State {
+        self.receive();
+
+        if let Some(entry) = self.map.get(&handle.id()) {
+            return entry.memory.state();
+        }
+
+        self.load(handle).state()
+    }
+
+    /// Evict image from cache
+    pub fn evict(&mut self, handle: &image::Handle, atlas: &mut Atlas) {
+        self.remove(handle.id(), atlas);
+    }
+
+    /// Returns the memory usage of the cache
+    pub fn metrics(&self) -> image::Metrics {
+        image::Metrics {
+            bytes: self.bytes,
+            entries: self.map.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+
     /// Trim cache misses from cache
     pub fn trim(&mut self, atlas: &mut Atlas) {
+        let frame = self.frame;
+        self.frame += 1;
+
         // Only trim if new entries have landed in the `Cache`
         if !self.should_trim {
             return;
         }
 
-        let hits = &self.hits;
+        match self.budget {
+            None => {
+                let last_trim = self.last_trim;
 
-        self.map.retain(|k, memory| {
-            let retain = hits.contains(k);
+                let unused: Vec<_> = self
+                    .map
+                    .iter()
+                    .filter(|(_, entry)| entry.last_used < last_trim)
+                    .map(|(id, _)| *id)
+                    .collect();
 
-            if !retain {
-                if let Memory::Device(entry) = memory {
-                    atlas.remove(entry);
+                for id in unused {
+                    self.remove(id, atlas);
                 }
             }
+            Some(budget) => {
+                // Evict the least recently used images not being displayed
+                let mut unused: Vec<_> = self
+                    .map
+                    .iter()
+                    .filter(|(_, entry)| entry.last_used < frame)
+                    .map(|(id, entry)| (entry.last_used, *id))
+                    .collect();
+
+                unused.sort_unstable();
 
-            retain
-        });
+                for (_, id) in unused {
+                    if self.bytes <= budget {
+                        break;
+                    }
+
+                    self.remove(id, atlas);
+                }
+            }
+        }
 
-        self.hits.clear();
+        self.last_trim = self.frame;
         self.should_trim = false;
     }
 
+    /// Store images decoded in the background
+    fn receive(&mut self) {
+        for (id, result) in self.decoder.poll() {
+            let Some(entry) = self.map.get_mut(&id) else {
+                continue;
+            };
+
+            if let Memory::Loading(_) = entry.memory {
+                entry.memory = Memory::from_result(result);
+
+                self.bytes += entry.memory.bytes();
+                self.should_trim = true;
+            }
+        }
+    }
+
     fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
-        let _ = self.hits.insert(handle.id());
+        let entry = self.map.get_mut(&handle.id())?;
+        entry.last_used = self.frame;
 
-        self.map.get_mut(&handle.id())
+        Some(&mut entry.memory)
     }
 
     fn insert(&mut self, handle: &image::Handle, memory: Memory) {
-        let _ = self.map.insert(handle.id(), memory);
+        self.bytes += memory.bytes();
+
+        if let Some(entry) = self.map.insert(
+            handle.id(),
+            Entry {
+                memory,
+                last_used: self.frame,
+            },
+        ) {
+            self.bytes -= entry.memory.bytes();
+        }
+    }
+
+    fn remove(&mut self, id: image::Id, atlas: &mut Atlas) {
+        let Some(entry) = self.map.remove(&id) else {
+            return;
+        };
+
+        self.bytes -= entry.memory.bytes();
+
+        if let Memory::Device(entry) = &entry.memory {
+            atlas.remove(entry);
+        }
     }
 
     fn contains(&self, handle: &image::Handle) -> bool {
@@ -1,5 +1,6 @@
 use crate::core::{
-    self, renderer, Background, Color, Point, Rectangle, Svg, Transformation,
+    self, renderer, Background, Border, Color, Point, Rectangle, Shadow, Svg,
+    Transformation,
 };
 use crate::graphics;
 use crate::graphics::color;
@@ -35,18 +36,54 @@ impl Layer {
     ) {
         let bounds = quad.bounds * transformation;
 
-        let quad = Quad {
+        let instance = |border: Border, shadow: Shadow| Quad {
             position: [bounds.x, bounds.y],
             size: [bounds.width, bounds.height],
-            border_color: color::pack(quad.border.color),
-            border_radius: quad.border.radius.into(),
-            border_width: quad.border.width,
-            shadow_color: color::pack(quad.shadow.color),
-            shadow_offset: quad.shadow.offset.into(),
-            shadow_blur_radius: quad.shadow.blur_radius,
+            border_color: color::pack(border.color),
+            border_radius: border.radius.into(),
+            border_width: border.width,
+            shadow_color: color::pack(shadow.color),
+            shadow_offset: shadow.offset.into(),
+            shadow_blur_radius: shadow.blur_radius,
+            shadow_inset: if shadow.inset { 1.0 } else { 0.0 },
         };
 
-        self.quads.add(quad, &background);
+        let transparent = Background::Color(Color::TRANSPARENT);
+
+        let radius = Border {
+            radius: quad.border.radius,
+            ..Border::default()
+        };
+
+        let shadows = quad.shadows.as_slice();
+        let mut outer = shadows.iter().filter(|shadow| !shadow.inset);
+        let inner = shadows.iter().filter(|shadow| shadow.inset);
+
+        // Like in CSS, the first shadow is drawn on top
+        let first_outer = outer.next().copied().unwrap_or_default();
+
+        for shadow in outer.rev() {
+            self.quads.add(instance(radius, *shadow), &transparent);
+        }
+
+        if inner.clone().next().is_none() {
+            self.quads
+                .add(instance(quad.border, first_outer), &background);
+
+            return;
+        }
+
+        // Inner shadows are drawn between the background and the border
+        self.quads.add(instance(radius, first_outer), &background);
+
+        for shadow in inner.rev() {
+            self.quads.add(instance(radius, *shadow), &transparent);
+        }
+
+        if quad.border.width > 0.0 {
+            self.quads
+                .add(instance(quad.border, Shadow::default()), &transparent);
+        }
     }
 
     pub fn draw_paragraph(
@@ -158,12 +195,20 @@ impl Layer {
             | Mesh::Gradient {
                 transformation: local_transformation,
                 ..
+            }
+            | Mesh::Textured {
+                transformation: local_transformation,
+                ..
             } => {
                 *local_transformation = *local_transformation * transformation;
             }
         }
 
-        self.pending_meshes.push(mesh);
+        if let Mesh::Textured { .. } = mesh {
+            self.images.push_mesh(mesh);
+        } else {
+            self.pending_meshes.push(mesh);
+        }
     }
 
     pub fn draw_mesh_group(
@@ -12,6 +12,8 @@
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
 //! - Images and SVG, loaded from memory or the file system.
 //! - Meshes of triangles, useful to draw geometry freely.
+//! - Layers with group opacity and backdrop blur effects.
+//! - Layers rendered offscreen and post-processed by custom primitives.
 //!
 //! [Iced]: https://github.com/iced-rs/iced
 //! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
@@ -32,6 +34,7 @@ pub mod geometry;
 
 mod buffer;
 mod color;
+mod effect;
 mod engine;
 mod quad;
 mod text;
@@ -76,6 +79,7 @@ pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
     layers: layer::Stack,
+    offscreen: Vec<(usize, primitive::Instance)>,
 
     triangle_storage: triangle::Storage,
     text_storage: text::Storage,
@@ -97,6 +101,7 @@ impl Renderer {
             default_font,
             default_text_size,
             layers: layer::Stack::new(),
+            offscreen: Vec::new(),
 
             triangle_storage: triangle::Storage::new(),
             text_storage: text::Storage::new(),
@@ -109,6 +114,38 @@ impl Renderer {
         }
     }
 
+    /// Sets the maximum amount of memory, in bytes, that the cached images
+    /// of the [`Renderer`] may use.
+    ///
+    /// Once exceeded, the least recently used images that are not being
+    /// displayed will be evicted.
+    pub fn set_image_budget(&mut self, _budget: Option<u64>) {
+        #[cfg(feature = "image")]
+        self.image_cache.get_mut().set_budget(_budget);
+    }
+
+    /// Evicts the image with the given [`Handle`] from the caches of the
+    /// [`Renderer`].
+    ///
+    /// [`Handle`]: core::image::Handle
+    pub fn evict_image(&mut self, _handle: &core::image::Handle) {
+        #[cfg(feature = "image")]
+        self.image_cache.get_mut().evict(_handle);
+    }
+
+    /// Returns the [`Metrics`] of the image caches of the [`Renderer`].
+    ///
+    /// [`Metrics`]: core::image::Metrics
+    pub fn image_metrics(&self) -> core::image::Metrics {
+        #[cfg(feature = "image")]
+        let metrics = self.image_cache.borrow().metrics();
+
+        #[cfg(not(feature = "image"))]
+        let metrics = core::image::Metrics::default();
+
+        metrics
+    }
+
     pub fn present<T: AsRef<str>>(
         &mut self,
         engine: &mut Engine,
@@ -129,7 +166,7 @@ impl Renderer {
         self.text_storage.trim();
 
         #[cfg(any(feature = "svg", feature = "image"))]
-        self.image_cache.borrow_mut().trim();
+        self.image_cache.borrow_mut().trim(device, encoder);
     }
 
     fn prepare(
@@ -145,6 +182,26 @@ impl Renderer {
 
         self.text_viewport.update(queue, viewport.physical_size());
 
+        engine.effect_pipeline.prepare(
+            device,
+            encoder,
+            &mut engine.staging_belt,
+            self.layers.groups(),
+            viewport.physical_size(),
+            scale_factor,
+        );
+
+        for (_, instance) in &self.offscreen {
+            instance.primitive.prepare(
+                device,
+                queue,
+                engine.format,
+                &mut engine.primitive_storage,
+                &instance.bounds,
+                viewport,
+            );
+        }
+
         for layer in self.layers.iter_mut() {
             if !layer.quads.is_empty() {
                 engine.quad_pipeline.prepare(
@@ -220,36 +277,38 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
-        let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("iced_wgpu render pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: frame,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: match clear_color {
-                            Some(background_color) => wgpu::LoadOp::Clear({
-                                let [r, g, b, a] =
-                                    graphics::color::pack(background_color)
-                                        .components();
-
-                                wgpu::Color {
-                                    r: f64::from(r),
-                                    g: f64::from(g),
-                                    b: f64::from(b),
-                                    a: f64::from(a),
-                                }
-                            }),
-                            None => wgpu::LoadOp::Load,
-                        },
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        ));
+        let clear = match clear_color {
+            Some(background_color) => wgpu::LoadOp::Clear({
+                let [r, g, b, a] =
+                    graphics::color::pack(background_color).components();
+
+                wgpu::Color {
+                    r: f64::from(r),
+                    g: f64::from(g),
+                    b: f64::from(b),
+                    a: f64::from(a),
+                }
+            }),
+            None => wgpu::LoadOp::Load,
+        };
+
+        let groups = self.layers.groups();
+
+        // Layers with effects are drawn in a canvas that can be sampled
+        // before being presented to the frame. The canvas always starts
+        // empty, since it is composited onto the frame every time
+        let (canvas, canvas_load) = if groups.is_empty() {
+            (frame, clear)
+        } else {
+            (
+                engine.effect_pipeline.target(0),
+                wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+            )
+        };
+
+        let mut target = canvas;
+        let mut render_pass =
+            ManuallyDrop::new(begin_render_pass(encoder, target, canvas_load));
 
         let mut quad_layer = 0;
         let mut mesh_layer = 0;
@@ -267,7 +326,77 @@ impl Renderer {
 
         let scale = Transformation::scale(scale_factor);
 
-        for layer in self.layers.iter() {
+        let mut open_groups: Vec<usize> = Vec::new();
+        let mut next_group = 0;
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            while let Some(&group) = open_groups.last() {
+                if groups[group].layers.end > index {
+                    break;
+                }
+
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let depth = open_groups.len();
+                let _ = open_groups.pop();
+
+                target = if open_groups.is_empty() {
+                    canvas
+                } else {
+                    engine.effect_pipeline.target(depth - 1)
+                };
+
+                if let Some(bounds) = physical_bounds
+                    .intersection(&(groups[group].bounds * scale))
+                    .and_then(Rectangle::snap)
+                {
+                    self.composite(
+                        engine, encoder, depth, group, target, bounds, viewport,
+                    );
+                }
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                ));
+            }
+
+            while groups
+                .get(next_group)
+                .is_some_and(|group| group.layers.start == index)
+            {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let group = &groups[next_group];
+
+                if group.effect.backdrop_blur > 0.0 {
+                    if let Some(bounds) = physical_bounds
+                        .intersection(&(group.bounds * scale))
+                        .and_then(Rectangle::snap)
+                    {
+                        engine.effect_pipeline.blur(
+                            encoder,
+                            open_groups.len(),
+                            next_group,
+                            group.effect.backdrop_blur * scale_factor,
+                            bounds,
+                        );
+                    }
+                }
+
+                open_groups.push(next_group);
+                next_group += 1;
+
+                target = engine.effect_pipeline.target(open_groups.len());
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                ));
+            }
+
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale))
             else {
@@ -294,7 +423,7 @@ impl Renderer {
 
                 mesh_layer += engine.triangle_pipeline.render(
                     encoder,
-                    frame,
+                    target,
                     &self.triangle_storage,
                     mesh_layer,
                     &layer.triangles,
@@ -302,23 +431,10 @@ impl Renderer {
                     scale,
                 );
 
-                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-                    &wgpu::RenderPassDescriptor {
-                        label: Some("iced_wgpu render pass"),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view: frame,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Load,
-                                    store: wgpu::StoreOp::Store,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                        timestamp_writes: None,
-                        occlusion_query_set: None,
-                    },
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
                 ));
             }
 
@@ -333,29 +449,16 @@ impl Renderer {
                         instance.primitive.render(
                             encoder,
                             &engine.primitive_storage,
-                            frame,
+                            target,
                             &clip_bounds,
                         );
                     }
                 }
 
-                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-                    &wgpu::RenderPassDescriptor {
-                        label: Some("iced_wgpu render pass"),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view: frame,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Load,
-                                    store: wgpu::StoreOp::Store,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                        timestamp_writes: None,
-                        occlusion_query_set: None,
-                    },
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
                 ));
             }
 
@@ -384,6 +487,70 @@ impl Renderer {
         }
 
         let _ = ManuallyDrop::into_inner(render_pass);
+
+        while let Some(group) = open_groups.pop() {
+            let depth = open_groups.len() + 1;
+
+            let target = if open_groups.is_empty() {
+                canvas
+            } else {
+                engine.effect_pipeline.target(depth - 1)
+            };
+
+            if let Some(bounds) = physical_bounds
+                .intersection(&(groups[group].bounds * scale))
+                .and_then(Rectangle::snap)
+            {
+                self.composite(
+                    engine, encoder, depth, group, target, bounds, viewport,
+                );
+            }
+        }
+
+        if !groups.is_empty() {
+            engine
+                .effect_pipeline
+                .present(encoder, groups.len(), frame, clear);
+        }
+    }
+
+    /// Composites the offscreen target of a group onto the given target;
+    /// either with its effect or with the [`Primitive`] it was drawn with.
+    fn composite(
+        &self,
+        engine: &Engine,
+        encoder: &mut wgpu::CommandEncoder,
+        depth: usize,
+        group: usize,
+        target: &wgpu::TextureView,
+        bounds: Rectangle<u32>,
+        viewport: &Viewport,
+    ) {
+        let Ok(index) = self
+            .offscreen
+            .binary_search_by_key(&group, |(group, _)| *group)
+        else {
+            engine
+                .effect_pipeline
+                .composite(encoder, depth, group, target, bounds);
+
+            return;
+        };
+
+        let texture = primitive::Texture {
+            view: engine.effect_pipeline.target(depth),
+            size: viewport.physical_size(),
+            bounds,
+            scale_factor: viewport.scale_factor() as f32,
+        };
+
+        self.offscreen[index].1.primitive.render_layer(
+            encoder,
+            &engine.primitive_storage,
+            &texture,
+            target,
+            &bounds,
+        );
     }
 
     fn draw_overlay(
@@ -409,6 +576,8 @@ impl Renderer {
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Basic,
                         wrapping: core::text::Wrapping::Word,
+                        ellipsis: core::text::Ellipsis::None,
+                        max_lines: None,
                     };
 
                     renderer.fill_text(
@@ -436,6 +605,14 @@ impl core::Renderer for Renderer {
         self.layers.push_clip(bounds);
     }
 
+    fn start_layer_with_effect(
+        &mut self,
+        bounds: Rectangle,
+        effect: core::renderer::Effect,
+    ) {
+        self.layers.push_clip_with_effect(bounds, effect);
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
@@ -459,6 +636,7 @@ impl core::Renderer for Renderer {
 
     fn clear(&mut self) {
         self.layers.clear();
+        self.offscreen.clear();
     }
 }
 
@@ -528,6 +706,10 @@ impl core::image::Renderer for Renderer {
         self.image_cache.borrow_mut().measure_image(handle)
     }
 
+    fn image_state(&self, handle: &Self::Handle) -> core::image::State {
+        self.image_cache.borrow_mut().image_state(handle)
+    }
+
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, transformation);
@@ -568,11 +750,16 @@ impl graphics::geometry::Renderer for Renderer {
         match geometry {
             Geometry::Live {
                 meshes,
+                textured,
                 images,
                 text,
             } => {
                 layer.draw_mesh_group(meshes, transformation);
 
+                for mesh in textured {
+                    layer.draw_mesh(mesh, transformation);
+                }
+
                 for image in images {
                     layer.draw_image(image, transformation);
                 }
@@ -584,6 +771,12 @@ impl graphics::geometry::Renderer for Renderer {
                     layer.draw_mesh_cache(meshes, transformation);
                 }
 
+                if let Some(textured) = cache.textured {
+                    for mesh in textured.iter().cloned() {
+                        layer.draw_mesh(mesh, transformation);
+                    }
+                }
+
                 if let Some(images) = cache.images {
                     for image in images.iter().cloned() {
                         layer.draw_image(image, transformation);
@@ -603,6 +796,42 @@ impl primitive::Renderer for Renderer {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_primitive(bounds, Box::new(primitive), transformation);
     }
+
+    fn start_layer_with_primitive(
+        &mut self,
+        bounds: Rectangle,
+        primitive: impl Primitive,
+    ) {
+        let transformation = self.layers.transformation();
+        let group =
+            self.layers.push_group(bounds, core::renderer::Effect::NONE);
+
+        self.offscreen.push((
+            group,
+            primitive::Instance::new(bounds * transformation, primitive),
+        ));
+    }
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    target: &wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load,
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
 }
 
 impl graphics::compositor::Default for crate::Renderer {
@@ -1,5 +1,5 @@
 //! Draw custom primitives.
-use crate::core::{self, Rectangle};
+use crate::core::{self, Rectangle, Size};
 use crate::graphics::Viewport;
 
 use rustc_hash::FxHashMap;
@@ -30,6 +30,77 @@ pub trait Primitive: Debug + Send + Sync + 'static {
         target: &wgpu::TextureView,
         clip_bounds: &Rectangle<u32>,
     );
+
+    /// Renders the [`Primitive`] using the contents of the layer it was drawn
+    /// with, which have been rendered offscreen into a [`Texture`].
+    ///
+    /// This is only called for primitives drawn with
+    /// [`Renderer::start_layer_with_primitive`]; in which case the
+    /// [`Primitive`] is in charge of drawing the contents of the layer onto
+    /// the `target`. Nothing else draws them.
+    ///
+    /// [`prepare`](Self::prepare) is called first with the logical bounds
+    /// of the layer. Then, once the layer and its nested layers have been
+    /// rendered, this method is called with:
+    ///
+    /// - `layer`, the offscreen contents of the layer. See [`Texture`].
+    /// - `target`, the texture of the parent layer or the frame. It has the
+    ///   same format and size as the [`Texture`]. Its contents must be kept,
+    ///   so render passes must load them and blend the layer on top; with
+    ///   premultiplied alpha.
+    /// - `clip_bounds`, the physical bounds the [`Primitive`] should draw
+    ///   in. They are the same as [`Texture::bounds`].
+    ///
+    /// By default, the contents of the layer are ignored and
+    /// [`render`](Self::render) is called instead.
+    fn render_layer(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        storage: &Storage,
+        _layer: &Texture<'_>,
+        target: &wgpu::TextureView,
+        clip_bounds: &Rectangle<u32>,
+    ) {
+        self.render(encoder, storage, target, clip_bounds);
+    }
+}
+
+/// The offscreen contents of a layer drawn with a [`Primitive`].
+///
+/// The texture is the full-window offscreen target of the layer, which the
+/// renderer shares with other groups of layers at the same nesting depth. It
+/// is cleared to transparent when the layer starts and the layer is rendered
+/// into it at the position it would have in the frame. Therefore, texture
+/// coordinates are the physical position in the frame divided by
+/// [`size`](Self::size).
+///
+/// Only the pixels inside [`bounds`](Self::bounds) belong to the layer;
+/// anything outside should not be sampled.
+#[derive(Debug, Clone, Copy)]
+pub struct Texture<'a> {
+    /// The view of the texture.
+    ///
+    /// The texture has the same format as the frame and its colors have
+    /// premultiplied alpha.
+    pub view: &'a wgpu::TextureView,
+
+    /// The physical size of the texture.
+    ///
+    /// It always matches the physical size of the viewport.
+    pub size: Size<u32>,
+
+    /// The physical bounds of the contents of the layer in the texture.
+    ///
+    /// They are the bounds of the layer scaled by
+    /// [`scale_factor`](Self::scale_factor), clipped to the viewport and
+    /// snapped to whole pixels. They are never empty.
+    pub bounds: Rectangle<u32>,
+
+    /// The scale factor used to render the contents of the layer.
+    ///
+    /// Multiply logical quantities by it to get physical pixels in the
+    /// texture; for instance, the radius of a blur.
+    pub scale_factor: f32,
 }
 
 #[derive(Debug)]
@@ -56,6 +127,39 @@ impl Instance {
 pub trait Renderer: core::Renderer {
     /// Draws a custom primitive.
     fn draw_primitive(&mut self, bounds: Rectangle, primitive: impl Primitive);
+
+    /// Starts recording a new layer that will be rendered offscreen and
+    /// then drawn by the given [`Primitive`].
+    ///
+    /// The contents of the layer—including any nested layers—will be
+    /// rendered into a [`Texture`] once the layer ends; which will be
+    /// passed to [`Primitive::render_layer`].
+    ///
+    /// By default, the [`Primitive`] is ignored and a regular layer is
+    /// started instead.
+    fn start_layer_with_primitive(
+        &mut self,
+        bounds: Rectangle,
+        _primitive: impl Primitive,
+    ) {
+        self.start_layer(bounds);
+    }
+
+    /// Draws the primitives recorded in the given closure in a new layer
+    /// that will be rendered offscreen and then drawn by the given
+    /// [`Primitive`].
+    ///
+    /// The layer will clip its contents to the provided `bounds`.
+    fn with_layer_primitive(
+        &mut self,
+        bounds: Rectangle,
+        primitive: impl Primitive,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_layer_with_primitive(bounds, primitive);
+        f(self);
+        self.end_layer();
+    }
 }
 
 /// Stores custom, user-provided types.
@@ -41,6 +41,11 @@ pub struct Quad {
 
     /// The shadow blur radius of the [`Quad`].
     pub shadow_blur_radius: f32,
+
+    /// Whether the shadow of the [`Quad`] is drawn inside its bounds.
+    ///
+    /// It is `1.0` for inner shadows and `0.0` otherwise.
+    pub shadow_inset: f32,
 }
 
 #[derive(Debug)]
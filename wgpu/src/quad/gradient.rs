@@ -149,7 +149,15 @@ impl Pipeline {
                                 // Border radius
                                 8 => Float32x4,
                                 // Border width
-                                9 => Float32
+                                9 => Float32,
+                                // Shadow color
+                                10 => Float32x4,
+                                // Shadow offset
+                                11 => Float32x2,
+                                // Shadow blur radius
+                                12 => Float32,
+                                // Shadow inset
+                                13 => Float32,
                             ),
                         }],
                         compilation_options:
@@ -112,6 +112,8 @@ impl Pipeline {
                             7 => Float32x2,
                             // Shadow blur radius
                             8 => Float32,
+                            // Shadow inset
+                            9 => Float32,
                         ),
                     }],
                     compilation_options:
@@ -27,6 +27,18 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The maximum amount of memory, in bytes, that cached images may use.
+    ///
+    /// Once exceeded, the least recently used images that are not being
+    /// displayed will be evicted.
+    ///
+    /// Only raster images count towards the budget; rasterized vector
+    /// graphics do not.
+    ///
+    /// By default, it is `None`; and images are only evicted when they
+    /// stop being displayed.
+    pub image_budget: Option<u64>,
 }
 
 impl Default for Settings {
@@ -37,6 +49,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_budget: None,
         }
     }
 }
@@ -47,6 +60,7 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            image_budget: settings.image_budget,
             ..Settings::default()
         }
     }
@@ -0,0 +1,44 @@
+struct Uniforms {
+    direction: vec2<f32>,
+    radius: f32,
+    opacity: f32,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    // A single triangle covering the whole target; scissors do the rest
+    let uv = vec2<f32>(vec2((vertex_index << 1u) & 2u, vertex_index & 2u));
+
+    return vec4<f32>(uv * vec2(2.0, -2.0) + vec2(-1.0, 1.0), 0.0, 1.0);
+}
+
+@fragment
+fn fs_composite(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return textureLoad(u_texture, vec2<i32>(position.xy), 0) * uniforms.opacity;
+}
+
+@fragment
+fn fs_blur(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let size = vec2<i32>(textureDimensions(u_texture)) - vec2(1, 1);
+    let origin = vec2<i32>(position.xy);
+    let direction = vec2<i32>(uniforms.direction);
+
+    let sigma = max(uniforms.radius / 2.0, 0.5);
+    let extent = i32(ceil(sigma * 3.0));
+
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+
+    for (var i = -extent; i <= extent; i++) {
+        let weight = exp(-f32(i * i) / (2.0 * sigma * sigma));
+        let sample = clamp(origin + direction * i, vec2(0, 0), size);
+
+        color += textureLoad(u_texture, sample, 0) * weight;
+        total += weight;
+    }
+
+    return color / total;
+}
@@ -69,3 +69,22 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the texture at the given UV coordinate and layer.
     return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
 }
+
+struct MeshInput {
+    @location(0) position: vec2<f32>,
+    @location(1) atlas_pos: vec2<f32>,
+    @location(2) layer: i32,
+    @location(3) opacity: f32,
+}
+
+@vertex
+fn vs_mesh(input: MeshInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.uv = input.atlas_pos;
+    out.layer = f32(input.layer);
+    out.opacity = input.opacity;
+    out.position = globals.transform * vec4<f32>(input.position * globals.scale_factor, 0.0, 1.0);
+
+    return out;
+}
@@ -22,6 +22,13 @@ fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
     return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
 }
 
+// Like `rounded_box_sdf`, but the distance is negative for points inside of the rounded rectangle
+fn signed_rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(to_center) - size + vec2<f32>(radius, radius);
+
+    return length(max(q, vec2<f32>(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
 // Based on the fragment position and the center of the quad, select one of the 4 radii.
 // Order matches CSS border radius attribute:
 // radii.x = top-left, radii.y = top-right, radii.z = bottom-right, radii.w = bottom-left
@@ -33,3 +40,78 @@ fn select_border_radius(radii: vec4<f32>, position: vec2<f32>, center: vec2<f32>
     rx = select(rx, ry, position.y > center.y);
     return rx;
 }
+
+// Blends the `top` color over the `bottom` color, both with straight alpha.
+fn blend_over(top: vec4<f32>, bottom: vec4<f32>) -> vec4<f32> {
+    let alpha = top.a + bottom.a * (1.0 - top.a);
+
+    if alpha <= 0.0 {
+        return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    }
+
+    let color = (top.rgb * top.a + bottom.rgb * bottom.a * (1.0 - top.a)) / alpha;
+
+    return vec4<f32>(color, alpha);
+}
+
+// Draws an inner shadow on top of the `background` color of a quad.
+fn inner_shadow(
+    background: vec4<f32>,
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radii: vec4<f32>,
+    shadow_color: vec4<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur_radius: f32
+) -> vec4<f32> {
+    let shadow_radius = select_border_radius(
+        radii,
+        frag_coord - shadow_offset,
+        position + size * 0.5
+    );
+
+    let shadow_distance = signed_rounded_box_sdf(
+        frag_coord - position - shadow_offset - (size / 2.0),
+        size / 2.0,
+        shadow_radius
+    );
+
+    let blur = max(shadow_blur_radius, 0.5);
+    let shadow_alpha = smoothstep(-blur, blur, shadow_distance);
+
+    return blend_over(
+        vec4<f32>(shadow_color.xyz, shadow_color.w * shadow_alpha),
+        background
+    );
+}
+
+// Draws an outer shadow beneath the `quad_color` of a quad.
+fn outer_shadow(
+    quad_color: vec4<f32>,
+    radius_alpha: f32,
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radii: vec4<f32>,
+    shadow_color: vec4<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur_radius: f32
+) -> vec4<f32> {
+    let shadow_radius = select_border_radius(
+        radii,
+        frag_coord - shadow_offset,
+        position + size * 0.5
+    );
+
+    let shadow_distance = max(rounded_box_sdf(frag_coord - position - shadow_offset - (size / 2.0), size / 2.0, shadow_radius), 0.);
+
+    let shadow_alpha = 1.0 - smoothstep(-shadow_blur_radius, shadow_blur_radius, shadow_distance);
+    let base_color = mix(
+        vec4<f32>(shadow_color.x, shadow_color.y, shadow_color.z, 0.0),
+        quad_color,
+        quad_color.a
+    );
+
+    return mix(base_color, shadow_color, (1.0 - radius_alpha) * shadow_alpha);
+}
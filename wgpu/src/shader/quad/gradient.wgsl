@@ -10,6 +10,10 @@ struct GradientVertexInput {
     @location(7) border_color: vec4<f32>,
     @location(8) border_radius: vec4<f32>,
     @location(9) border_width: f32,
+    @location(10) shadow_color: vec4<f32>,
+    @location(11) shadow_offset: vec2<f32>,
+    @location(12) shadow_blur_radius: f32,
+    @location(13) shadow_inset: f32,
 }
 
 struct GradientVertexOutput {
@@ -24,6 +28,10 @@ struct GradientVertexOutput {
     @location(8) border_color: vec4<f32>,
     @location(9) border_radius: vec4<f32>,
     @location(10) border_width: f32,
+    @location(11) shadow_color: vec4<f32>,
+    @location(12) shadow_offset: vec2<f32>,
+    @location(13) shadow_blur_radius: f32,
+    @location(14) shadow_inset: f32,
 }
 
 @vertex
@@ -41,11 +49,23 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    // Outer shadows are drawn outside of the bounds of the quad
+    var shadow_offset: vec2<f32> = vec2<f32>(0.0, 0.0);
+    var shadow_blur_radius: f32 = 0.0;
+
+    if input.shadow_inset < 0.5 {
+        shadow_offset = input.shadow_offset;
+        shadow_blur_radius = input.shadow_blur_radius;
+    }
+
+    let draw_pos = (input.position_and_scale.xy + min(shadow_offset, vec2<f32>(0.0, 0.0)) - shadow_blur_radius) * globals.scale;
+    let draw_scale = (input.position_and_scale.zw + abs(shadow_offset) + shadow_blur_radius * 2.0) * globals.scale;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(draw_scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, draw_scale.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(draw_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.position = globals.transform * transform * vec4<f32>(vertex_position(input.vertex_index), 0.0, 1.0);
@@ -59,6 +79,10 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.border_color = input.border_color;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = input.shadow_offset * globals.scale;
+    out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_inset = input.shadow_inset;
 
     return out;
 }
@@ -152,11 +176,26 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var background: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
 
+    if input.shadow_color.a > 0.0 && input.shadow_inset > 0.5 {
+        background = inner_shadow(
+            background,
+            input.position.xy,
+            pos,
+            scale,
+            input.border_radius,
+            input.shadow_color,
+            input.shadow_offset,
+            input.shadow_blur_radius
+        );
+    }
+
+    var mixed_color: vec4<f32> = background;
+
     var border_radius = select_border_radius(
         input.border_radius,
         input.position.xy,
@@ -179,7 +218,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
             internal_distance
         );
 
-        mixed_color = mix(mixed_color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        mixed_color = mix(background, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
@@ -194,7 +233,23 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         border_radius + 0.5,
         dist);
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    let quad_color = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+
+    if input.shadow_color.a > 0.0 && input.shadow_inset < 0.5 {
+        return outer_shadow(
+            quad_color,
+            radius_alpha,
+            input.position.xy,
+            pos,
+            scale,
+            input.border_radius,
+            input.shadow_color,
+            input.shadow_offset,
+            input.shadow_blur_radius
+        );
+    } else {
+        return quad_color;
+    }
 }
 
 fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
@@ -9,6 +9,7 @@ struct SolidVertexInput {
     @location(6) shadow_color: vec4<f32>,
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
+    @location(9) shadow_inset: f32,
 }
 
 struct SolidVertexOutput {
@@ -22,6 +23,7 @@ struct SolidVertexOutput {
     @location(6) shadow_color: vec4<f32>,
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
+    @location(9) shadow_inset: f32,
 }
 
 @vertex
@@ -65,6 +67,7 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     out.shadow_color = input.shadow_color;
     out.shadow_offset = input.shadow_offset * globals.scale;
     out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_inset = input.shadow_inset;
 
     return out;
 }
@@ -73,7 +76,22 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 fn solid_fs_main(
     input: SolidVertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var background: vec4<f32> = input.color;
+
+    if input.shadow_color.a > 0.0 && input.shadow_inset > 0.5 {
+        background = inner_shadow(
+            background,
+            input.position.xy,
+            input.pos,
+            input.scale,
+            input.border_radius,
+            input.shadow_color,
+            input.shadow_offset,
+            input.shadow_blur_radius
+        );
+    }
+
+    var mixed_color: vec4<f32> = background;
 
     var border_radius = select_border_radius(
         input.border_radius,
@@ -97,7 +115,7 @@ fn solid_fs_main(
             internal_distance
         );
 
-        mixed_color = mix(input.color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        mixed_color = mix(background, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
@@ -115,23 +133,18 @@ fn solid_fs_main(
 
     let quad_color = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
 
-    if input.shadow_color.a > 0.0 {
-        let shadow_radius = select_border_radius(
-            input.border_radius,
-            input.position.xy - input.shadow_offset,
-            (input.pos + input.scale * 0.5).xy
-        );
-        let shadow_distance = max(rounded_box_sdf(input.position.xy - input.pos - input.shadow_offset - (input.scale / 2.0), input.scale / 2.0, shadow_radius), 0.);
-        
-        let shadow_alpha = 1.0 - smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, shadow_distance);
-        let shadow_color = input.shadow_color;
-        let base_color = mix(
-            vec4<f32>(shadow_color.x, shadow_color.y, shadow_color.z, 0.0),
+    if input.shadow_color.a > 0.0 && input.shadow_inset < 0.5 {
+        return outer_shadow(
             quad_color,
-            quad_color.a
+            radius_alpha,
+            input.position.xy,
+            input.pos,
+            input.scale,
+            input.border_radius,
+            input.shadow_color,
+            input.shadow_offset,
+            input.shadow_blur_radius
         );
-
-        return mix(base_color, shadow_color, (1.0 - radius_alpha) * shadow_alpha);
     } else {
         return quad_color;
     }
@@ -442,7 +442,6 @@ fn prepare(
     layer_transformation: Transformation,
 ) -> Result<(), glyphon::PrepareError> {
     let mut font_system = font_system().write().expect("Write font system");
-    let font_system = font_system.raw();
 
     enum Allocation {
         Paragraph(Paragraph),
@@ -470,7 +469,7 @@ fn prepare(
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
-                    font_system,
+                    &mut font_system,
                     text_cache::Key {
                         content,
                         size: f32::from(*size),
@@ -640,7 +639,7 @@ fn prepare(
         device,
         queue,
         encoder,
-        font_system,
+        font_system.raw(),
         atlas,
         viewport,
         text_areas,
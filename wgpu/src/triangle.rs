@@ -432,7 +432,7 @@ impl Layer {
         let mut gradient_uniform_offset = 0;
         let mut index_offset = 0;
 
-        for mesh in meshes {
+        for mesh in meshes.iter().filter(|mesh| is_untextured(mesh)) {
             let indices = mesh.indices();
 
             let uniforms =
@@ -483,6 +483,7 @@ impl Layer {
                         &[uniforms],
                     );
                 }
+                Mesh::Textured { .. } => {}
             }
         }
     }
@@ -500,7 +501,9 @@ impl Layer {
         let mut num_gradients = 0;
         let mut last_is_solid = None;
 
-        for (index, mesh) in meshes.iter().enumerate() {
+        for (index, mesh) in
+            meshes.iter().filter(|mesh| is_untextured(mesh)).enumerate()
+        {
             let Some(clip_bounds) = bounds
                 .intersection(&(mesh.clip_bounds() * transformation))
                 .and_then(Rectangle::snap)
@@ -512,6 +515,7 @@ impl Layer {
                     Mesh::Gradient { .. } => {
                         num_gradients += 1;
                     }
+                    Mesh::Textured { .. } => {}
                 }
                 continue;
             };
@@ -566,6 +570,7 @@ impl Layer {
 
                     num_gradients += 1;
                 }
+                Mesh::Textured { .. } => {}
             };
 
             render_pass.set_index_buffer(
@@ -578,6 +583,13 @@ impl Layer {
     }
 }
 
+/// Returns whether the [`Mesh`] is drawn by the triangle pipeline.
+///
+/// Textured meshes are drawn by the image pipeline instead.
+fn is_untextured(mesh: &Mesh) -> bool {
+    !matches!(mesh, Mesh::Textured { .. })
+}
+
 fn fragment_target(
     texture_format: wgpu::TextureFormat,
 ) -> wgpu::ColorTargetState {
@@ -1,5 +1,5 @@
 //! Connect a window with a renderer.
-use crate::core::{Color, Size};
+use crate::core::{self, Color, Size};
 use crate::graphics::color;
 use crate::graphics::compositor;
 use crate::graphics::error;
@@ -300,12 +300,15 @@ impl graphics::Compositor for Compositor {
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             &self.device,
             &self.engine,
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+
+        renderer.set_image_budget(self.settings.image_budget);
+        renderer
     }
 
     fn create_surface<W: compositor::Window>(
@@ -356,6 +359,18 @@ impl graphics::Compositor for Compositor {
         }
     }
 
+    fn evict_image(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        handle: &core::image::Handle,
+    ) {
+        renderer.evict_image(handle);
+    }
+
+    fn image_metrics(&self, renderer: &Self::Renderer) -> core::image::Metrics {
+        renderer.image_metrics()
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
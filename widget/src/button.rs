@@ -28,7 +28,7 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
-    Shadow, Shell, Size, Theme, Vector, Widget,
+    Shadow, Shadows, Shell, Size, Theme, Vector, Widget,
 };
 
 /// A generic widget that produces a message when pressed.
@@ -371,13 +371,13 @@ where
 
         if style.background.is_some()
             || style.border.width > 0.0
-            || style.shadow.color.a > 0.0
+            || style.shadows.is_visible()
         {
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
                     border: style.border,
-                    shadow: style.shadow,
+                    shadows: style.shadows,
                 },
                 style
                     .background
@@ -482,8 +482,8 @@ pub struct Style {
     pub text_color: Color,
     /// The [`Border`] of the button.
     pub border: Border,
-    /// The [`Shadow`] of the button.
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the button.
+    pub shadows: Shadows,
 }
 
 impl Style {
@@ -494,6 +494,22 @@ impl Style {
             ..self
         }
     }
+
+    /// Sets the single shadow of the [`Style`].
+    pub fn shadow(self, shadow: impl Into<Shadow>) -> Self {
+        Self {
+            shadows: Shadows::from(shadow.into()),
+            ..self
+        }
+    }
+
+    /// Updates the shadows of the [`Style`].
+    pub fn shadows(self, shadows: impl Into<Shadows>) -> Self {
+        Self {
+            shadows: shadows.into(),
+            ..self
+        }
+    }
 }
 
 impl Default for Style {
@@ -502,7 +518,7 @@ impl Default for Style {
             background: None,
             text_color: Color::BLACK,
             border: Border::default(),
-            shadow: Shadow::default(),
+            shadows: Shadows::NONE,
         }
     }
 }
@@ -49,17 +49,20 @@
 //! }
 //! ```
 pub mod event;
+pub mod scene;
 
 mod program;
 
 pub use event::Event;
 pub use program::Program;
+pub use scene::Scene;
 
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
-    fill, gradient, path, stroke, Fill, Gradient, Image, LineCap, LineDash,
-    LineJoin, Path, Stroke, Style, Text,
+    export, fill, gradient, path, stroke, Fill, Gradient, Image, LineCap,
+    LineDash, LineJoin, Path, RichText, Stroke, Style, Text,
 };
+pub use crate::graphics::mesh::{Indexed, TexturedVertex2D};
 
 use crate::core;
 use crate::core::layout::{self, Layout};
@@ -0,0 +1,623 @@
+//! Retain identifiable shapes and query them.
+//!
+//! A [`Scene`] keeps a set of [`Shape`]s with their own [`Cache`]; so only
+//! the shapes that change are redrawn. It can also find the shapes under a
+//! point or inside a rectangle.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::canvas;
+//! use iced::widget::canvas::{scene, Path, Scene};
+//! use iced::{Color, Point};
+//!
+//! struct Editor {
+//!     scene: Scene,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Hovered(Option<scene::Id>),
+//!     Clicked(scene::Id),
+//! }
+//!
+//! impl Editor {
+//!     fn new() -> Self {
+//!         let mut scene = Scene::new();
+//!
+//!         let _circle = scene.insert(
+//!             scene::Shape::new(Path::circle(Point::new(50.0, 50.0), 20.0))
+//!                 .fill(Color::BLACK),
+//!         );
+//!
+//!         Self { scene }
+//!     }
+//!
+//!     fn update(&mut self, message: Message) {
+//!         if let Message::Clicked(id) = message {
+//!             self.scene.update(id, |shape| shape.z_index += 1);
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         canvas(
+//!             self.scene
+//!                 .interact()
+//!                 .on_hover(Message::Hovered)
+//!                 .on_click(Message::Clicked),
+//!         )
+//!         .into()
+//!     }
+//! }
+//! ```
+use crate::canvas::event::{self, Event};
+use crate::canvas::{Cache, Fill, Geometry, Path, Program, Stroke};
+use crate::core::mouse;
+use crate::core::{Point, Radians, Rectangle, Size, Vector};
+use crate::graphics::cache::Group;
+use crate::graphics::geometry;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The identifier of a [`Shape`] in a [`Scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u64);
+
+/// A shape of a [`Scene`].
+#[derive(Debug, Clone)]
+pub struct Shape {
+    /// The [`Path`] of the [`Shape`]; in local coordinates.
+    pub path: Path,
+
+    /// The [`Fill`] of the [`Shape`], if any.
+    pub fill: Option<Fill>,
+
+    /// The [`Stroke`] of the [`Shape`], if any.
+    pub stroke: Option<Stroke<'static>>,
+
+    /// The [`Transform`] of the [`Shape`].
+    pub transform: Transform,
+
+    /// The stacking order of the [`Shape`].
+    ///
+    /// Shapes with a higher index are drawn on top. Shapes with the same
+    /// index are drawn in insertion order.
+    pub z_index: i32,
+}
+
+impl Shape {
+    /// Creates a new [`Shape`] with the given [`Path`].
+    ///
+    /// It has no fill nor stroke by default.
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+            fill: None,
+            stroke: None,
+            transform: Transform::default(),
+            z_index: 0,
+        }
+    }
+
+    /// Sets the [`Fill`] of the [`Shape`].
+    pub fn fill(mut self, fill: impl Into<Fill>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Sets the [`Stroke`] of the [`Shape`].
+    pub fn stroke(mut self, stroke: impl Into<Stroke<'static>>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Sets the [`Transform`] of the [`Shape`].
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Sets the stacking order of the [`Shape`].
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Returns true if the given [`Point`], in local coordinates, hits the
+    /// [`Shape`].
+    fn contains(&self, point: Point, tolerance: f32) -> bool {
+        self.fill
+            .is_some_and(|fill| self.path.contains(point, fill.rule))
+            || self.stroke.is_some_and(|stroke| {
+                self.path.stroke_contains(point, stroke.width, tolerance)
+            })
+    }
+
+    /// Returns the axis-aligned bounds of the [`Shape`] in scene
+    /// coordinates.
+    fn bounds(&self) -> Rectangle {
+        let stroke_width = self.stroke.map_or(0.0, |stroke| stroke.width);
+        let local = self.path.bounds().expand(stroke_width / 2.0);
+
+        let corners = [
+            Point::new(local.x, local.y),
+            Point::new(local.x + local.width, local.y),
+            Point::new(local.x, local.y + local.height),
+            Point::new(local.x + local.width, local.y + local.height),
+        ]
+        .map(|corner| self.transform.apply(corner));
+
+        let (min, max) = corners.iter().fold(
+            (corners[0], corners[0]),
+            |(min, max), corner| {
+                (
+                    Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                    Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+                )
+            },
+        );
+
+        Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+    }
+}
+
+/// The placement of a [`Shape`] in a [`Scene`].
+///
+/// A [`Shape`] is scaled first, then rotated, and finally translated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// The translation of the [`Transform`].
+    pub translation: Vector,
+
+    /// The rotation of the [`Transform`].
+    pub rotation: Radians,
+
+    /// The uniform scale of the [`Transform`].
+    pub scale: f32,
+}
+
+impl Transform {
+    /// The identity [`Transform`].
+    pub const IDENTITY: Self = Self {
+        translation: Vector::ZERO,
+        rotation: Radians(0.0),
+        scale: 1.0,
+    };
+
+    /// Creates a [`Transform`] with the given translation.
+    pub fn translate(translation: impl Into<Vector>) -> Self {
+        Self {
+            translation: translation.into(),
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Transforms the given local [`Point`] into scene coordinates.
+    pub fn apply(&self, point: Point) -> Point {
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let x = point.x * self.scale;
+        let y = point.y * self.scale;
+
+        Point::new(
+            x * cos - y * sin + self.translation.x,
+            x * sin + y * cos + self.translation.y,
+        )
+    }
+
+    /// Transforms the given [`Point`] in scene coordinates into local
+    /// coordinates, if the [`Transform`] can be inverted.
+    pub fn invert(&self, point: Point) -> Option<Point> {
+        if self.scale == 0.0 {
+            return None;
+        }
+
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let x = point.x - self.translation.x;
+        let y = point.y - self.translation.y;
+
+        Some(Point::new(
+            (x * cos + y * sin) / self.scale,
+            (y * cos - x * sin) / self.scale,
+        ))
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+struct Entry<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    shape: Shape,
+    bounds: Rectangle,
+    cache: Cache<Renderer>,
+}
+
+/// A retained set of [`Shape`]s.
+///
+/// Every [`Shape`] is cached separately; so changing a [`Shape`] only redraws
+/// that [`Shape`]. All the caches share the same [`Group`].
+pub struct Scene<Renderer = crate::Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    entries: BTreeMap<Id, Entry<Renderer>>,
+    order: Vec<Id>,
+    group: Group,
+    tolerance: f32,
+    next_id: u64,
+}
+
+impl<Renderer> Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// The default distance tolerance used when hit testing strokes.
+    pub const DEFAULT_TOLERANCE: f32 = 2.0;
+
+    /// Creates a new empty [`Scene`].
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            order: Vec::new(),
+            group: Group::unique(),
+            tolerance: Self::DEFAULT_TOLERANCE,
+            next_id: 0,
+        }
+    }
+
+    /// Sets the extra distance allowed when hit testing the strokes of the
+    /// [`Scene`].
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Inserts a new [`Shape`] in the [`Scene`], returning its [`Id`].
+    pub fn insert(&mut self, shape: Shape) -> Id {
+        let id = Id(self.next_id);
+        self.next_id += 1;
+
+        let _ = self.entries.insert(
+            id,
+            Entry {
+                bounds: shape.bounds(),
+                shape,
+                cache: Cache::with_group(self.group),
+            },
+        );
+
+        self.sort();
+
+        id
+    }
+
+    /// Updates the [`Shape`] with the given [`Id`] using the given closure.
+    ///
+    /// The [`Shape`] will be redrawn the next time the [`Scene`] is drawn.
+    /// Returns `false` if the [`Shape`] does not exist.
+    pub fn update(&mut self, id: Id, f: impl FnOnce(&mut Shape)) -> bool {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return false;
+        };
+
+        let z_index = entry.shape.z_index;
+
+        f(&mut entry.shape);
+
+        entry.bounds = entry.shape.bounds();
+        entry.cache.clear();
+
+        if entry.shape.z_index != z_index {
+            self.sort();
+        }
+
+        true
+    }
+
+    /// Removes the [`Shape`] with the given [`Id`] from the [`Scene`].
+    pub fn remove(&mut self, id: Id) -> Option<Shape> {
+        let entry = self.entries.remove(&id)?;
+
+        self.order.retain(|other| *other != id);
+
+        Some(entry.shape)
+    }
+
+    /// Removes all the shapes of the [`Scene`].
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Returns the [`Shape`] with the given [`Id`], if it exists.
+    pub fn get(&self, id: Id) -> Option<&Shape> {
+        self.entries.get(&id).map(|entry| &entry.shape)
+    }
+
+    /// Returns the number of shapes in the [`Scene`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Scene`] has no shapes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the shapes of the [`Scene`]; from bottom to
+    /// top.
+    pub fn shapes(&self) -> impl Iterator<Item = (Id, &Shape)> + '_ {
+        self.order.iter().map(|id| (*id, &self.entries[id].shape))
+    }
+
+    /// Returns the [`Id`] of the topmost [`Shape`] at the given [`Point`],
+    /// if any.
+    pub fn hit_test(&self, point: Point) -> Option<Id> {
+        self.order.iter().rev().copied().find(|id| {
+            let entry = &self.entries[id];
+
+            entry.bounds.expand(self.tolerance).contains(point)
+                && entry.shape.transform.invert(point).is_some_and(|local| {
+                    entry.shape.contains(
+                        local,
+                        self.tolerance / entry.shape.transform.scale.abs(),
+                    )
+                })
+        })
+    }
+
+    /// Returns the [`Id`]s of the shapes completely inside of the given
+    /// [`Rectangle`]; from bottom to top.
+    pub fn contained_in(&self, rectangle: Rectangle) -> Vec<Id> {
+        self.query(|bounds| bounds.is_within(&rectangle))
+    }
+
+    /// Returns the [`Id`]s of the shapes whose bounds intersect with the given
+    /// [`Rectangle`]; from bottom to top.
+    pub fn intersecting(&self, rectangle: Rectangle) -> Vec<Id> {
+        self.query(|bounds| bounds.intersects(&rectangle))
+    }
+
+    /// Draws the [`Scene`] with the given [`Size`], producing a layer of
+    /// [`Geometry`] per [`Shape`].
+    ///
+    /// Only the shapes that changed since the last call will be redrawn.
+    pub fn draw(
+        &self,
+        renderer: &Renderer,
+        size: Size,
+    ) -> Vec<Geometry<Renderer>> {
+        self.order
+            .iter()
+            .map(|id| {
+                let entry = &self.entries[id];
+                let shape = &entry.shape;
+
+                entry.cache.draw(renderer, size, |frame| {
+                    frame.translate(shape.transform.translation);
+                    frame.rotate(shape.transform.rotation);
+                    frame.scale(shape.transform.scale);
+
+                    if let Some(fill) = shape.fill {
+                        frame.fill(&shape.path, fill);
+                    }
+
+                    if let Some(stroke) = shape.stroke {
+                        frame.stroke(&shape.path, stroke);
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Creates an [`Interaction`] with the [`Scene`]; a [`Program`] that can
+    /// be used in a [`Canvas`].
+    ///
+    /// [`Canvas`]: crate::Canvas
+    pub fn interact<Message>(&self) -> Interaction<'_, Message, Renderer> {
+        Interaction {
+            scene: self,
+            on_hover: None,
+            on_click: None,
+        }
+    }
+
+    fn query(&self, predicate: impl Fn(&Rectangle) -> bool) -> Vec<Id> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|id| predicate(&self.entries[id].bounds))
+            .collect()
+    }
+
+    fn sort(&mut self) {
+        self.order = self.entries.keys().copied().collect();
+
+        let entries = &self.entries;
+        self.order.sort_by_key(|id| entries[id].shape.z_index);
+    }
+}
+
+impl<Renderer> Default for Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Renderer> fmt::Debug for Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scene")
+            .field("shapes", &self.entries.len())
+            .field("group", &self.group)
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+/// A [`Program`] that draws a [`Scene`] and produces messages when its
+/// shapes are hovered or clicked.
+#[allow(missing_debug_implementations)]
+pub struct Interaction<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    scene: &'a Scene<Renderer>,
+    on_hover: Option<Box<dyn Fn(Option<Id>) -> Message + 'a>>,
+    on_click: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> Interaction<'a, Message, Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Sets the message produced when the hovered [`Shape`] changes.
+    pub fn on_hover(
+        mut self,
+        on_hover: impl Fn(Option<Id>) -> Message + 'a,
+    ) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the message produced when a [`Shape`] is clicked.
+    pub fn on_click(mut self, on_click: impl Fn(Id) -> Message + 'a) -> Self {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Program<Message, Theme, Renderer>
+    for Interaction<'a, Message, Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    type State = Option<Id>;
+
+    fn update(
+        &self,
+        hovered: &mut Option<Id>,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+            ) => {
+                let current = cursor
+                    .position_in(bounds)
+                    .and_then(|position| self.scene.hit_test(position));
+
+                if current == *hovered {
+                    return (event::Status::Ignored, None);
+                }
+
+                *hovered = current;
+
+                (
+                    event::Status::Ignored,
+                    self.on_hover.as_ref().map(|on_hover| on_hover(current)),
+                )
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(on_click) = &self.on_click else {
+                    return (event::Status::Ignored, None);
+                };
+
+                match cursor
+                    .position_in(bounds)
+                    .and_then(|position| self.scene.hit_test(position))
+                {
+                    Some(id) => (event::Status::Captured, Some(on_click(id))),
+                    None => (event::Status::Ignored, None),
+                }
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _hovered: &Option<Id>,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        self.scene.draw(renderer, bounds.size())
+    }
+
+    fn mouse_interaction(
+        &self,
+        hovered: &Option<Id>,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if self.on_click.is_some()
+            && hovered.is_some()
+            && cursor.is_over(bounds)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Color;
+
+    #[test]
+    fn hit_testing_and_selection() {
+        let mut scene = Scene::<()>::new();
+
+        let square = scene.insert(
+            Shape::new(Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0)))
+                .fill(Color::BLACK)
+                .transform(Transform::translate(Vector::new(20.0, 20.0))),
+        );
+
+        let line = scene.insert(
+            Shape::new(Path::line(Point::ORIGIN, Point::new(50.0, 50.0)))
+                .stroke(Stroke::default().with_width(2.0))
+                .z_index(-1),
+        );
+
+        assert_eq!(scene.hit_test(Point::new(25.0, 25.0)), Some(square));
+        assert_eq!(scene.hit_test(Point::new(40.0, 41.0)), Some(line));
+        assert_eq!(scene.hit_test(Point::new(40.0, 10.0)), None);
+
+        let _ = scene.update(line, |shape| shape.z_index = 1);
+
+        assert_eq!(scene.hit_test(Point::new(25.0, 25.0)), Some(line));
+        assert_eq!(
+            scene.shapes().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![square, line]
+        );
+
+        let selection =
+            Rectangle::new(Point::new(15.0, 15.0), Size::new(20.0, 20.0));
+
+        assert_eq!(scene.contained_in(selection), vec![square]);
+        assert_eq!(scene.intersecting(selection), vec![square, line]);
+
+        assert!(scene.remove(square).is_some());
+        assert_eq!(scene.len(), 1);
+    }
+}
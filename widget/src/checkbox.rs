@@ -295,6 +295,8 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsis::None,
+                    None,
                 )
             },
         )
@@ -404,6 +406,8 @@ where
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
                         wrapping: text::Wrapping::default(),
+                        ellipsis: text::Ellipsis::default(),
+                        max_lines: None,
                     },
                     bounds.center(),
                     style.icon_color,
@@ -424,6 +428,7 @@ where
                 state.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -31,8 +31,8 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
     self, color, Background, Clipboard, Color, Element, Layout, Length,
-    Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Theme, Vector,
-    Widget,
+    Padding, Pixels, Point, Rectangle, Shadow, Shadows, Shell, Size, Theme,
+    Vector, Widget,
 };
 use crate::runtime::task::{self, Task};
 
@@ -352,25 +352,48 @@ where
         let style = theme.style(&self.class);
 
         if let Some(clipped_viewport) = bounds.intersection(viewport) {
-            draw_background(renderer, &style, bounds);
-
-            self.content.as_widget().draw(
-                tree,
-                renderer,
-                theme,
-                &renderer::Style {
-                    text_color: style
-                        .text_color
-                        .unwrap_or(renderer_style.text_color),
-                },
-                layout.children().next().unwrap(),
-                cursor,
-                if self.clip {
-                    &clipped_viewport
-                } else {
-                    viewport
-                },
-            );
+            let draw_content = |renderer: &mut Renderer| {
+                self.content.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style
+                            .text_color
+                            .unwrap_or(renderer_style.text_color),
+                    },
+                    layout.children().next().unwrap(),
+                    cursor,
+                    if self.clip {
+                        &clipped_viewport
+                    } else {
+                        viewport
+                    },
+                );
+            };
+
+            if style.effect.is_none() {
+                draw_background(renderer, &style, bounds);
+                draw_content(renderer);
+            } else {
+                // The layer must fit the outer shadows, but the contents
+                // are still clipped to the bounds of the container
+                let layer_bounds = shadow_bounds(bounds, &style.shadows);
+
+                renderer.with_layer_effect(
+                    layer_bounds,
+                    style.effect,
+                    |renderer| {
+                        draw_background(renderer, &style, bounds);
+
+                        if layer_bounds == bounds {
+                            draw_content(renderer);
+                        } else {
+                            renderer.with_layer(bounds, draw_content);
+                        }
+                    },
+                );
+            }
         }
     }
 
@@ -442,13 +465,13 @@ pub fn draw_background<Renderer>(
 {
     if style.background.is_some()
         || style.border.width > 0.0
-        || style.shadow.color.a > 0.0
+        || style.shadows.is_visible()
     {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border: style.border,
-                shadow: style.shadow,
+                shadows: style.shadows,
             },
             style
                 .background
@@ -457,6 +480,19 @@ pub fn draw_background<Renderer>(
     }
 }
 
+/// Returns the bounds covered by a quad with the given `bounds` and its
+/// outer [`Shadows`].
+fn shadow_bounds(bounds: Rectangle, shadows: &Shadows) -> Rectangle {
+    shadows
+        .iter()
+        .filter(|shadow| !shadow.inset && shadow.color.a > 0.0)
+        .fold(bounds, |covered, shadow| {
+            covered.union(
+                &(bounds + shadow.offset).expand(shadow.blur_radius.max(0.0)),
+            )
+        })
+}
+
 /// The identifier of a [`Container`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Id(widget::Id);
@@ -580,8 +616,18 @@ pub struct Style {
     pub background: Option<Background>,
     /// The [`Border`] of the container.
     pub border: Border,
-    /// The [`Shadow`] of the container.
-    pub shadow: Shadow,
+    /// The [`Shadows`] of the container.
+    pub shadows: Shadows,
+    /// The [`Effect`] of the container.
+    ///
+    /// When present, the container will be drawn in its own layer; so the
+    /// effect applies to its background, border and [`Shadows`] as well as
+    /// to its contents. The layer grows to fit the outer shadows, which
+    /// means a backdrop blur also covers them. The contents are still
+    /// clipped to the bounds of the container.
+    ///
+    /// [`Effect`]: renderer::Effect
+    pub effect: renderer::Effect,
 }
 
 impl Style {
@@ -609,10 +655,26 @@ impl Style {
         }
     }
 
-    /// Updates the shadow of the [`Style`].
+    /// Sets the single shadow of the [`Style`].
     pub fn shadow(self, shadow: impl Into<Shadow>) -> Self {
         Self {
-            shadow: shadow.into(),
+            shadows: Shadows::from(shadow.into()),
+            ..self
+        }
+    }
+
+    /// Updates the shadows of the [`Style`].
+    pub fn shadows(self, shadows: impl Into<Shadows>) -> Self {
+        Self {
+            shadows: shadows.into(),
+            ..self
+        }
+    }
+
+    /// Updates the effect of the [`Style`].
+    pub fn effect(self, effect: impl Into<renderer::Effect>) -> Self {
+        Self {
+            effect: effect.into(),
             ..self
         }
     }
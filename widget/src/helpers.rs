@@ -4,6 +4,7 @@ use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
+use crate::core::keyboard::shortcut;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Pixels, Widget};
@@ -13,10 +14,12 @@ use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::range_slider::RangeSlider;
 use crate::rule::{self, Rule};
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
+use crate::shortcuts::Shortcuts;
 use crate::slider::{self, Slider};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
@@ -737,7 +740,7 @@ where
                         tree, layout, cursor, viewport, renderer,
                     )
                 })
-                .find(|&interaction| interaction != mouse::Interaction::None)
+                .find(|interaction| *interaction != mouse::Interaction::None)
                 .unwrap_or_default()
         }
 
@@ -1301,6 +1304,45 @@ where
     Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// Range sliders let users select a range of values by moving two handles.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+/// use std::ops::RangeInclusive;
+///
+/// struct State {
+///    range: RangeInclusive<f32>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged(RangeInclusive<f32>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0.0..=100.0, state.range.clone(), Message::RangeChanged)
+///         .into()
+/// }
+/// ```
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    values: std::ops::RangeInclusive<T>,
+    on_change: impl Fn(std::ops::RangeInclusive<T>) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: slider::Catalog + 'a,
+{
+    RangeSlider::new(range, values, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// Sliders let users set a value by moving an indicator.
@@ -1601,6 +1643,43 @@ where
     ProgressBar::new(range, value)
 }
 
+/// Creates a new [`Circular`] progress indicator.
+///
+/// Circular progress indicators display progress along a ring, or spin
+/// while the progress is unknown.
+///
+/// [`Circular`]: crate::progress_bar::Circular
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::circular_progress;
+///
+/// struct State {
+///    progress: f32,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     circular_progress(0.0..=100.0, state.progress).into()
+/// }
+/// ```
+#[cfg(feature = "canvas")]
+pub fn circular_progress<'a, Theme>(
+    range: RangeInclusive<f32>,
+    value: f32,
+) -> progress_bar::Circular<'a, Theme>
+where
+    Theme: progress_bar::Catalog + 'a,
+{
+    progress_bar::Circular::new(range, value)
+}
+
 /// Creates a new [`Image`].
 ///
 /// Images display raster graphics in different formats (PNG, JPG, etc.).
@@ -1628,6 +1707,39 @@ pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
     crate::Image::new(handle.into())
 }
 
+/// Creates a new [`Animated`] image that plays the given [`Frames`].
+///
+/// Animated images display GIF, APNG and animated WebP images.
+///
+/// [`Animated`]: crate::image::Animated
+/// [`Frames`]: crate::image::animated::Frames
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::animated_image;
+/// use iced::widget::image::animated;
+///
+/// struct State {
+///    frames: animated::Frames,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     animated_image(&state.frames).looping(false).into()
+/// }
+/// ```
+#[cfg(feature = "animated-image")]
+pub fn animated_image(
+    frames: &crate::image::animated::Frames,
+) -> crate::image::Animated<'_> {
+    crate::image::Animated::new(frames)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// Svg widgets display vector graphics in your application.
@@ -1808,6 +1920,24 @@ where
     crate::Shader::new(program)
 }
 
+/// Creates a new [`Offscreen`] widget that renders the given content
+/// offscreen and then draws it with the [`Primitive`] produced by the
+/// given function.
+///
+/// [`Offscreen`]: crate::shader::Offscreen
+/// [`Primitive`]: crate::shader::Primitive
+#[cfg(feature = "wgpu")]
+pub fn offscreen<'a, Message, Theme, Renderer, P>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    primitive: impl Fn(core::Rectangle, core::mouse::Cursor) -> P + 'a,
+) -> crate::shader::Offscreen<'a, Message, Theme, Renderer, P>
+where
+    Renderer: crate::renderer::wgpu::primitive::Renderer,
+    P: crate::shader::Primitive,
+{
+    crate::shader::Offscreen::new(content, primitive)
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_previous()))
@@ -1828,6 +1958,19 @@ where
     MouseArea::new(widget)
 }
 
+/// A widget that triggers the bindings of a keyboard shortcut [`Registry`].
+///
+/// [`Registry`]: shortcut::Registry
+pub fn shortcuts<'a, Message, Theme, Renderer>(
+    registry: &'a shortcut::Registry<Message>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Shortcuts<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Shortcuts::new(registry, content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -19,6 +19,14 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
+#[cfg(feature = "animated-image")]
+pub mod animated;
+#[cfg(feature = "animated-image")]
+pub use animated::Animated;
+
+mod fallback;
+pub use fallback::Fallback;
+
 use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
@@ -119,6 +127,30 @@ impl<Handle> Image<Handle> {
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the element displayed while the [`Image`] is loading.
+    ///
+    /// By default, nothing is displayed until the image is ready.
+    pub fn placeholder<'a, Message, Theme, Renderer>(
+        self,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Fallback<'a, Message, Theme, Renderer>
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        Fallback::new(self).placeholder(placeholder)
+    }
+
+    /// Sets the element displayed when the [`Image`] fails to load.
+    pub fn error<'a, Message, Theme, Renderer>(
+        self,
+        error: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Fallback<'a, Message, Theme, Renderer>
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        Fallback::new(self).error(error)
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -211,6 +243,7 @@ pub fn draw<Renderer, Handle>(
                 rotation: rotation.radians(),
                 opacity,
                 snap: true,
+                source: None,
             },
             drawing_bounds,
         );
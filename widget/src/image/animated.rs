@@ -0,0 +1,343 @@
+//! Play animated images, like GIF, APNG and animated WebP.
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Rectangle, Rotation, Shell,
+    Size, Widget,
+};
+use crate::renderer::graphics;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub use graphics::image::Frame;
+
+/// The decoded frames of an animated image.
+///
+/// Cloning [`Frames`] is cheap.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    frames: Arc<[Frame]>,
+}
+
+impl Frames {
+    /// Decodes all the frames of the animated image of the given [`Handle`].
+    ///
+    /// Images that are not animated produce a single frame.
+    ///
+    /// Decoding may take a while for long animations; consider doing it
+    /// in the background (e.g. with a [`Task`]).
+    ///
+    /// [`Handle`]: image::Handle
+    /// [`Task`]: crate::runtime::Task
+    pub fn load(
+        handle: impl Into<image::Handle>,
+    ) -> Result<Self, image::Error> {
+        let frames =
+            graphics::image::load_frames(&handle.into()).map_err(|error| {
+                match error {
+                    graphics::image::image_rs::ImageError::IoError(_) => {
+                        image::Error::NotFound
+                    }
+                    _ => image::Error::Invalid,
+                }
+            })?;
+
+        if frames.is_empty() {
+            return Err(image::Error::Invalid);
+        }
+
+        Ok(Self {
+            frames: Arc::from(frames),
+        })
+    }
+
+    /// Decodes all the frames of the animated image at the given path.
+    pub fn from_path(path: impl Into<PathBuf>) -> Result<Self, image::Error> {
+        Self::load(image::Handle::from_path(path))
+    }
+
+    /// Decodes all the frames of the animated image in the given bytes.
+    pub fn from_bytes(
+        bytes: impl Into<image::Bytes>,
+    ) -> Result<Self, image::Error> {
+        Self::load(image::Handle::from_bytes(bytes))
+    }
+
+    /// Returns the frames of the animated image.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the total duration of a single loop of the animation.
+    pub fn duration(&self) -> std::time::Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+
+/// A frame that plays an animated image while keeping aspect ratio.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::animated_image;
+/// use iced::widget::image::animated;
+///
+/// struct State {
+///    frames: animated::Frames,
+///    is_paused: bool,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     animated_image(&state.frames).paused(state.is_paused).into()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Animated<'a> {
+    frames: &'a Frames,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    rotation: Rotation,
+    opacity: f32,
+    is_paused: bool,
+    is_looping: bool,
+}
+
+impl<'a> Animated<'a> {
+    /// Creates a new [`Animated`] image with the given [`Frames`].
+    pub fn new(frames: &'a Frames) -> Self {
+        Self {
+            frames,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: image::FilterMethod::default(),
+            rotation: Rotation::default(),
+            opacity: 1.0,
+            is_paused: false,
+            is_looping: true,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`] image.
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Applies the given [`Rotation`] to the [`Animated`] image.
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Animated`] image.
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets whether the animation is paused.
+    ///
+    /// A paused animation keeps displaying its current frame.
+    pub fn paused(mut self, is_paused: bool) -> Self {
+        self.is_paused = is_paused;
+        self
+    }
+
+    /// Sets whether the animation starts over after its last frame.
+    ///
+    /// Animations loop by default.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+}
+
+struct State {
+    frames: Arc<[Frame]>,
+    current: usize,
+    next_frame: Option<Instant>,
+}
+
+impl State {
+    fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.frames.clone(),
+            current: 0,
+            next_frame: None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Animated<'a>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.frames))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !Arc::ptr_eq(&state.frames, &self.frames.frames) {
+            *state = State::new(self.frames);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        super::layout(
+            renderer,
+            limits,
+            &self.frames.frames[0].handle,
+            self.width,
+            self.height,
+            self.content_fit,
+            self.rotation,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let frames = &state.frames;
+
+        if self.is_paused || frames.len() < 2 {
+            state.next_frame = None;
+
+            return event::Status::Ignored;
+        }
+
+        let next_frame = *state
+            .next_frame
+            .get_or_insert_with(|| now + frames[state.current].delay);
+
+        if now >= next_frame {
+            let is_last = state.current + 1 == frames.len();
+
+            if is_last && !self.is_looping {
+                return event::Status::Ignored;
+            }
+
+            state.current = (state.current + 1) % frames.len();
+
+            let delay = frames[state.current].delay;
+            let next_frame = next_frame + delay;
+
+            // Skip ahead instead of catching up if we fell behind
+            state.next_frame = Some(if next_frame > now {
+                next_frame
+            } else {
+                now + delay
+            });
+        }
+
+        if let Some(next_frame) = state.next_frame {
+            shell.request_redraw(window::RedrawRequest::At(next_frame));
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        super::draw(
+            renderer,
+            layout,
+            &state.frames[state.current].handle,
+            self.content_fit,
+            self.filter_method,
+            self.rotation,
+            self.opacity,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<'a>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn from(animated: Animated<'a>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
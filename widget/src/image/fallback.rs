@@ -0,0 +1,282 @@
+use super::Image;
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Alignment, Clipboard, Element, Layout, Length, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// An [`Image`] that displays other elements while it is loading or
+/// when it fails to load.
+///
+/// It can be created with [`Image::placeholder`] and [`Image::error`].
+#[allow(missing_debug_implementations)]
+pub struct Fallback<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    image: Image<Renderer::Handle>,
+    placeholder: Option<Element<'a, Message, Theme, Renderer>>,
+    error: Option<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Fallback<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    pub(super) fn new(image: Image<Renderer::Handle>) -> Self {
+        Self {
+            image,
+            placeholder: None,
+            error: None,
+        }
+    }
+
+    /// Sets the element displayed while the [`Image`] is loading.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the element displayed when the [`Image`] fails to load.
+    pub fn error(
+        mut self,
+        error: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    fn active(
+        &self,
+        renderer: &Renderer,
+    ) -> Option<(usize, &Element<'a, Message, Theme, Renderer>)> {
+        match renderer.image_state(&self.image.handle) {
+            image::State::Loading => self.placeholder.as_ref().map(|p| (0, p)),
+            image::State::Failed(_) => self.error.as_ref().map(|e| (1, e)),
+            image::State::Loaded => None,
+        }
+    }
+
+    fn active_mut(
+        &mut self,
+        renderer: &Renderer,
+    ) -> Option<(usize, &mut Element<'a, Message, Theme, Renderer>)> {
+        match renderer.image_state(&self.image.handle) {
+            image::State::Loading => self.placeholder.as_mut().map(|p| (0, p)),
+            image::State::Failed(_) => self.error.as_mut().map(|e| (1, e)),
+            image::State::Loaded => None,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Fallback<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer,
+    Renderer::Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        [&self.placeholder, &self.error]
+            .into_iter()
+            .map(|element| element.as_ref().map_or_else(Tree::empty, Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children.resize_with(2, Tree::empty);
+
+        for (child, element) in tree
+            .children
+            .iter_mut()
+            .zip([&self.placeholder, &self.error])
+        {
+            match element {
+                Some(element) => child.diff(element),
+                None => *child = Tree::empty(),
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Message, Theme, Renderer>::size(&self.image)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = super::layout(
+            renderer,
+            limits,
+            &self.image.handle,
+            self.image.width,
+            self.image.height,
+            self.image.content_fit,
+            self.image.rotation,
+        )
+        .size();
+
+        let limits = layout::Limits::new(Size::ZERO, size);
+
+        let children = tree
+            .children
+            .iter_mut()
+            .zip([&self.placeholder, &self.error])
+            .map(|(tree, element)| match element {
+                Some(element) => element
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .align(Alignment::Center, Alignment::Center, size),
+                None => layout::Node::default(),
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some((index, element)) = self.active(renderer) {
+            element.as_widget().operate(
+                &mut tree.children[index],
+                layout.children().nth(index).unwrap(),
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Some((index, element)) = self.active_mut(renderer) {
+            element.as_widget_mut().on_event(
+                &mut tree.children[index],
+                event,
+                layout.children().nth(index).unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.active(renderer)
+            .map(|(index, element)| {
+                element.as_widget().mouse_interaction(
+                    &tree.children[index],
+                    layout.children().nth(index).unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some((index, element)) = self.active(renderer) {
+            element.as_widget().draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                style,
+                layout.children().nth(index).unwrap(),
+                cursor,
+                viewport,
+            );
+        } else {
+            super::draw(
+                renderer,
+                layout,
+                &self.image.handle,
+                self.image.content_fit,
+                self.image.filter_method,
+                self.image.rotation,
+                self.image.opacity,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (index, element) = self.active_mut(renderer)?;
+
+        element.as_widget_mut().overlay(
+            &mut tree.children[index],
+            layout.children().nth(index).unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Fallback<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: image::Renderer + 'a,
+    Renderer::Handle: Clone + 'a,
+{
+    fn from(
+        fallback: Fallback<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(fallback)
+    }
+}
@@ -25,8 +25,10 @@ pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
 pub mod rule;
 pub mod scrollable;
+pub mod shortcuts;
 pub mod slider;
 pub mod text;
 pub mod text_editor;
@@ -66,12 +68,16 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use shortcuts::Shortcuts;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
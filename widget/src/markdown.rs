@@ -232,6 +232,32 @@ impl Span {
 /// }
 /// ```
 pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
+    parse_with(
+        markdown,
+        #[cfg(feature = "highlighter")]
+        iced_highlighter::Theme::Base16Ocean,
+    )
+}
+
+/// Parse the given Markdown content, highlighting code blocks with the given
+/// [`Theme`](iced_highlighter::Theme).
+///
+/// The syntaxes and themes of the [`registry`](iced_highlighter::registry)
+/// are available to code blocks. You can use
+/// [`Theme::from_palette`](iced_highlighter::Theme::from_palette) to
+/// highlight code blocks with the colors of your application.
+#[cfg(feature = "highlighter")]
+pub fn parse_with_theme(
+    markdown: &str,
+    theme: iced_highlighter::Theme,
+) -> impl Iterator<Item = Item> + '_ {
+    parse_with(markdown, theme)
+}
+
+fn parse_with(
+    markdown: &str,
+    #[cfg(feature = "highlighter")] theme: iced_highlighter::Theme,
+) -> impl Iterator<Item = Item> + '_ {
     struct List {
         start: Option<u64>,
         items: Vec<Vec<Item>>,
@@ -330,7 +356,7 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 
                     highlighter =
                         Some(Highlighter::new(&iced_highlighter::Settings {
-                            theme: iced_highlighter::Theme::Base16Ocean,
+                            theme: theme.clone(),
                             token: _language.to_string(),
                         }));
                 }
@@ -509,6 +535,8 @@ pub struct Settings {
     pub h6_size: Pixels,
     /// The text size used in code blocks.
     pub code_size: Pixels,
+    /// Whether the rendered text can be selected and copied.
+    pub selectable: bool,
 }
 
 impl Settings {
@@ -529,6 +557,7 @@ impl Settings {
             h5_size: text_size,
             h6_size: text_size,
             code_size: text_size * 0.75,
+            selectable: false,
         }
     }
 }
@@ -631,30 +660,34 @@ where
         h5_size,
         h6_size,
         code_size,
+        selectable,
     } = settings;
 
     let spacing = text_size * 0.625;
 
     let blocks = items.into_iter().enumerate().map(|(i, item)| match item {
-        Item::Heading(level, heading) => {
-            container(rich_text(heading.spans(style)).size(match level {
-                pulldown_cmark::HeadingLevel::H1 => h1_size,
-                pulldown_cmark::HeadingLevel::H2 => h2_size,
-                pulldown_cmark::HeadingLevel::H3 => h3_size,
-                pulldown_cmark::HeadingLevel::H4 => h4_size,
-                pulldown_cmark::HeadingLevel::H5 => h5_size,
-                pulldown_cmark::HeadingLevel::H6 => h6_size,
-            }))
-            .padding(padding::top(if i > 0 {
-                text_size / 2.0
-            } else {
-                Pixels::ZERO
-            }))
-            .into()
-        }
-        Item::Paragraph(paragraph) => {
-            rich_text(paragraph.spans(style)).size(text_size).into()
-        }
+        Item::Heading(level, heading) => container(
+            rich_text(heading.spans(style))
+                .size(match level {
+                    pulldown_cmark::HeadingLevel::H1 => h1_size,
+                    pulldown_cmark::HeadingLevel::H2 => h2_size,
+                    pulldown_cmark::HeadingLevel::H3 => h3_size,
+                    pulldown_cmark::HeadingLevel::H4 => h4_size,
+                    pulldown_cmark::HeadingLevel::H5 => h5_size,
+                    pulldown_cmark::HeadingLevel::H6 => h6_size,
+                })
+                .selectable(selectable),
+        )
+        .padding(padding::top(if i > 0 {
+            text_size / 2.0
+        } else {
+            Pixels::ZERO
+        }))
+        .into(),
+        Item::Paragraph(paragraph) => rich_text(paragraph.spans(style))
+            .size(text_size)
+            .selectable(selectable)
+            .into(),
         Item::List { start: None, items } => {
             column(items.iter().map(|items| {
                 row![text("•").size(text_size), view(items, settings, style)]
@@ -682,7 +715,8 @@ where
                 container(
                     rich_text(code.spans(style))
                         .font(Font::MONOSPACE)
-                        .size(code_size),
+                        .size(code_size)
+                        .selectable(selectable),
                 )
                 .padding(spacing.0 / 2.0),
             )
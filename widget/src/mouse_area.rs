@@ -259,13 +259,13 @@ where
             renderer,
         );
 
-        match (self.interaction, content_interaction) {
+        match (&self.interaction, content_interaction) {
             (Some(interaction), mouse::Interaction::None)
                 if cursor.is_over(layout.bounds()) =>
             {
-                interaction
+                interaction.clone()
             }
-            _ => content_interaction,
+            (_, content_interaction) => content_interaction,
         }
     }
 
@@ -533,6 +533,8 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
@@ -0,0 +1,328 @@
+//! Show a list of spelling suggestions for a misspelled word.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Background, Clipboard, Color, Padding, Pixels, Point, Rectangle, Shell,
+    Size, Vector,
+};
+
+/// A list of spelling suggestions shown next to a misspelled word.
+#[allow(missing_debug_implementations)]
+pub struct Suggestions<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    options: &'a [String],
+    hovered_option: &'a mut Option<usize>,
+    on_selected: Box<dyn FnMut(&str) -> Message + 'a>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    style: Box<dyn Fn(&Theme) -> Style + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Suggestions<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    /// The default [`Padding`] of each suggestion.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        bottom: 2.0,
+        left: 8.0,
+        right: 8.0,
+    };
+
+    /// Creates some new [`Suggestions`] with the given options, the hovered
+    /// option, the message to produce when an option is selected, and a
+    /// function producing their [`Style`].
+    pub fn new(
+        options: &'a [String],
+        hovered_option: &'a mut Option<usize>,
+        on_selected: impl FnMut(&str) -> Message + 'a,
+        style: impl Fn(&Theme) -> Style + 'a,
+    ) -> Self {
+        Self {
+            options,
+            hovered_option,
+            on_selected: Box::new(on_selected),
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            style: Box::new(style),
+        }
+    }
+
+    /// Sets the [`Padding`] of each option of the [`Suggestions`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Suggestions`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Suggestions`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`Suggestions`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Turns the [`Suggestions`] into an overlay [`Element`] at the given
+    /// target position.
+    ///
+    /// The list is displayed under the target if there is space for it, or
+    /// on top of it otherwise.
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(Overlay {
+            suggestions: self,
+            position,
+            target_height,
+        }))
+    }
+
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+}
+
+/// The appearance of some [`Suggestions`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the list.
+    pub background: Background,
+    /// The [`Border`] of the list.
+    pub border: Border,
+    /// The text [`Color`] of the options.
+    pub text_color: Color,
+    /// The [`Background`] of the hovered option.
+    pub hovered_background: Background,
+}
+
+struct Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    suggestions: Suggestions<'a, Message, Theme, Renderer>,
+    position: Point,
+    target_height: f32,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let suggestions = &self.suggestions;
+
+        let text_size = suggestions
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = suggestions.font.unwrap_or_else(|| renderer.default_font());
+
+        let width = suggestions
+            .options
+            .iter()
+            .map(|option| {
+                Renderer::Paragraph::with_text(Text {
+                    content: option.as_str(),
+                    bounds: Size::INFINITY,
+                    size: text_size,
+                    line_height: suggestions.text_line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
+                })
+                .min_width()
+            })
+            .fold(0.0, f32::max)
+            + suggestions.padding.horizontal();
+
+        let size = Size::new(
+            width.min(bounds.width),
+            suggestions.option_height(renderer)
+                * suggestions.options.len() as f32,
+        );
+
+        let space_below =
+            bounds.height - (self.position.y + self.target_height);
+
+        let position = if space_below >= size.height {
+            self.position + Vector::new(0.0, self.target_height)
+        } else {
+            self.position - Vector::new(0.0, size.height)
+        };
+
+        layout::Node::new(size).move_to(Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            position.y.max(0.0),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let option_height = self.suggestions.option_height(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                *self.suggestions.hovered_option = cursor
+                    .position_in(bounds)
+                    .map(|position| (position.y / option_height) as usize);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let index = (position.y / option_height) as usize;
+
+                if let Some(option) = self.suggestions.options.get(index) {
+                    shell.publish((self.suggestions.on_selected)(option));
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let suggestions = &self.suggestions;
+        let style = (suggestions.style)(theme);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_size = suggestions
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let option_height = suggestions.option_height(renderer);
+
+        for (i, option) in suggestions.options.iter().enumerate() {
+            let bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + option_height * i as f32,
+                width: bounds.width,
+                height: option_height,
+            };
+
+            if *suggestions.hovered_option == Some(i) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + style.border.width,
+                            width: bounds.width - style.border.width * 2.0,
+                            ..bounds
+                        },
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: option.clone(),
+                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    size: text_size,
+                    line_height: suggestions.text_line_height,
+                    font: suggestions
+                        .font
+                        .unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
+                },
+                Point::new(
+                    bounds.x + suggestions.padding.left,
+                    bounds.center_y(),
+                ),
+                style.text_color,
+                bounds,
+            );
+        }
+    }
+}
@@ -4,6 +4,11 @@ use crate::pane_grid::Axis;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -7,6 +7,11 @@ use std::collections::BTreeMap;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Node {
     /// The region of this [`Node`] is split into two.
     Split {
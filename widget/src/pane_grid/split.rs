@@ -2,4 +2,9 @@
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Split(pub(super) usize);
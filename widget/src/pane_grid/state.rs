@@ -21,7 +21,15 @@ use std::collections::BTreeMap;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 /// [`PaneGrid::new`]: super::PaneGrid::new
+///
+/// When deserialized, the panes are checked against the layout; a
+/// [`State`] with missing, unknown, or duplicated panes is rejected.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde", try_from = "Unchecked<T>")
+)]
 pub struct State<T> {
     /// The panes of the [`PaneGrid`].
     ///
@@ -94,6 +102,37 @@ impl<T> State<T> {
         &self.internal.layout
     }
 
+    /// Returns the current [`Configuration`] of the [`State`].
+    ///
+    /// A [`State`] created with this [`Configuration`] will have the same
+    /// layout—including the ratio of its splits.
+    pub fn configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        fn build<T: Clone>(
+            node: &Node,
+            panes: &BTreeMap<Pane, T>,
+        ) -> Option<Configuration<T>> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Some(Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(build(a, panes)?),
+                    b: Box::new(build(b, panes)?),
+                }),
+                Node::Pane(pane) => {
+                    panes.get(pane).cloned().map(Configuration::Pane)
+                }
+            }
+        }
+
+        build(&self.internal.layout, &self.panes)
+            .expect("Every pane of the layout must have a state")
+    }
+
     /// Returns the adjacent [`Pane`] of another [`Pane`] in the given
     /// direction, if there is one.
     pub fn adjacent(&self, pane: Pane, direction: Direction) -> Option<Pane> {
@@ -351,6 +390,11 @@ impl<T> State<T> {
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde", try_from = "UncheckedInternal")
+)]
 pub struct Internal {
     layout: Node,
     last_id: usize,
@@ -419,6 +463,93 @@ impl Internal {
     }
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Unchecked<T> {
+    panes: BTreeMap<Pane, T>,
+    internal: Internal,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<Unchecked<T>> for State<T> {
+    type Error = &'static str;
+
+    fn try_from(state: Unchecked<T>) -> Result<Self, Self::Error> {
+        let Unchecked { panes, internal } = state;
+
+        let layout = internal.layout.pane_regions(0.0, Size::ZERO);
+
+        if !layout.keys().eq(panes.keys()) {
+            return Err("every pane of the layout must have a state");
+        }
+
+        Ok(Self { panes, internal })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct UncheckedInternal {
+    layout: Node,
+    last_id: usize,
+    maximized: Option<Pane>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedInternal> for Internal {
+    type Error = &'static str;
+
+    fn try_from(internal: UncheckedInternal) -> Result<Self, Self::Error> {
+        use std::collections::BTreeSet;
+
+        let UncheckedInternal {
+            layout,
+            last_id,
+            maximized,
+        } = internal;
+
+        let mut panes = BTreeSet::new();
+        let mut splits = BTreeSet::new();
+        let mut unvisited = vec![&layout];
+
+        while let Some(node) = unvisited.pop() {
+            match node {
+                Node::Split {
+                    id, ratio, a, b, ..
+                } => {
+                    if id.0 > last_id || !splits.insert(*id) {
+                        return Err("splits must have unique identifiers");
+                    }
+
+                    if !(0.0..=1.0).contains(ratio) {
+                        return Err("split ratios must be in [0.0, 1.0]");
+                    }
+
+                    unvisited.push(a);
+                    unvisited.push(b);
+                }
+                Node::Pane(pane) => {
+                    if pane.0 > last_id || !panes.insert(*pane) {
+                        return Err("panes must have unique identifiers");
+                    }
+                }
+            }
+        }
+
+        if maximized.is_some_and(|pane| !panes.contains(&pane)) {
+            return Err("the maximized pane must be part of the layout");
+        }
+
+        Ok(Self {
+            layout,
+            last_id,
+            maximized,
+        })
+    }
+}
+
 /// The current action of a [`PaneGrid`].
 ///
 /// [`PaneGrid`]: super::PaneGrid
@@ -466,3 +597,63 @@ impl Action {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    fn deserialize(json: &str) -> Result<State<u8>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn serialized_states_are_restored() {
+        let (mut state, first) = State::new(1);
+        let (second, _) = state.split(Axis::Vertical, first, 2).unwrap();
+        state.maximize(second);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored = deserialize(&json).unwrap();
+
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.maximized(), Some(second));
+        assert!(matches!(
+            restored.configuration(),
+            Configuration::Split { .. }
+        ));
+    }
+
+    #[test]
+    fn inconsistent_states_are_rejected() {
+        let layout = r#"{"Split":{"id":2,"axis":"Vertical","ratio":0.5,"a":{"Pane":0},"b":{"Pane":1}}}"#;
+
+        let state = |panes: &str, layout: &str, maximized: &str| {
+            deserialize(&format!(
+                r#"{{"panes":{panes},"internal":{{"layout":{layout},"last_id":3,"maximized":{maximized}}}}}"#
+            ))
+        };
+
+        assert!(state(r#"{"0":1,"1":2}"#, layout, "null").is_ok());
+        assert!(state(r#"{"0":1}"#, layout, "null").is_err());
+        assert!(state(r#"{"0":1,"1":2,"2":3}"#, layout, "null").is_err());
+        assert!(state(r#"{"0":1,"1":2}"#, layout, "5").is_err());
+        assert!(state(
+            r#"{"0":1}"#,
+            r#"{"Split":{"id":2,"axis":"Vertical","ratio":0.5,"a":{"Pane":0},"b":{"Pane":0}}}"#,
+            "null"
+        )
+        .is_err());
+        assert!(state(
+            r#"{"0":1,"1":2}"#,
+            r#"{"Split":{"id":2,"axis":"Vertical","ratio":1.5,"a":{"Pane":0},"b":{"Pane":1}}}"#,
+            "null"
+        )
+        .is_err());
+        assert!(state(
+            r#"{"0":1,"1":2}"#,
+            r#"{"Split":{"id":9,"axis":"Vertical","ratio":0.5,"a":{"Pane":0},"b":{"Pane":1}}}"#,
+            "null"
+        )
+        .is_err());
+    }
+}
@@ -373,6 +373,8 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
             wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -639,6 +641,8 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
                 },
                 Point::new(
                     bounds.x + bounds.width - self.padding.right,
@@ -669,6 +673,8 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
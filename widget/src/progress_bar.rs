@@ -19,14 +19,23 @@
 //!     progress_bar(0.0..=100.0, state.progress).into()
 //! }
 //! ```
+#[cfg(feature = "canvas")]
+pub mod circular;
+
+#[cfg(feature = "canvas")]
+pub use circular::Circular;
+
 use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme,
-    Widget,
+    self, Background, Clipboard, Color, Element, Layout, Length, Rectangle,
+    Shell, Size, Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -59,8 +68,10 @@ where
 {
     range: RangeInclusive<f32>,
     value: f32,
-    width: Length,
+    width: Option<Length>,
     height: Option<Length>,
+    is_vertical: bool,
+    is_indeterminate: bool,
     class: Theme::Class<'a>,
 }
 
@@ -80,15 +91,17 @@ where
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
-            width: Length::Fill,
+            width: None,
             height: None,
+            is_vertical: false,
+            is_indeterminate: false,
             class: Theme::default(),
         }
     }
 
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.width = Some(width.into());
         self
     }
 
@@ -98,6 +111,24 @@ where
         self
     }
 
+    /// Makes the [`ProgressBar`] vertical; filling it from bottom to top.
+    ///
+    /// A vertical [`ProgressBar`] fills the available height and
+    /// has a fixed width of [`Self::DEFAULT_HEIGHT`] by default.
+    pub fn vertical(mut self) -> Self {
+        self.is_vertical = true;
+        self
+    }
+
+    /// Sets whether the [`ProgressBar`] is indeterminate.
+    ///
+    /// An indeterminate [`ProgressBar`] ignores its value and displays
+    /// an animation instead; for operations of unknown length.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -123,10 +154,28 @@ where
     Theme: Catalog,
     Renderer: core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
+        let (length, girth) =
+            (Length::Fill, Length::Fixed(Self::DEFAULT_HEIGHT));
+
+        if self.is_vertical {
+            Size {
+                width: self.width.unwrap_or(girth),
+                height: self.height.unwrap_or(length),
+            }
+        } else {
+            Size {
+                width: self.width.unwrap_or(length),
+                height: self.height.unwrap_or(girth),
+            }
         }
     }
 
@@ -136,16 +185,34 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(
-            limits,
-            self.width,
-            self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
-        )
+        let size = Widget::<Message, Theme, Renderer>::size(self);
+
+        layout::atomic(limits, size.width, size.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state.downcast_mut::<State>().update(
+            &event,
+            self.is_indeterminate,
+            shell,
+        );
+
+        event::Status::Ignored
     }
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -153,14 +220,37 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        // The length of the segment that sweeps across an indeterminate bar
+        const SEGMENT: f32 = 0.4;
+
         let bounds = layout.bounds();
         let (range_start, range_end) = self.range.clone().into_inner();
 
-        let active_progress_width = if range_start >= range_end {
-            0.0
+        let (from, to) = if self.is_indeterminate {
+            let state = tree.state.downcast_ref::<State>();
+
+            let progress = state.cycle(Duration::from_millis(1500));
+            let head = ease(progress) * (1.0 + SEGMENT);
+
+            ((head - SEGMENT).max(0.0), head.min(1.0))
+        } else if range_start >= range_end {
+            (0.0, 0.0)
+        } else {
+            (0.0, (self.value - range_start) / (range_end - range_start))
+        };
+
+        let active_bounds = if self.is_vertical {
+            Rectangle {
+                y: bounds.y + bounds.height * (1.0 - to),
+                height: bounds.height * (to - from),
+                ..bounds
+            }
         } else {
-            bounds.width * (self.value - range_start)
-                / (range_end - range_start)
+            Rectangle {
+                x: bounds.x + bounds.width * from,
+                width: bounds.width * (to - from),
+                ..bounds
+            }
         };
 
         let style = theme.style(&self.class);
@@ -174,13 +264,10 @@ where
             style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if active_bounds.width > 0.0 && active_bounds.height > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
+                    bounds: active_bounds,
                     border: Border {
                         color: Color::TRANSPARENT,
                         ..style.border
@@ -207,6 +294,53 @@ where
     }
 }
 
+/// The animation state of an indeterminate progress indicator.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    started_at: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Advances the animation on redraws, requesting the next frame while
+    /// it is animating.
+    fn update<Message>(
+        &mut self,
+        event: &Event,
+        is_animating: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if !is_animating {
+            *self = Self::default();
+            return;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let _ = self.started_at.get_or_insert(*now);
+            self.now = Some(*now);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    /// Returns the progress of the current cycle of the animation, given
+    /// the duration of a cycle, in the `[0.0, 1.0)` range.
+    fn cycle(&self, duration: Duration) -> f32 {
+        let (Some(started_at), Some(now)) = (self.started_at, self.now) else {
+            return 0.0;
+        };
+
+        let elapsed = now.saturating_duration_since(started_at);
+
+        (elapsed.as_secs_f32() / duration.as_secs_f32()).fract()
+    }
+}
+
+/// Eases the given progress in and out.
+fn ease(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
 /// The appearance of a progress bar.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
@@ -0,0 +1,281 @@
+//! Circular progress indicators display progress along a ring.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Pixels, Radians,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::graphics::geometry::{self, Frame, LineCap, Path, Stroke};
+use crate::progress_bar::{ease, Catalog, State, Style, StyleFn};
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+/// A ring that displays progress.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::circular_progress;
+///
+/// struct State {
+///    progress: Option<f32>,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     circular_progress(0.0..=100.0, state.progress.unwrap_or_default())
+///         .indeterminate(state.progress.is_none())
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Circular<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    size: f32,
+    bar_width: f32,
+    is_indeterminate: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Circular<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Circular`] progress indicator.
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default width of the bar of a [`Circular`] progress indicator.
+    pub const DEFAULT_BAR_WIDTH: f32 = 4.0;
+
+    /// Creates a new [`Circular`] progress indicator.
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Circular`] progress indicator
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Circular {
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            size: Self::DEFAULT_SIZE,
+            bar_width: Self::DEFAULT_BAR_WIDTH,
+            is_indeterminate: false,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`Circular`] progress indicator.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the bar of the [`Circular`] progress indicator.
+    pub fn bar_width(mut self, bar_width: impl Into<Pixels>) -> Self {
+        self.bar_width = bar_width.into().0;
+        self
+    }
+
+    /// Sets whether the [`Circular`] progress indicator is indeterminate.
+    ///
+    /// An indeterminate [`Circular`] progress indicator ignores its value
+    /// and spins instead.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets the style of the [`Circular`] progress indicator.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Circular`] progress indicator.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Circular<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state.downcast_mut::<State>().update(
+            &event,
+            self.is_indeterminate,
+            shell,
+        );
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        // The shortest and longest arcs of an indeterminate indicator
+        const MIN_ARC: f32 = PI / 8.0;
+        const MAX_ARC: f32 = 3.0 * PI / 2.0;
+
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let (start, sweep) = if self.is_indeterminate {
+            let state = tree.state.downcast_ref::<State>();
+
+            let rotation = state.cycle(Duration::from_millis(2000)) * 2.0 * PI;
+            let breath = state.cycle(Duration::from_millis(1600)) * 2.0;
+
+            // The arc grows and shrinks while rotating
+            let arc = MIN_ARC
+                + (MAX_ARC - MIN_ARC)
+                    * ease(if breath < 1.0 { breath } else { 2.0 - breath });
+
+            (rotation, arc)
+        } else {
+            let (range_start, range_end) = self.range.clone().into_inner();
+
+            let progress = if range_start >= range_end {
+                0.0
+            } else {
+                (self.value - range_start) / (range_end - range_start)
+            };
+
+            (-PI / 2.0, progress * 2.0 * PI)
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = frame.center();
+        let radius = (bounds.width.min(bounds.height) - self.bar_width) / 2.0;
+
+        frame.stroke(
+            &Path::circle(center, radius),
+            Stroke::default()
+                .with_color(color(style.background))
+                .with_width(self.bar_width),
+        );
+
+        if sweep > 0.0 {
+            let bar = Path::new(|builder| {
+                builder.arc(geometry::path::Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start),
+                    end_angle: Radians(start + sweep),
+                });
+            });
+
+            frame.stroke(
+                &bar,
+                Stroke::default()
+                    .with_color(color(style.bar))
+                    .with_width(self.bar_width)
+                    .with_line_cap(LineCap::Round),
+            );
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw_geometry(geometry);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Circular<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + geometry::Renderer,
+{
+    fn from(
+        circular: Circular<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(circular)
+    }
+}
+
+/// Returns the solid [`Color`] of a [`Background`]; using the first
+/// color stop of gradients.
+fn color(background: Background) -> Color {
+    match background {
+        Background::Color(color) => color,
+        Background::Gradient(gradient) => match gradient {
+            crate::core::Gradient::Linear(linear) => linear
+                .stops
+                .iter()
+                .flatten()
+                .map(|stop| stop.color)
+                .next()
+                .unwrap_or(Color::TRANSPARENT),
+        },
+    }
+}
@@ -316,6 +316,8 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsis::None,
+                    None,
                 )
             },
         )
@@ -434,6 +436,7 @@ where
                 state.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -0,0 +1,721 @@
+//! Range sliders let users select a range of values by moving two handles.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::range_slider;
+//! use std::ops::RangeInclusive;
+//!
+//! struct State {
+//!    range: RangeInclusive<f32>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     RangeChanged(RangeInclusive<f32>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     range_slider(0.0..=100.0, state.range.clone(), Message::RangeChanged)
+//!         .step(5.0)
+//!         .ticks([0.0, 25.0, 50.0, 75.0, 100.0])
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::RangeChanged(range) => {
+//!             state.range = range;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::Border;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Text, Widget,
+};
+use crate::slider::{Catalog, HandleShape, Status, Style, StyleFn};
+
+use std::ops::RangeInclusive;
+
+/// The length of the tick marks of a [`RangeSlider`].
+const TICK_LENGTH: f32 = 4.0;
+
+/// The spacing between the rail, the tick marks, and their labels.
+const TICK_SPACING: f32 = 2.0;
+
+/// An horizontal bar with two handles that select a range of values.
+///
+/// The handles cannot cross each other, and the segment of the rail
+/// between them is filled.
+///
+/// A [`RangeSlider`] is styled like a [`Slider`], using the same
+/// [`Catalog`].
+///
+/// [`Slider`]: crate::Slider
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::range_slider;
+/// use std::ops::RangeInclusive;
+///
+/// struct State {
+///    range: RangeInclusive<u32>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged(RangeInclusive<u32>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     range_slider(0..=10, state.range.clone(), Message::RangeChanged)
+///         .ticks(0..=10)
+///         .tick_labels(|value| value.to_string())
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    values: (T, T),
+    on_change: Box<dyn Fn(RangeInclusive<T>) -> Message + 'a>,
+    on_release: Option<Message>,
+    ticks: Vec<T>,
+    tick_label: Option<Box<dyn Fn(T) -> String + 'a>>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// The default height of the rail of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the currently selected range of the [`RangeSlider`]
+    ///   * a function that will be called when any handle of the
+    ///     [`RangeSlider`] is dragged. It receives the new selected range
+    ///     and must produce a `Message`.
+    pub fn new<F>(
+        range: RangeInclusive<T>,
+        values: RangeInclusive<T>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(RangeInclusive<T>) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = values.into_inner();
+        let (start, end) = (clamp(start), clamp(end));
+
+        RangeSlider {
+            values: if start <= end {
+                (start, end)
+            } else {
+                (end, start)
+            },
+            range,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            ticks: Vec::new(),
+            tick_label: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rail of the [`RangeSlider`].
+    ///
+    /// Tick marks and their labels are placed below it.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`RangeSlider`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the values where tick marks are drawn below the rail of the
+    /// [`RangeSlider`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks = ticks
+            .into_iter()
+            .filter(|tick| self.range.contains(tick))
+            .collect();
+        self
+    }
+
+    /// Sets the function used to produce the labels of the tick marks of
+    /// the [`RangeSlider`].
+    pub fn tick_labels(mut self, label: impl Fn(T) -> String + 'a) -> Self {
+        self.tick_label = Some(Box::new(label));
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RangeSlider`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+{
+    fn current_step(&self, modifiers: keyboard::Modifiers) -> f64 {
+        if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    fn locate(&self, bounds: Rectangle, cursor: Point, step: f64) -> Option<T> {
+        if cursor.x <= bounds.x {
+            Some(*self.range.start())
+        } else if cursor.x >= bounds.x + bounds.width {
+            Some(*self.range.end())
+        } else {
+            let start = (*self.range.start()).into();
+            let end = (*self.range.end()).into();
+
+            let percent =
+                f64::from(cursor.x - bounds.x) / f64::from(bounds.width);
+
+            let steps = (percent * (end - start) / step).round();
+            let value = steps * step + start;
+
+            T::from_f64(value.min(end))
+        }
+    }
+
+    fn increment(&self, value: T, step: f64) -> Option<T> {
+        let steps = (value.into() / step).round();
+        let new_value = step * (steps + 1.0);
+
+        if new_value > (*self.range.end()).into() {
+            return Some(*self.range.end());
+        }
+
+        T::from_f64(new_value)
+    }
+
+    fn decrement(&self, value: T, step: f64) -> Option<T> {
+        let steps = (value.into() / step).round();
+        let new_value = step * (steps - 1.0);
+
+        if new_value < (*self.range.start()).into() {
+            return Some(*self.range.start());
+        }
+
+        T::from_f64(new_value)
+    }
+
+    fn value(&self, thumb: Thumb) -> T {
+        match thumb {
+            Thumb::Start => self.values.0,
+            Thumb::End => self.values.1,
+        }
+    }
+
+    /// Returns the [`Thumb`] closest to the given value.
+    fn closest(&self, value: T) -> Thumb {
+        let value = value.into();
+        let start = self.values.0.into();
+        let end = self.values.1.into();
+
+        let to_start = (value - start).abs();
+        let to_end = (value - end).abs();
+
+        if to_end < to_start || (to_end == to_start && value > end) {
+            Thumb::End
+        } else {
+            Thumb::Start
+        }
+    }
+
+    /// Returns the [`Thumb`] that should move to the given value while
+    /// dragging the given one.
+    ///
+    /// Overlapping handles are separated in the direction of the drag.
+    fn resolve(&self, thumb: Thumb, value: T) -> Thumb {
+        let value = value.into();
+        let start = self.values.0.into();
+        let end = self.values.1.into();
+
+        if start < end {
+            thumb
+        } else if value > end {
+            Thumb::End
+        } else if value < start {
+            Thumb::Start
+        } else {
+            thumb
+        }
+    }
+
+    fn change(
+        &mut self,
+        thumb: Thumb,
+        value: T,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (start, end) = self.values;
+
+        let values = match thumb {
+            Thumb::Start if value.into() > end.into() => (end, end),
+            Thumb::Start => (value, end),
+            Thumb::End if value.into() < start.into() => (start, start),
+            Thumb::End => (start, value),
+        };
+
+        if (values.0.into() - start.into()).abs() > f64::EPSILON
+            || (values.1.into() - end.into()).abs() > f64::EPSILON
+        {
+            shell.publish((self.on_change)(values.0..=values.1));
+
+            self.values = values;
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut height = self.height;
+
+        if !self.ticks.is_empty() {
+            height += TICK_SPACING + TICK_LENGTH;
+
+            if self.tick_label.is_some() {
+                height += TICK_SPACING
+                    + f32::from(
+                        text::LineHeight::default()
+                            .to_absolute(renderer.default_size()),
+                    );
+            }
+        }
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let step = self.current_step(state.keyboard_modifiers);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    if let Some(value) =
+                        self.locate(bounds, cursor_position, step)
+                    {
+                        let thumb = self.closest(value);
+
+                        state.dragging = Some(thumb);
+                        state.focused = Some(thumb);
+
+                        self.change(thumb, value, shell);
+                    }
+
+                    return event::Status::Captured;
+                } else {
+                    state.focused = None;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    state.dragging = None;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = state.dragging {
+                    if let Some(value) =
+                        cursor.position().and_then(|position| {
+                            self.locate(bounds, position, step)
+                        })
+                    {
+                        let thumb = self.resolve(thumb, value);
+
+                        state.dragging = Some(thumb);
+                        state.focused = Some(thumb);
+
+                        self.change(thumb, value, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if let Some(thumb) = state.focused {
+                    let value = self.value(thumb);
+
+                    let new_value = match key {
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => self.increment(value, step),
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => self.decrement(value, step),
+                        _ => return event::Status::Ignored,
+                    };
+
+                    if let Some(new_value) = new_value {
+                        self.change(thumb, new_value, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        let rail_style = theme.style(
+            &self.class,
+            if state.dragging.is_some() {
+                Status::Dragged
+            } else if is_mouse_over {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (handle_width, handle_height, handle_border_radius) =
+            match rail_style.handle.shape {
+                HandleShape::Circle { radius } => {
+                    (radius * 2.0, radius * 2.0, radius.into())
+                }
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                } => (f32::from(width), self.height, border_radius),
+            };
+
+        let (range_start, range_end) = {
+            let (start, end) = self.range.clone().into_inner();
+
+            (start.into() as f32, end.into() as f32)
+        };
+
+        let center = |value: T| {
+            let offset = if range_start >= range_end {
+                0.0
+            } else {
+                (bounds.width - handle_width)
+                    * (value.into() as f32 - range_start)
+                    / (range_end - range_start)
+            };
+
+            bounds.x + offset + handle_width / 2.0
+        };
+
+        let rail_y = bounds.y + self.height / 2.0;
+
+        let mut draw_rail = |from: f32, to: f32, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: from,
+                        y: rail_y - rail_style.rail.width / 2.0,
+                        width: to - from,
+                        height: rail_style.rail.width,
+                    },
+                    border: rail_style.rail.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        let start = center(self.values.0);
+        let end = center(self.values.1);
+
+        draw_rail(bounds.x, start, rail_style.rail.backgrounds.1);
+        draw_rail(start, end, rail_style.rail.backgrounds.0);
+        draw_rail(end, bounds.x + bounds.width, rail_style.rail.backgrounds.1);
+
+        let tick_y = bounds.y + self.height + TICK_SPACING;
+        let label_font = renderer.default_font();
+        let label_size = renderer.default_size();
+        let label_line_height = text::LineHeight::default();
+
+        for &tick in &self.ticks {
+            let x = center(tick);
+            let is_selected = x >= start && x <= end;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - 0.5,
+                        y: tick_y,
+                        width: 1.0,
+                        height: TICK_LENGTH,
+                    },
+                    ..renderer::Quad::default()
+                },
+                if is_selected {
+                    rail_style.rail.backgrounds.0
+                } else {
+                    rail_style.rail.backgrounds.1
+                },
+            );
+
+            if let Some(label) = &self.tick_label {
+                renderer.fill_text(
+                    Text {
+                        content: label(tick),
+                        size: label_size,
+                        line_height: label_line_height,
+                        font: label_font,
+                        bounds: Size::new(
+                            f32::INFINITY,
+                            f32::from(
+                                label_line_height.to_absolute(label_size),
+                            ),
+                        ),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::default(),
+                        wrapping: text::Wrapping::None,
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                    },
+                    Point::new(x, tick_y + TICK_LENGTH + TICK_SPACING),
+                    style.text_color,
+                    *viewport,
+                );
+            }
+        }
+
+        for (thumb, x) in [(Thumb::Start, start), (Thumb::End, end)] {
+            let handle_bounds = Rectangle {
+                x: x - handle_width / 2.0,
+                y: rail_y - handle_height / 2.0,
+                width: handle_width,
+                height: handle_height,
+            };
+
+            let status = if state.dragging == Some(thumb) {
+                Status::Dragged
+            } else if state.focused == Some(thumb)
+                || cursor.is_over(handle_bounds)
+            {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let handle = theme.style(&self.class, status).handle;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: handle_bounds,
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: handle.border_width,
+                        color: handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(range_slider)
+    }
+}
+
+/// One of the two handles of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    focused: Option<Thumb>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
@@ -1,8 +1,10 @@
 //! A custom shader widget for wgpu applications.
 mod event;
+mod offscreen;
 mod program;
 
 pub use event::Event;
+pub use offscreen::Offscreen;
 pub use program::Program;
 
 use crate::core;
@@ -18,7 +20,7 @@ use crate::renderer::wgpu::primitive;
 use std::marker::PhantomData;
 
 pub use crate::graphics::Viewport;
-pub use primitive::{Primitive, Storage};
+pub use primitive::{Primitive, Storage, Texture};
 
 /// A widget which can render custom shaders with Iced's `wgpu` backend.
 ///
@@ -0,0 +1,183 @@
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::renderer::wgpu::primitive::{self, Primitive};
+
+/// A widget that renders its contents offscreen and then draws them with
+/// a custom [`Primitive`].
+///
+/// The contents are rendered into a [`Texture`] that is passed to
+/// [`Primitive::render_layer`]; which can be used to post-process them
+/// with a custom shader.
+///
+/// [`Texture`]: primitive::Texture
+#[allow(missing_debug_implementations)]
+pub struct Offscreen<'a, Message, Theme, Renderer, P>
+where
+    Renderer: primitive::Renderer,
+    P: Primitive,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    primitive: Box<dyn Fn(Rectangle, mouse::Cursor) -> P + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer, P> Offscreen<'a, Message, Theme, Renderer, P>
+where
+    Renderer: primitive::Renderer,
+    P: Primitive,
+{
+    /// Creates a new [`Offscreen`] widget with the given content and a
+    /// function that produces the [`Primitive`] drawing it, given the
+    /// bounds of the widget and the mouse cursor.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        primitive: impl Fn(Rectangle, mouse::Cursor) -> P + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            primitive: Box::new(primitive),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, P> Widget<Message, Theme, Renderer>
+    for Offscreen<'a, Message, Theme, Renderer, P>
+where
+    Renderer: primitive::Renderer,
+    P: Primitive,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.with_layer_primitive(
+            bounds,
+            (self.primitive)(bounds, cursor),
+            |renderer| {
+                self.content.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer, P>
+    From<Offscreen<'a, Message, Theme, Renderer, P>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: primitive::Renderer + 'a,
+    P: Primitive,
+{
+    fn from(
+        offscreen: Offscreen<'a, Message, Theme, Renderer, P>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(offscreen)
+    }
+}
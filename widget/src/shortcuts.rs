@@ -0,0 +1,363 @@
+//! Trigger messages with keyboard shortcuts.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::keyboard; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::keyboard::shortcut::{Registry, Shortcut};
+//! use iced::widget::{shortcuts, text_editor};
+//!
+//! struct State {
+//!    content: text_editor::Content,
+//!    shortcuts: Registry<Message>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Edit(text_editor::Action),
+//!     Save,
+//!     SaveAll,
+//! }
+//!
+//! impl State {
+//!     fn new() -> Self {
+//!         Self {
+//!             content: text_editor::Content::new(),
+//!             shortcuts: Registry::new()
+//!                 .bind(Shortcut::parse("Cmd+S").unwrap(), Message::Save)
+//!                 .bind(Shortcut::parse("Cmd+K S").unwrap(), Message::SaveAll),
+//!         }
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     shortcuts(
+//!         &state.shortcuts,
+//!         text_editor(&state.content).on_action(Message::Edit),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::keyboard::shortcut::{Chord, Match, Priority, Registry};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{tree, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A widget that triggers the [`Binding`]s of a [`Registry`] when their
+/// shortcuts are pressed.
+///
+/// A [`Shortcuts`] widget wrapping the contents of a window will trigger
+/// its bindings as long as the window has focus. Wrap any subtree
+/// of widgets instead, with a [`Scope::Focused`], to only trigger the
+/// bindings while a widget inside the subtree has focus.
+///
+/// [`Binding`]: crate::core::keyboard::shortcut::Binding
+#[allow(missing_debug_implementations)]
+pub struct Shortcuts<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    registry: &'a Registry<Message>,
+    scope: Scope,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Shortcuts<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Shortcuts`] widget with the given [`Registry`] and
+    /// content.
+    pub fn new(
+        registry: &'a Registry<Message>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            registry,
+            scope: Scope::default(),
+            content: content.into(),
+        }
+    }
+
+    /// Sets the [`Scope`] of the [`Shortcuts`].
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// The scope where the shortcuts of a [`Shortcuts`] widget are active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scope {
+    /// The shortcuts are active as long as the window has focus.
+    #[default]
+    Window,
+
+    /// The shortcuts are only active while a widget inside the contents
+    /// of the [`Shortcuts`] widget has focus.
+    Focused,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pending: Vec<Chord>,
+}
+
+impl<'a, Message, Theme, Renderer> Shortcuts<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn is_active(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> bool {
+        match self.scope {
+            Scope::Window => true,
+            Scope::Focused => {
+                let mut count = operation::focusable::count();
+
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut operation::black_box(&mut count),
+                );
+
+                matches!(
+                    count.finish(),
+                    operation::Outcome::Some(operation::focusable::Count {
+                        focused: Some(_),
+                        ..
+                    })
+                )
+            }
+        }
+    }
+}
+
+/// Looks for the given [`Chord`] after the pending ones and publishes the
+/// message of any matching binding.
+///
+/// Returns true if the [`Chord`] was consumed.
+fn resolve<Message: Clone>(
+    registry: &Registry<Message>,
+    state: &mut State,
+    chord: &Chord,
+    priority: Option<Priority>,
+    shell: &mut Shell<'_, Message>,
+) -> bool {
+    let mut sequence = std::mem::take(&mut state.pending);
+    sequence.push(chord.clone());
+
+    match registry.find(&sequence, |binding| {
+        priority.is_none() || priority == Some(binding.priority)
+    }) {
+        Match::Exact(message) => {
+            shell.publish(message.clone());
+            true
+        }
+        Match::Partial => {
+            state.pending = sequence;
+            true
+        }
+        Match::None => false,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Shortcuts<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let chord = match &event {
+            Event::Keyboard(event) => Chord::from_event(event)
+                .filter(|_| self.is_active(tree, layout, renderer)),
+            _ => None,
+        };
+
+        if let Some(chord) = &chord {
+            let state = tree.state.downcast_mut::<State>();
+
+            // Sequences in progress take priority over the focused widget
+            if !state.pending.is_empty()
+                && resolve(self.registry, state, chord, None, shell)
+            {
+                return event::Status::Captured;
+            }
+
+            if resolve(
+                self.registry,
+                state,
+                chord,
+                Some(Priority::Override),
+                shell,
+            ) {
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let (Some(chord), event::Status::Ignored) = (&chord, status) {
+            let state = tree.state.downcast_mut::<State>();
+
+            if resolve(
+                self.registry,
+                state,
+                chord,
+                Some(Priority::Defer),
+                shell,
+            ) {
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Shortcuts<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        shortcuts: Shortcuts<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(shortcuts)
+    }
+}
@@ -268,7 +268,7 @@ where
                     state, layout, cursor, viewport, renderer,
                 )
             })
-            .find(|&interaction| interaction != mouse::Interaction::None)
+            .find(|interaction| *interaction != mouse::Interaction::None)
             .unwrap_or_default()
     }
 
@@ -3,9 +3,11 @@ use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::decoration::{self, Decoration};
 use crate::core::text::{Paragraph, Span};
 use crate::core::widget::text::{
-    self, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
+    self, Catalog, Ellipsis, LineHeight, Selection, Shaping, Style, StyleFn,
+    Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
@@ -30,6 +32,9 @@ where
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -52,6 +57,9 @@ where
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            is_selectable: false,
             class: Theme::default(),
         }
     }
@@ -126,6 +134,28 @@ where
         self
     }
 
+    /// Sets the [`Ellipsis`] strategy of the [`Rich`] text.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Rich`] text.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether the contents of the [`Rich`] text can be selected and
+    /// copied by the user.
+    ///
+    /// Clicking a link still publishes it, instead of starting a selection.
+    /// Truncated contents cannot be selected.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -151,7 +181,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the default style class of the [`Rich`] text.
@@ -179,6 +212,8 @@ struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
     paragraph: P,
+    content: String,
+    selection: Selection,
 }
 
 impl<'a, Link, Theme, Renderer> Widget<Link, Theme, Renderer>
@@ -197,6 +232,8 @@ where
             spans: Vec::new(),
             span_pressed: None,
             paragraph: Renderer::Paragraph::default(),
+            content: String::new(),
+            selection: Selection::default(),
         })
     }
 
@@ -227,6 +264,8 @@ where
             self.align_x,
             self.align_y,
             self.wrapping,
+            self.ellipsis,
+            self.max_lines,
         )
     }
 
@@ -246,6 +285,15 @@ where
 
         let style = theme.style(&self.class);
 
+        if self.is_selectable {
+            state.selection.draw(
+                renderer,
+                &state.paragraph,
+                layout.bounds(),
+                style.selection_color(defaults),
+            );
+        }
+
         let hovered_span = cursor
             .position_in(layout.bounds())
             .and_then(|position| state.paragraph.hit_span(position));
@@ -254,11 +302,14 @@ where
             let is_hovered_link =
                 span.link.is_some() && Some(index) == hovered_span;
 
-            if span.highlight.is_some()
-                || span.underline
-                || span.strikethrough
-                || is_hovered_link
-            {
+            let hovered_underline = (is_hovered_link
+                && span.underline.is_none())
+            .then(|| (decoration::Line::Underline, Decoration::default()));
+
+            let decorations: Vec<_> =
+                span.decorations().chain(hovered_underline).collect();
+
+            if span.highlight.is_some() || !decorations.is_empty() {
                 let translation = layout.position() - Point::ORIGIN;
                 let regions = state.paragraph.span_bounds(index);
 
@@ -288,7 +339,7 @@ where
                     }
                 }
 
-                if span.underline || span.strikethrough || is_hovered_link {
+                if !decorations.is_empty() {
                     let size = span
                         .size
                         .or(self.size)
@@ -304,41 +355,24 @@ where
                         .or(style.color)
                         .unwrap_or(defaults.text_color);
 
-                    let baseline = translation
-                        + Vector::new(
-                            0.0,
-                            size.0 + (line_height.0 - size.0) / 2.0,
-                        );
-
-                    if span.underline || is_hovered_link {
-                        for bounds in &regions {
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle::new(
-                                        bounds.position() + baseline
-                                            - Vector::new(0.0, size.0 * 0.08),
-                                        Size::new(bounds.width, 1.0),
-                                    ),
-                                    ..Default::default()
-                                },
-                                color,
-                            );
-                        }
-                    }
-
-                    if span.strikethrough {
-                        for bounds in &regions {
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle::new(
-                                        bounds.position() + baseline
-                                            - Vector::new(0.0, size.0 / 2.0),
-                                        Size::new(bounds.width, 1.0),
-                                    ),
-                                    ..Default::default()
-                                },
-                                color,
-                            );
+                    for bounds in &regions {
+                        let bounds = Rectangle {
+                            height: line_height.0,
+                            ..*bounds
+                        } + translation;
+
+                        for (line, decoration) in &decorations {
+                            for segment in
+                                decoration.segments(*line, bounds, size)
+                            {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: segment,
+                                        ..Default::default()
+                                    },
+                                    decoration.color.unwrap_or(color),
+                                );
+                            }
                         }
                     }
                 }
@@ -362,10 +396,12 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Link>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let spans = self.spans.as_ref().as_ref();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(position) = cursor.position_in(layout.bounds()) {
@@ -374,9 +410,16 @@ where
                         .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
                     if let Some(span) = state.paragraph.hit_span(position) {
-                        state.span_pressed = Some(span);
+                        let is_link = spans
+                            .get(span)
+                            .is_some_and(|span| span.link.is_some());
+
+                        if is_link || !self.is_selectable {
+                            state.span_pressed = Some(span);
+                            state.selection.clear();
 
-                        return event::Status::Captured;
+                            return event::Status::Captured;
+                        }
                     }
                 }
             }
@@ -392,10 +435,7 @@ where
                     {
                         match state.paragraph.hit_span(position) {
                             Some(span) if span == span_pressed => {
-                                if let Some(link) = self
-                                    .spans
-                                    .as_ref()
-                                    .as_ref()
+                                if let Some(link) = spans
                                     .get(span)
                                     .and_then(|span| span.link.clone())
                                 {
@@ -410,7 +450,22 @@ where
             _ => {}
         }
 
-        event::Status::Ignored
+        if self.is_selectable {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            state.selection.update(
+                &event,
+                layout.bounds(),
+                cursor,
+                &state.paragraph,
+                &state.content,
+                clipboard,
+            )
+        } else {
+            event::Status::Ignored
+        }
     }
 
     fn mouse_interaction(
@@ -435,6 +490,10 @@ where
                     return mouse::Interaction::Pointer;
                 }
             }
+
+            if self.is_selectable && !state.paragraph.is_truncated() {
+                return mouse::Interaction::Text;
+            }
         }
 
         mouse::Interaction::None
@@ -454,6 +513,8 @@ fn layout<Link, Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
 ) -> layout::Node
 where
     Link: Clone,
@@ -475,12 +536,16 @@ where
             vertical_alignment,
             shaping: Shaping::Advanced,
             wrapping,
+            ellipsis,
+            max_lines,
         };
 
         if state.spans != spans {
             state.paragraph =
                 Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.content =
+                spans.iter().map(|span| span.text.as_ref()).collect();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -492,6 +557,8 @@ where
                 vertical_alignment,
                 shaping: Shaping::Advanced,
                 wrapping,
+                ellipsis,
+                max_lines,
             }) {
                 core::text::Difference::None => {}
                 core::text::Difference::Bounds => {
@@ -38,9 +38,12 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text::decoration;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
+use crate::core::text::spell;
 use crate::core::text::{self, LineHeight, Text, Wrapping};
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
@@ -51,9 +54,11 @@ use crate::core::{
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
 
+use crate::overlay::suggestions::{self, Suggestions};
+
 use std::cell::RefCell;
 use std::fmt;
-use std::ops::DerefMut;
+use std::ops::{DerefMut, Range};
 use std::sync::Arc;
 
 pub use text::editor::{Action, Edit, Motion};
@@ -115,6 +120,7 @@ pub struct TextEditor<
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    spell_checker: Option<&'a dyn spell::Checker>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -143,6 +149,7 @@ where
             class: Theme::default(),
             key_binding: None,
             on_edit: None,
+            spell_checker: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -268,11 +275,21 @@ where
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
+            spell_checker: self.spell_checker,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
     }
 
+    /// Sets the [`spell::Checker`] of the [`TextEditor`].
+    ///
+    /// Misspelled words will be underlined, and right clicking them will
+    /// show a list of suggestions to replace them with.
+    pub fn spell_checker(mut self, checker: &'a dyn spell::Checker) -> Self {
+        self.spell_checker = Some(checker);
+        self
+    }
+
     /// Sets the closure to produce key bindings on key presses.
     ///
     /// See [`Binding`] for the list of available bindings.
@@ -314,6 +331,9 @@ where
 {
     editor: R::Editor,
     is_dirty: bool,
+    /// The topmost line changed by an edit since the misspellings of the
+    /// [`Content`] were last invalidated.
+    edited_line: Option<usize>,
 }
 
 impl<R> Content<R>
@@ -330,6 +350,7 @@ where
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
             is_dirty: true,
+            edited_line: Some(0),
         }))
     }
 
@@ -337,8 +358,32 @@ where
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
+        let edited_line = match &action {
+            Action::Edit(edit) => {
+                let (line, _) = internal.editor.cursor_position();
+
+                Some(match edit {
+                    Edit::Replace { line: replaced, .. } => line.min(*replaced),
+                    _ => line,
+                })
+            }
+            _ => None,
+        };
+
         internal.editor.perform(action);
         internal.is_dirty = true;
+
+        // Edits replace the selection, so the cursor ends up at its start
+        if let Some(line) = edited_line {
+            let (cursor_line, _) = internal.editor.cursor_position();
+            let line = line.min(cursor_line);
+
+            internal.edited_line = Some(
+                internal
+                    .edited_line
+                    .map_or(line, |edited_line| edited_line.min(line)),
+            );
+        }
     }
 
     /// Returns the amount of lines of the [`Content`].
@@ -457,6 +502,18 @@ pub struct State<Highlighter: text::Highlighter> {
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    misspellings: RefCell<Vec<Option<Vec<Range<usize>>>>>,
+    misspelling: Option<Misspelling>,
+    suggestions: Vec<String>,
+    hovered_suggestion: Option<usize>,
+}
+
+/// A misspelled word whose suggestions are being shown.
+#[derive(Debug, Clone)]
+struct Misspelling {
+    line: usize,
+    range: Range<usize>,
+    bounds: Rectangle,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -533,6 +590,10 @@ where
             )),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            misspellings: RefCell::new(Vec::new()),
+            misspelling: None,
+            suggestions: Vec::new(),
+            hovered_suggestion: None,
         })
     }
 
@@ -568,6 +629,10 @@ where
             state.highlighter_settings = self.highlighter_settings.clone();
         }
 
+        if let Some(line) = internal.edited_line.take() {
+            state.misspellings.get_mut().truncate(line);
+        }
+
         let limits = limits.width(self.width).height(self.height);
 
         internal.editor.update(
@@ -645,6 +710,18 @@ where
                     }
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                // Clicks outside the suggestions only close them
+                if state.misspelling.take().is_some()
+                    && button == mouse::Button::Left
+                {
+                    return event::Status::Captured;
+                }
+
+                if button == mouse::Button::Right {
+                    return self.open_suggestions(state, layout, cursor);
+                }
+            }
             _ => {}
         }
 
@@ -865,6 +942,8 @@ where
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: text::Shaping::Advanced,
                         wrapping: self.wrapping,
+                        ellipsis: text::Ellipsis::default(),
+                        max_lines: None,
                     },
                     text_bounds.position(),
                     style.placeholder,
@@ -881,6 +960,63 @@ where
         }
 
         let translation = text_bounds.position() - Point::ORIGIN;
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        if let Some(checker) = self.spell_checker {
+            let mut misspellings = state.misspellings.borrow_mut();
+            let color = spell::DECORATION.color.unwrap_or(style.value);
+
+            misspellings.resize(internal.editor.line_count(), None);
+
+            // Only visible lines are checked; the rest are checked lazily
+            // once scrolled into view
+            for i in internal.editor.visible_lines() {
+                let Some(line) = internal.editor.line(i) else {
+                    continue;
+                };
+
+                let ranges =
+                    misspellings[i].get_or_insert_with(|| checker.check(line));
+
+                for range in ranges.iter() {
+                    for bounds in internal.editor.range_bounds(i, range.clone())
+                    {
+                        for segment in spell::DECORATION.segments(
+                            decoration::Line::Underline,
+                            bounds + translation,
+                            size,
+                        ) {
+                            if let Some(segment) =
+                                text_bounds.intersection(&segment)
+                            {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: segment,
+                                        ..renderer::Quad::default()
+                                    },
+                                    color,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for (bounds, line, decoration) in internal.editor.decorations() {
+            for segment in decoration.segments(line, bounds + translation, size)
+            {
+                if let Some(segment) = text_bounds.intersection(&segment) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: segment,
+                            ..renderer::Quad::default()
+                        },
+                        decoration.color.unwrap_or(style.value),
+                    );
+                }
+            }
+        }
 
         if let Some(focus) = state.focus.as_ref() {
             match internal.editor.cursor() {
@@ -960,6 +1096,127 @@ where
 
         operation.focusable(state, None);
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        let on_edit = self.on_edit.as_ref()?;
+        let Misspelling {
+            line,
+            range,
+            bounds,
+        } = state.misspelling.clone()?;
+
+        let class = &self.class;
+        let misspelling = &mut state.misspelling;
+
+        let mut suggestions = Suggestions::new(
+            &state.suggestions,
+            &mut state.hovered_suggestion,
+            move |suggestion| {
+                *misspelling = None;
+
+                on_edit(Action::Edit(Edit::Replace {
+                    line,
+                    range: range.clone(),
+                    text: Arc::new(suggestion.to_owned()),
+                }))
+            },
+            move |theme: &Theme| {
+                let style = theme.style(class, Status::Focused);
+
+                suggestions::Style {
+                    background: style.background,
+                    border: style.border,
+                    text_color: style.value,
+                    hovered_background: Background::Color(style.selection),
+                }
+            },
+        )
+        .font(self.font.unwrap_or_else(|| renderer.default_font()))
+        .text_line_height(self.line_height);
+
+        if let Some(text_size) = self.text_size {
+            suggestions = suggestions.text_size(text_size);
+        }
+
+        Some(suggestions.overlay(
+            layout.position() + translation + Vector::new(bounds.x, bounds.y),
+            bounds.height,
+        ))
+    }
+}
+
+impl<'a, Highlighter, Message, Theme, Renderer>
+    TextEditor<'a, Highlighter, Message, Theme, Renderer>
+where
+    Highlighter: text::Highlighter,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn open_suggestions(
+        &self,
+        state: &mut State<Highlighter>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> event::Status {
+        let Some(checker) = self.spell_checker else {
+            return event::Status::Ignored;
+        };
+
+        let Some(position) = cursor.position_in(layout.bounds()) else {
+            return event::Status::Ignored;
+        };
+
+        let padding = Vector::new(self.padding.left, self.padding.top);
+        let internal = self.content.0.borrow();
+
+        let Some((line, index)) = internal.editor.hit(position - padding)
+        else {
+            return event::Status::Ignored;
+        };
+
+        let Some(text) = internal.editor.line(line) else {
+            return event::Status::Ignored;
+        };
+
+        let Some(range) = checker
+            .check(text)
+            .into_iter()
+            .find(|range| range.start <= index && index <= range.end)
+        else {
+            return event::Status::Ignored;
+        };
+
+        let suggestions = checker.suggest(&text[range.clone()]);
+
+        let Some(bounds) = internal
+            .editor
+            .range_bounds(line, range.clone())
+            .into_iter()
+            .last()
+        else {
+            return event::Status::Ignored;
+        };
+
+        if !suggestions.is_empty() {
+            state.misspelling = Some(Misspelling {
+                line,
+                range,
+                bounds: bounds + padding,
+            });
+            state.suggestions = suggestions;
+            state.hovered_suggestion = None;
+        }
+
+        event::Status::Captured
+    }
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -34,8 +34,10 @@ mod editor;
 mod value;
 
 pub mod cursor;
+pub mod mask;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
 pub use value::Value;
 
 use editor::Editor;
@@ -47,8 +49,11 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse::{self, click};
+use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text::decoration;
 use crate::core::text::paragraph::{self, Paragraph as _};
+use crate::core::text::spell;
 use crate::core::text::{self, Text};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
@@ -58,11 +63,14 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Theme, Vector, Widget,
+    Rectangle, Shadows, Shell, Size, Theme, Vector, Widget,
 };
+use crate::overlay::suggestions::{self, Suggestions};
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
+use std::ops::Range;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -119,6 +127,10 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    spell_checker: Option<&'a dyn spell::Checker>,
+    mask: Option<Mask>,
+    validator: Option<Box<dyn Fn(&str) -> Result<(), String> + 'a>>,
+    show_error: bool,
     class: Theme::Class<'a>,
 }
 
@@ -149,6 +161,10 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            spell_checker: None,
+            mask: None,
+            validator: None,
+            show_error: false,
             class: Theme::default(),
         }
     }
@@ -237,6 +253,49 @@ where
         self
     }
 
+    /// Sets the [`spell::Checker`] of the [`TextInput`].
+    ///
+    /// Misspelled words will be underlined, and right clicking them will
+    /// show a list of suggestions to replace them with.
+    ///
+    /// Secure inputs are never checked.
+    pub fn spell_checker(mut self, checker: &'a dyn spell::Checker) -> Self {
+        self.spell_checker = Some(checker);
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    ///
+    /// The current value and any text typed or pasted will be fitted into
+    /// the [`Mask`] before producing any messages.
+    pub fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        let mask = mask.into();
+
+        self.value = Value::new(&mask.apply(&self.value.to_string()));
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets the validator of the [`TextInput`].
+    ///
+    /// The validator returns an error message when the current value is
+    /// invalid, which turns the [`Status`] of the [`TextInput`] into
+    /// [`Status::Invalid`].
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets whether the error message of the validator of the [`TextInput`]
+    /// should be displayed under it.
+    pub fn show_error(mut self, show_error: bool) -> Self {
+        self.show_error = show_error;
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -302,6 +361,7 @@ where
     ) -> layout::Node {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let value = value.unwrap_or(&self.value);
+        let error = self.error(value);
 
         let font = self.font.unwrap_or_else(|| renderer.default_font());
         let text_size = self.size.unwrap_or_else(|| renderer.default_size());
@@ -321,6 +381,8 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
             wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
         };
 
         state.placeholder.update(placeholder_text);
@@ -333,7 +395,7 @@ where
             ..placeholder_text
         });
 
-        if let Some(icon) = &self.icon {
+        let field = if let Some(icon) = &self.icon {
             let mut content = [0; 4];
 
             let icon_text = Text {
@@ -346,6 +408,8 @@ where
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
                 wrapping: text::Wrapping::default(),
+                ellipsis: text::Ellipsis::default(),
+                max_lines: None,
             };
 
             state.icon.update(icon_text);
@@ -387,7 +451,41 @@ where
                 .move_to(Point::new(padding.left, padding.top));
 
             layout::Node::with_children(text_bounds.expand(padding), vec![text])
-        }
+        };
+
+        let Some(error) = error.filter(|_| self.show_error) else {
+            return layout::Node::with_children(field.size(), vec![field]);
+        };
+
+        state.error.update(Text {
+            content: error.as_str(),
+            bounds: Size::new(field.size().width, f32::INFINITY),
+            vertical_alignment: alignment::Vertical::Top,
+            wrapping: text::Wrapping::Word,
+            ..placeholder_text
+        });
+
+        let error = layout::Node::new(Size::new(
+            field.size().width,
+            state.error.min_bounds().height,
+        ))
+        .move_to(Point::new(0.0, field.size().height + ERROR_SPACING));
+
+        layout::Node::with_children(
+            Size::new(
+                field.size().width,
+                error.bounds().y + error.size().height,
+            ),
+            vec![field, error],
+        )
+    }
+
+    /// Returns the error message of the validator of the [`TextInput`] for
+    /// the given [`Value`], if invalid.
+    fn error(&self, value: &Value) -> Option<String> {
+        let validator = self.validator.as_ref()?;
+
+        validator(&value.to_string()).err()
     }
 
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
@@ -407,10 +505,15 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let value = value.unwrap_or(&self.value);
         let is_disabled = self.on_input.is_none();
+        let is_invalid = self.error(value).is_some();
 
         let secure_value = self.is_secure.then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(value);
 
+        let mut children = layout.children();
+        let layout = children.next().unwrap();
+        let error_layout = children.next();
+
         let bounds = layout.bounds();
 
         let mut children_layout = layout.children();
@@ -420,6 +523,8 @@ where
 
         let status = if is_disabled {
             Status::Disabled
+        } else if is_invalid {
+            Status::Invalid
         } else if state.is_focused() {
             Status::Focused
         } else if is_mouse_over {
@@ -434,11 +539,20 @@ where
             renderer::Quad {
                 bounds,
                 border: style.border,
-                ..renderer::Quad::default()
+                shadows: style.shadows,
             },
             style.background,
         );
 
+        if let Some(error_layout) = error_layout {
+            renderer.fill_paragraph(
+                state.error.raw(),
+                error_layout.position(),
+                style.error,
+                *viewport,
+            );
+        }
+
         if self.icon.is_some() {
             let icon_layout = children_layout.next().unwrap();
 
@@ -452,6 +566,11 @@ where
 
         let text = value.to_string();
 
+        let misspellings = match self.spell_checker {
+            Some(checker) if !self.is_secure => checker.check(&text),
+            _ => Vec::new(),
+        };
+
         let (cursor, offset, is_selecting) = if let Some(focus) = state
             .is_focused
             .as_ref()
@@ -573,6 +692,46 @@ where
                 },
                 viewport,
             );
+
+            if misspellings.is_empty() {
+                return;
+            }
+
+            let text_size =
+                self.size.unwrap_or_else(|| renderer.default_size());
+            let line_height =
+                f32::from(self.line_height.to_absolute(text_size));
+            let color = spell::DECORATION.color.unwrap_or(style.value);
+
+            renderer.with_layer(text_bounds, |renderer| {
+                for range in &misspellings {
+                    for bounds in
+                        state.value.raw().selection_bounds(range.clone())
+                    {
+                        let line = Rectangle {
+                            x: text_bounds.x + bounds.x + alignment_offset
+                                - offset,
+                            y: text_bounds.center_y() - line_height / 2.0,
+                            width: bounds.width,
+                            height: line_height,
+                        };
+
+                        for segment in spell::DECORATION.segments(
+                            decoration::Line::Underline,
+                            line,
+                            text_size,
+                        ) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: segment,
+                                    ..renderer::Quad::default()
+                                },
+                                color,
+                            );
+                        }
+                    }
+                }
+            });
         };
 
         if is_selecting {
@@ -648,6 +807,8 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let layout = layout.children().next().unwrap();
+
         let update_cache = |state, value| {
             replace_paragraph(
                 renderer,
@@ -665,6 +826,11 @@ where
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let state = state::<Renderer>(tree);
 
+                // Clicks outside the suggestions only close them
+                if state.misspelling.take().is_some() {
+                    return event::Status::Captured;
+                }
+
                 let click_position = cursor.position_over(layout.bounds());
 
                 state.is_focused = if click_position.is_some() {
@@ -763,6 +929,77 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let state = state::<Renderer>(tree);
+                state.misspelling = None;
+
+                let Some(checker) = self.spell_checker else {
+                    return event::Status::Ignored;
+                };
+
+                if self.is_secure || self.on_input.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let text_bounds = layout.children().next().unwrap().bounds();
+
+                let alignment_offset =
+                    alignment_offset(
+                        text_bounds.width,
+                        state.value.raw().min_width(),
+                        self.alignment,
+                    ) - offset(text_bounds, &self.value, state);
+
+                let Some(index) = state
+                    .value
+                    .raw()
+                    .hit_test(Point::new(
+                        cursor_position.x - text_bounds.x - alignment_offset,
+                        text_bounds.height / 2.0,
+                    ))
+                    .map(text::Hit::cursor)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let text = self.value.to_string();
+
+                let Some(range) = checker
+                    .check(&text)
+                    .into_iter()
+                    .find(|range| range.start <= index && index <= range.end)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let suggestions = checker.suggest(&text[range.clone()]);
+
+                if suggestions.is_empty() {
+                    return event::Status::Captured;
+                }
+
+                let x = state
+                    .value
+                    .raw()
+                    .selection_bounds(range.clone())
+                    .first()
+                    .map_or(0.0, |bounds| bounds.x);
+
+                state.misspelling = Some(Misspelling {
+                    range,
+                    x: text_bounds.x - layout.bounds().x + x + alignment_offset,
+                });
+                state.suggestions = suggestions;
+                state.hovered_suggestion = None;
+
+                return event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
@@ -812,6 +1049,7 @@ where
                 key, text, ..
             }) => {
                 let state = state::<Renderer>(tree);
+                state.misspelling = None;
 
                 if let Some(focus) = &mut state.is_focused {
                     let modifiers = state.keyboard_modifiers;
@@ -850,8 +1088,11 @@ where
                                 );
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut state.cursor,
+                                self.mask.as_ref(),
+                            );
                             editor.delete();
 
                             let message = (on_input)(editor.contents());
@@ -883,8 +1124,11 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut state.cursor,
+                                self.mask.as_ref(),
+                            );
 
                             editor.paste(content.clone());
 
@@ -921,8 +1165,11 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut state.cursor,
+                                self.mask.as_ref(),
+                            );
 
                             editor.insert(c);
 
@@ -962,8 +1209,11 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut state.cursor,
+                                self.mask.as_ref(),
+                            );
                             editor.backspace();
 
                             let message = (on_input)(editor.contents());
@@ -993,8 +1243,11 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut state.cursor,
+                                self.mask.as_ref(),
+                            );
                             editor.delete();
 
                             let message = (on_input)(editor.contents());
@@ -1061,6 +1314,22 @@ where
                                 state.cursor.select_left(&self.value);
                             } else {
                                 state.cursor.move_left(&self.value);
+
+                                if let Some(mask) = &self.mask {
+                                    let mut position =
+                                        state.cursor.start(&self.value);
+
+                                    while position > 0
+                                        && mask.is_literal_at(
+                                            &self.value,
+                                            position - 1,
+                                        )
+                                    {
+                                        position -= 1;
+                                    }
+
+                                    state.cursor.move_to(position);
+                                }
                             }
                         }
                         keyboard::Key::Named(key::Named::ArrowRight) => {
@@ -1078,6 +1347,19 @@ where
                                 state.cursor.select_right(&self.value);
                             } else {
                                 state.cursor.move_right(&self.value);
+
+                                if let Some(mask) = &self.mask {
+                                    let mut position =
+                                        state.cursor.end(&self.value);
+
+                                    while mask
+                                        .is_literal_at(&self.value, position)
+                                    {
+                                        position += 1;
+                                    }
+
+                                    state.cursor.move_to(position);
+                                }
                             }
                         }
                         keyboard::Key::Named(key::Named::Escape) => {
@@ -1184,6 +1466,60 @@ where
         self.draw(tree, renderer, theme, layout, cursor, None, viewport);
     }
 
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let layout = layout.children().next().unwrap();
+
+        let on_input = self.on_input.as_ref()?;
+        let Misspelling { range, x } = state.misspelling.clone()?;
+
+        let class = &self.class;
+        let bounds = layout.bounds();
+        let value = self.value.to_string();
+
+        let misspelling = &mut state.misspelling;
+
+        let mut suggestions = Suggestions::new(
+            &state.suggestions,
+            &mut state.hovered_suggestion,
+            move |suggestion| {
+                *misspelling = None;
+
+                let mut value = value.clone();
+                value.replace_range(range.clone(), suggestion);
+
+                (on_input)(value)
+            },
+            move |theme: &Theme| {
+                let style = theme.style(class, Status::Focused);
+
+                suggestions::Style {
+                    background: style.background,
+                    border: style.border,
+                    text_color: style.value,
+                    hovered_background: Background::Color(style.selection),
+                }
+            },
+        )
+        .font(self.font.unwrap_or_else(|| renderer.default_font()))
+        .text_line_height(self.line_height);
+
+        if let Some(size) = self.size {
+            suggestions = suggestions.text_size(size);
+        }
+
+        Some(suggestions.overlay(
+            Point::new(bounds.x + x, bounds.y) + translation,
+            bounds.height,
+        ))
+    }
+
     fn mouse_interaction(
         &self,
         _state: &Tree,
@@ -1192,6 +1528,8 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let layout = layout.children().next().unwrap();
+
         if cursor.is_over(layout.bounds()) {
             if self.on_input.is_none() {
                 mouse::Interaction::Idle
@@ -1321,12 +1659,16 @@ pub struct State<P: text::Paragraph> {
     value: paragraph::Plain<P>,
     placeholder: paragraph::Plain<P>,
     icon: paragraph::Plain<P>,
+    error: paragraph::Plain<P>,
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    misspelling: Option<Misspelling>,
+    suggestions: Vec<String>,
+    hovered_suggestion: Option<usize>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1336,6 +1678,13 @@ fn state<Renderer: text::Renderer>(
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
 
+/// A misspelled word whose suggestions are being shown.
+#[derive(Debug, Clone)]
+struct Misspelling {
+    range: Range<usize>,
+    x: f32,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
@@ -1522,11 +1871,16 @@ fn replace_paragraph<Renderer>(
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
         wrapping: text::Wrapping::default(),
+        ellipsis: text::Ellipsis::default(),
+        max_lines: None,
     });
 }
 
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
 
+/// The vertical spacing between a [`TextInput`] and its error message.
+const ERROR_SPACING: f32 = 4.0;
+
 /// The possible status of a [`TextInput`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -1538,6 +1892,8 @@ pub enum Status {
     Focused,
     /// The [`TextInput`] cannot be interacted with.
     Disabled,
+    /// The value of the [`TextInput`] was rejected by its validator.
+    Invalid,
 }
 
 /// The appearance of a text input.
@@ -1547,6 +1903,8 @@ pub struct Style {
     pub background: Background,
     /// The [`Border`] of the text input.
     pub border: Border,
+    /// The [`Shadows`] of the text input.
+    pub shadows: Shadows,
     /// The [`Color`] of the icon of the text input.
     pub icon: Color,
     /// The [`Color`] of the placeholder of the text input.
@@ -1555,6 +1913,8 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the error message of the text input.
+    pub error: Color,
 }
 
 /// The theme catalog of a [`TextInput`].
@@ -1597,10 +1957,12 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             width: 1.0,
             color: palette.background.strong.color,
         },
+        shadows: Shadows::NONE,
         icon: palette.background.weak.text,
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        error: palette.danger.base.color,
     };
 
     match status {
@@ -1624,6 +1986,13 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             value: active.placeholder,
             ..active
         },
+        Status::Invalid => Style {
+            border: Border {
+                color: palette.danger.base.color,
+                ..active.border
+            },
+            ..active
+        },
     }
 }
 
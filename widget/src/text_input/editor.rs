@@ -1,13 +1,22 @@
-use crate::text_input::{Cursor, Value};
+use crate::text_input::{Cursor, Mask, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    mask: Option<&'a Mask>,
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        mask: Option<&'a Mask>,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            mask,
+        }
     }
 
     pub fn contents(&self) -> String {
@@ -22,6 +31,8 @@ impl<'a> Editor<'a> {
 
         self.value.insert(self.cursor.end(self.value), character);
         self.cursor.move_right(self.value);
+
+        self.conform();
     }
 
     pub fn paste(&mut self, content: Value) {
@@ -34,6 +45,8 @@ impl<'a> Editor<'a> {
         self.value.insert_many(self.cursor.end(self.value), content);
 
         self.cursor.move_right_by_amount(self.value, length);
+
+        self.conform();
     }
 
     pub fn backspace(&mut self) {
@@ -43,7 +56,16 @@ impl<'a> Editor<'a> {
                 self.value.remove_many(start, end);
             }
             None => {
-                let start = self.cursor.start(self.value);
+                let mut start = self.cursor.start(self.value);
+
+                if let Some(mask) = self.mask {
+                    while start > 0 && mask.is_literal_at(self.value, start - 1)
+                    {
+                        start -= 1;
+                    }
+
+                    self.cursor.move_to(start);
+                }
 
                 if start > 0 {
                     self.cursor.move_left(self.value);
@@ -51,6 +73,8 @@ impl<'a> Editor<'a> {
                 }
             }
         }
+
+        self.conform();
     }
 
     pub fn delete(&mut self) {
@@ -59,12 +83,110 @@ impl<'a> Editor<'a> {
                 self.backspace();
             }
             None => {
-                let end = self.cursor.end(self.value);
+                let mut end = self.cursor.end(self.value);
+
+                if let Some(mask) = self.mask {
+                    while mask.is_literal_at(self.value, end) {
+                        end += 1;
+                    }
+                }
 
                 if end < self.value.len() {
                     self.value.remove(end);
                 }
+
+                self.conform();
             }
         }
     }
+
+    /// Fits the value into the mask, if any, keeping the cursor after the
+    /// same typed characters.
+    fn conform(&mut self) {
+        let Some(mask) = self.mask else {
+            return;
+        };
+
+        let position = self.cursor.end(self.value);
+        let before = mask.apply(&self.value.until(position).to_string());
+
+        *self.value = Value::new(&mask.apply(&self.value.to_string()));
+        self.cursor.move_to(Value::new(&before).len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum Input {
+        Type(&'static str),
+        Paste(&'static str),
+        Backspace,
+        Delete,
+    }
+
+    fn edit(value: &str, position: usize, input: Input) -> (String, usize) {
+        let mask = Mask::new("(999) 999-9999");
+        let mut value = Value::new(value);
+        let mut cursor = Cursor::default();
+
+        cursor.move_to(position);
+
+        let mut editor = Editor::new(&mut value, &mut cursor, Some(&mask));
+
+        match input {
+            Input::Type(text) => text.chars().for_each(|c| editor.insert(c)),
+            Input::Paste(text) => editor.paste(Value::new(text)),
+            Input::Backspace => editor.backspace(),
+            Input::Delete => editor.delete(),
+        }
+
+        (editor.contents(), cursor.end(&value))
+    }
+
+    #[test]
+    fn typing_inserts_literals() {
+        assert_eq!(edit("", 0, Input::Type("5551")), ("(555) 1".to_owned(), 7));
+    }
+
+    #[test]
+    fn typing_in_the_middle_shifts_the_rest() {
+        assert_eq!(
+            edit("(555) 123", 2, Input::Type("9")),
+            ("(595) 512-3".to_owned(), 3)
+        );
+    }
+
+    #[test]
+    fn backspacing_skips_literals() {
+        assert_eq!(
+            edit("(555) 1", 6, Input::Backspace),
+            ("(551".to_owned(), 3)
+        );
+        assert_eq!(
+            edit("(555) 1", 7, Input::Backspace),
+            ("(555".to_owned(), 4)
+        );
+    }
+
+    #[test]
+    fn deleting_skips_literals() {
+        assert_eq!(
+            edit("(555) 123", 4, Input::Delete),
+            ("(555) 23".to_owned(), 4)
+        );
+    }
+
+    #[test]
+    fn pasting_fits_the_mask() {
+        assert_eq!(
+            edit("", 0, Input::Paste("555-123-4567")),
+            ("(555) 123-4567".to_owned(), 14)
+        );
+        assert_eq!(
+            edit("(555", 1, Input::Paste("12")),
+            ("(125) 55".to_owned(), 3)
+        );
+    }
 }
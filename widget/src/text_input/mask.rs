@@ -0,0 +1,199 @@
+//! Constrain the value of a text input to a pattern.
+use crate::text_input::Value;
+
+/// A pattern that the value of a text input must follow.
+///
+/// A [`Mask`] is made of slots, which are described by the characters of
+/// its pattern:
+///
+/// - `9` accepts a digit.
+/// - `a` accepts a letter.
+/// - `*` accepts a letter or a digit.
+/// - `\` turns the next character into a literal.
+/// - Any other character is a literal.
+///
+/// Literals are inserted automatically when the user types the next
+/// character, and the cursor of a text input skips them.
+///
+/// # Example
+/// ```
+/// # use iced_widget::text_input::Mask;
+/// let phone = Mask::new("(999) 999-9999");
+///
+/// assert_eq!(phone.apply("5551234567"), "(555) 123-4567");
+/// assert_eq!(phone.apply("555"), "(555");
+/// assert!(!phone.is_complete("(555"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+/// A slot of a [`Mask`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// Accepts a digit.
+    Digit,
+    /// Accepts a letter.
+    Letter,
+    /// Accepts a letter or a digit.
+    Alphanumeric,
+    /// A fixed character.
+    Literal(char),
+}
+
+impl Slot {
+    /// Returns true if the [`Slot`] accepts the given character.
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(literal) => c == literal,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+
+        let slots = std::iter::from_fn(|| {
+            Some(match chars.next()? {
+                '9' => Slot::Digit,
+                'a' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            })
+        })
+        .collect();
+
+        Self { slots }
+    }
+
+    /// Returns the slots of the [`Mask`].
+    pub fn slots(&self) -> &[Slot] {
+        &self.slots
+    }
+
+    /// Returns true if the slot at the given index of the [`Mask`] is a
+    /// [`Slot::Literal`].
+    pub fn is_literal(&self, index: usize) -> bool {
+        matches!(self.slots.get(index), Some(Slot::Literal(_)))
+    }
+
+    /// Returns true if the grapheme at the given index of the [`Value`]
+    /// fills a [`Slot::Literal`] of the [`Mask`].
+    ///
+    /// Slots are filled by characters, while the positions of a [`Value`]
+    /// count graphemes; so the index is converted accordingly.
+    pub fn is_literal_at(&self, value: &Value, index: usize) -> bool {
+        index < value.len()
+            && self.is_literal(value.until(index).to_string().chars().count())
+    }
+
+    /// Fits the given input into the [`Mask`].
+    ///
+    /// Characters that do not fit their slot are discarded, and literals are
+    /// inserted before every accepted character that follows them. The input
+    /// may already contain the literals, so applying a [`Mask`] to its own
+    /// output produces the same output.
+    pub fn apply(&self, input: &str) -> String {
+        let mut output = String::new();
+        let mut literals = String::new();
+        let mut chars = input.chars().peekable();
+
+        for slot in &self.slots {
+            if let Slot::Literal(literal) = slot {
+                if chars.peek() == Some(literal) {
+                    let _ = chars.next();
+                }
+
+                literals.push(*literal);
+                continue;
+            }
+
+            let Some(c) = chars.by_ref().find(|c| slot.accepts(*c)) else {
+                break;
+            };
+
+            output.push_str(&literals);
+            output.push(c);
+            literals.clear();
+        }
+
+        output
+    }
+
+    /// Returns the characters typed into the given masked value; that is,
+    /// the value without its literals.
+    pub fn raw(&self, value: &str) -> String {
+        self.slots
+            .iter()
+            .zip(value.chars())
+            .filter(|(slot, _)| !matches!(slot, Slot::Literal(_)))
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    /// Returns true if the given value fills every slot of the [`Mask`].
+    pub fn is_complete(&self, value: &str) -> bool {
+        self.apply(value).chars().count() == self.slots.len()
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_are_inserted() {
+        let mask = Mask::new("(999) 999-9999");
+
+        assert_eq!(mask.apply(""), "");
+        assert_eq!(mask.apply("5"), "(5");
+        assert_eq!(mask.apply("5551"), "(555) 1");
+        assert_eq!(mask.apply("555-123-45678"), "(555) 123-4567");
+        assert_eq!(mask.apply("(555) 1x2"), "(555) 12");
+    }
+
+    #[test]
+    fn applying_is_idempotent() {
+        let mask = Mask::new("*****-*****");
+        let value = mask.apply("ab12cXYZ");
+
+        assert_eq!(value, "ab12c-XYZ");
+        assert_eq!(mask.apply(&value), value);
+        assert_eq!(mask.raw(&value), "ab12cXYZ");
+    }
+
+    #[test]
+    fn literals_are_found_by_grapheme() {
+        let mask = Mask::new("aa-9");
+        let value = Value::new(&mask.apply("\u{915}\u{93F}1"));
+
+        assert_eq!(value.len(), 3);
+        assert!(!mask.is_literal_at(&value, 0));
+        assert!(mask.is_literal_at(&value, 1));
+        assert!(!mask.is_literal_at(&value, 2));
+        assert!(!mask.is_literal_at(&value, 3));
+    }
+
+    #[test]
+    fn escaped_characters_are_literals() {
+        let mask = Mask::new("\\9a-99");
+
+        assert_eq!(mask.slots()[0], Slot::Literal('9'));
+        assert_eq!(mask.apply("x12"), "9x-12");
+        assert!(mask.is_complete("x12"));
+        assert!(!mask.is_complete("x1"));
+    }
+}
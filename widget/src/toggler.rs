@@ -296,6 +296,8 @@ where
                         alignment::Vertical::Top,
                         self.text_shaping,
                         self.text_wrapping,
+                        text::Ellipsis::None,
+                        None,
                     )
                 } else {
                     layout::Node::new(Size::ZERO)
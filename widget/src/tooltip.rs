@@ -73,6 +73,7 @@ pub struct Tooltip<
     gap: f32,
     padding: f32,
     snap_within_viewport: bool,
+    only_when_truncated: bool,
     class: Theme::Class<'a>,
 }
 
@@ -99,6 +100,7 @@ where
             gap: 0.0,
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
+            only_when_truncated: false,
             class: Theme::default(),
         }
     }
@@ -121,6 +123,18 @@ where
         self
     }
 
+    /// Sets whether the [`Tooltip`] is only displayed when its content is
+    /// some text that has been truncated.
+    ///
+    /// This is useful to reveal the full contents of text with an
+    /// [`Ellipsis`] or a maximum amount of lines.
+    ///
+    /// [`Ellipsis`]: text::Ellipsis
+    pub fn only_when_truncated(mut self, only_when_truncated: bool) -> Self {
+        self.only_when_truncated = only_when_truncated;
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     #[must_use]
     pub fn style(
@@ -201,12 +215,16 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let is_truncated = !self.only_when_truncated
+            || is_truncated::<Renderer>(&tree.children[0]);
+
         let state = tree.state.downcast_mut::<State>();
 
         let was_idle = *state == State::Idle;
 
         *state = cursor
             .position_over(layout.bounds())
+            .filter(|_| is_truncated)
             .map(|cursor_position| State::Hovered { cursor_position })
             .unwrap_or_default();
 
@@ -344,6 +362,22 @@ pub enum Position {
     FollowCursor,
 }
 
+/// Returns true if the given [`Tree`] belongs to some text that has been
+/// truncated.
+///
+/// [`Tree`]: widget::Tree
+fn is_truncated<Renderer>(tree: &widget::Tree) -> bool
+where
+    Renderer: text::Renderer,
+{
+    tree.tag
+        == widget::tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        && tree
+            .state
+            .downcast_ref::<widget::text::State<Renderer::Paragraph>>()
+            .is_truncated()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 enum State {
     #[default]
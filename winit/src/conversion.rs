@@ -2,11 +2,12 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.13/runtime
+use crate::core::image;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Point, Rectangle, Size};
 
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
@@ -34,6 +35,12 @@ pub fn window_attributes(
         .with_transparent(settings.transparent)
         .with_window_icon(settings.icon.and_then(icon))
         .with_window_level(window_level(settings.level))
+        .with_maximized(settings.maximized)
+        .with_fullscreen(
+            settings
+                .fullscreen
+                .then_some(winit::window::Fullscreen::Borderless(None)),
+        )
         .with_visible(settings.visible);
 
     if let Some(position) =
@@ -325,7 +332,8 @@ pub fn position(
 ) -> Option<winit::dpi::Position> {
     match position {
         window::Position::Default => None,
-        window::Position::Specific(position) => {
+        window::Position::Specific(position)
+        | window::Position::Restored(position) => {
             Some(winit::dpi::Position::Logical(winit::dpi::LogicalPosition {
                 x: f64::from(position.x),
                 y: f64::from(position.y),
@@ -387,6 +395,42 @@ pub fn position(
     }
 }
 
+/// Resolves a [`window::Position::Restored`] of the given [`window::Settings`],
+/// centering the window instead if it would not be visible in any of the
+/// given monitors.
+pub fn restore_position(
+    settings: window::Settings,
+    monitors: impl IntoIterator<Item = winit::monitor::MonitorHandle>,
+) -> window::Settings {
+    let window::Position::Restored(position) = settings.position else {
+        return settings;
+    };
+
+    let monitors = monitors.into_iter().map(|monitor| {
+        let scale_factor = monitor.scale_factor();
+
+        let position: winit::dpi::LogicalPosition<f32> =
+            monitor.position().to_logical(scale_factor);
+
+        let size: winit::dpi::LogicalSize<f32> =
+            monitor.size().to_logical(scale_factor);
+
+        Rectangle::new(
+            Point::new(position.x, position.y),
+            Size::new(size.width, size.height),
+        )
+    });
+
+    if window::Snapshot::is_visible(position, settings.size, monitors) {
+        settings
+    } else {
+        window::Settings {
+            position: window::Position::Centered,
+            ..settings
+        }
+    }
+}
+
 /// Converts a [`window::Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -422,6 +466,10 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
 
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
+/// [`mouse::Interaction::Hidden`] and [`mouse::Interaction::Custom`] are
+/// converted to the default cursor icon, since they cannot be represented
+/// with a cursor icon.
+///
 /// [`winit`]: https://github.com/rust-windowing/winit
 pub fn mouse_interaction(
     interaction: mouse::Interaction,
@@ -429,9 +477,10 @@ pub fn mouse_interaction(
     use mouse::Interaction;
 
     match interaction {
-        Interaction::None | Interaction::Idle => {
-            winit::window::CursorIcon::Default
-        }
+        Interaction::None
+        | Interaction::Idle
+        | Interaction::Hidden
+        | Interaction::Custom(_) => winit::window::CursorIcon::Default,
         Interaction::Pointer => winit::window::CursorIcon::Pointer,
         Interaction::Working => winit::window::CursorIcon::Progress,
         Interaction::Grab => winit::window::CursorIcon::Grab,
@@ -458,6 +507,55 @@ pub fn mouse_interaction(
     }
 }
 
+/// Converts a [`mouse::CustomCursor`] to a [`winit`] custom cursor source.
+///
+/// Returns `None` if the image of the cursor is not made of RGBA pixels or
+/// if it is invalid.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn custom_cursor(
+    cursor: &mouse::CustomCursor,
+) -> Option<winit::window::CustomCursorSource> {
+    let image::Handle::Rgba {
+        width,
+        height,
+        pixels,
+        ..
+    } = &cursor.image
+    else {
+        log::warn!(
+            "Custom cursors only support RGBA images; found {:?}",
+            cursor.image
+        );
+
+        return None;
+    };
+
+    let width = u16::try_from(*width).ok()?;
+    let height = u16::try_from(*height).ok()?;
+
+    winit::window::CustomCursor::from_rgba(
+        pixels.to_vec(),
+        width,
+        height,
+        cursor.hotspot.x,
+        cursor.hotspot.y,
+    )
+    .map_err(|error| log::warn!("Invalid custom cursor: {error}"))
+    .ok()
+}
+
+/// Converts a [`window::CursorGrab`] to a [`winit`] cursor grab mode.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn cursor_grab(grab: window::CursorGrab) -> winit::window::CursorGrabMode {
+    match grab {
+        window::CursorGrab::None => winit::window::CursorGrabMode::None,
+        window::CursorGrab::Confined => winit::window::CursorGrabMode::Confined,
+        window::CursorGrab::Locked => winit::window::CursorGrabMode::Locked,
+    }
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
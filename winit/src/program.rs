@@ -11,7 +11,7 @@ use crate::core::renderer;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Color, Element, Point, Size, Theme};
+use crate::core::{Color, Element, Point, Size, Theme, Vector};
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::futures::task;
@@ -32,6 +32,9 @@ use std::borrow::Cow;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
+/// The maximum amount of custom cursors kept around to be reused.
+const MAX_CUSTOM_CURSORS: usize = 16;
+
 /// An interactive, native, cross-platform, multi-windowed application.
 ///
 /// This trait is the main entrypoint of multi-window Iced. Once implemented, you can run
@@ -193,6 +196,18 @@ where
 
     let (proxy, worker) = Proxy::new(event_loop.create_proxy());
 
+    graphics::image::set_waker({
+        let proxy = std::sync::Mutex::new(event_loop.create_proxy());
+
+        move || {
+            // The event loop may be gone already
+            let _ = proxy
+                .lock()
+                .expect("Lock event loop proxy")
+                .send_event(Action::Window(runtime::window::Action::RedrawAll));
+        }
+    });
+
     let mut runtime = {
         let executor =
             P::Executor::new().map_err(Error::ExecutorCreationFailed)?;
@@ -247,6 +262,11 @@ where
         sender: mpsc::UnboundedSender<Event<Action<Message>>>,
         receiver: mpsc::UnboundedReceiver<Control>,
         error: Option<Error>,
+        custom_cursors:
+            FxHashMap<mouse::CustomCursor, (winit::window::CustomCursor, u64)>,
+        custom_cursor_uses: u64,
+        #[cfg(feature = "persistence")]
+        storage: Option<runtime::persistence::Storage>,
 
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc<std::cell::RefCell<bool>>,
@@ -272,6 +292,10 @@ where
         sender: event_sender,
         receiver: control_receiver,
         error: None,
+        custom_cursors: FxHashMap::default(),
+        custom_cursor_uses: 0,
+        #[cfg(feature = "persistence")]
+        storage: settings.storage,
 
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc::new(std::cell::RefCell::new(false)),
@@ -326,7 +350,9 @@ where
                     C::new(graphics_settings, window.clone()).await?;
 
                 for font in fonts {
-                    compositor.load_font(font);
+                    if let Err(error) = compositor.load_font(font) {
+                        log::warn!("Failed to load font: {error}");
+                    }
                 }
 
                 sender
@@ -418,6 +444,26 @@ where
             }
         }
 
+        fn device_event(
+            &mut self,
+            event_loop: &winit::event_loop::ActiveEventLoop,
+            device_id: winit::event::DeviceId,
+            event: winit::event::DeviceEvent,
+        ) {
+            // Only raw mouse motion is needed, for locked cursors
+            if !matches!(event, winit::event::DeviceEvent::MouseMotion { .. }) {
+                return;
+            }
+
+            self.process_event(
+                event_loop,
+                Event::EventLoopAwakened(winit::event::Event::DeviceEvent {
+                    device_id,
+                    event,
+                }),
+            );
+        }
+
         fn user_event(
             &mut self,
             event_loop: &winit::event_loop::ActiveEventLoop,
@@ -519,6 +565,30 @@ where
                                 let target =
                                     settings.platform_specific.target.clone();
 
+                                #[cfg(feature = "persistence")]
+                                let (settings, persistence) = restore_snapshot(
+                                    settings,
+                                    self.storage.as_ref(),
+                                );
+
+                                let settings = conversion::restore_position(
+                                    settings,
+                                    event_loop.available_monitors(),
+                                );
+
+                                let restored = window_manager::Restored {
+                                    position: match settings.position {
+                                        window::Position::Specific(
+                                            position,
+                                        )
+                                        | window::Position::Restored(
+                                            position,
+                                        ) => Some(position),
+                                        _ => None,
+                                    },
+                                    size: settings.size,
+                                };
+
                                 let window_attributes =
                                     conversion::window_attributes(
                                         settings,
@@ -596,9 +666,71 @@ where
                                         exit_on_close_request,
                                         make_visible: visible,
                                         on_open,
+                                        restored,
+                                        #[cfg(feature = "persistence")]
+                                        persistence,
                                     },
                                 );
                             }
+                            Control::SetCustomCursor { window, cursor } => {
+                                self.custom_cursor_uses += 1;
+
+                                let custom_cursor = match self
+                                    .custom_cursors
+                                    .get_mut(&cursor)
+                                {
+                                    Some((custom_cursor, last_use)) => {
+                                        *last_use = self.custom_cursor_uses;
+
+                                        Some(custom_cursor.clone())
+                                    }
+                                    None => conversion::custom_cursor(&cursor)
+                                        .map(|source| {
+                                            let custom_cursor = event_loop
+                                                .create_custom_cursor(source);
+
+                                            let _ = self.custom_cursors.insert(
+                                                cursor,
+                                                (
+                                                    custom_cursor.clone(),
+                                                    self.custom_cursor_uses,
+                                                ),
+                                            );
+
+                                            custom_cursor
+                                        }),
+                                };
+
+                                // Windows keep the cursors they are using,
+                                // so evicting them from the cache is safe
+                                if self.custom_cursors.len()
+                                    > MAX_CUSTOM_CURSORS
+                                {
+                                    let least_recently_used = self
+                                        .custom_cursors
+                                        .iter()
+                                        .min_by_key(|(_, (_, last_use))| {
+                                            *last_use
+                                        })
+                                        .map(|(cursor, _)| cursor.clone());
+
+                                    if let Some(cursor) = least_recently_used {
+                                        let _ =
+                                            self.custom_cursors.remove(&cursor);
+                                    }
+                                }
+
+                                match custom_cursor {
+                                    Some(custom_cursor) => {
+                                        window.set_cursor(custom_cursor);
+                                    }
+                                    None => {
+                                        window.set_cursor(
+                                            winit::window::CursorIcon::Default,
+                                        );
+                                    }
+                                }
+                            }
                             Control::Exit => {
                                 event_loop.exit();
                             }
@@ -645,6 +777,9 @@ enum Event<Message: 'static> {
         exit_on_close_request: bool,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
+        restored: window_manager::Restored,
+        #[cfg(feature = "persistence")]
+        persistence: Option<(runtime::persistence::Storage, String)>,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -660,6 +795,10 @@ enum Control {
         monitor: Option<winit::monitor::MonitorHandle>,
         on_open: oneshot::Sender<window::Id>,
     },
+    SetCustomCursor {
+        window: Arc<winit::window::Window>,
+        cursor: mouse::CustomCursor,
+    },
 }
 
 async fn run_instance<P, C>(
@@ -713,6 +852,9 @@ async fn run_instance<P, C>(
                 exit_on_close_request,
                 make_visible,
                 on_open,
+                restored,
+                #[cfg(feature = "persistence")]
+                persistence,
             } => {
                 let window = window_manager.insert(
                     id,
@@ -720,8 +862,14 @@ async fn run_instance<P, C>(
                     &program,
                     &mut compositor,
                     exit_on_close_request,
+                    restored,
                 );
 
+                #[cfg(feature = "persistence")]
+                {
+                    window.persistence = persistence;
+                }
+
                 let logical_size = window.state.logical_size();
 
                 let _ = user_interfaces.insert(
@@ -841,15 +989,11 @@ async fn run_instance<P, C>(
                         );
                         debug.draw_finished();
 
-                        if new_mouse_interaction != window.mouse_interaction {
-                            window.raw.set_cursor(
-                                conversion::mouse_interaction(
-                                    new_mouse_interaction,
-                                ),
-                            );
-
-                            window.mouse_interaction = new_mouse_interaction;
-                        }
+                        update_mouse_interaction(
+                            window,
+                            new_mouse_interaction,
+                            &mut control_sender,
+                        );
 
                         runtime.broadcast(subscription::Event::Interaction {
                             window: id,
@@ -912,17 +1056,11 @@ async fn run_instance<P, C>(
                                 );
                             debug.draw_finished();
 
-                            if new_mouse_interaction != window.mouse_interaction
-                            {
-                                window.raw.set_cursor(
-                                    conversion::mouse_interaction(
-                                        new_mouse_interaction,
-                                    ),
-                                );
-
-                                window.mouse_interaction =
-                                    new_mouse_interaction;
-                            }
+                            update_mouse_interaction(
+                                window,
+                                new_mouse_interaction,
+                                &mut control_sender,
+                            );
 
                             compositor.configure_surface(
                                 &mut window.surface,
@@ -1021,6 +1159,14 @@ async fn run_instance<P, C>(
                                 &mut debug,
                             );
 
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::Resized(_)
+                                    | winit::event::WindowEvent::Moved(_)
+                            ) {
+                                window.update_restored();
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
@@ -1030,6 +1176,28 @@ async fn run_instance<P, C>(
                             }
                         }
                     }
+                    event::Event::DeviceEvent {
+                        event: event::DeviceEvent::MouseMotion { delta },
+                        ..
+                    } => {
+                        let locked_window =
+                            window_manager.iter_mut().find(|(_, window)| {
+                                window.cursor_grab == window::CursorGrab::Locked
+                                    && window.raw.has_focus()
+                            });
+
+                        if let Some((id, _)) = locked_window {
+                            events.push((
+                                id,
+                                core::Event::Mouse(mouse::Event::RawMotion {
+                                    delta: Vector::new(
+                                        delta.0 as f32,
+                                        delta.1 as f32,
+                                    ),
+                                }),
+                            ));
+                        }
+                    }
                     event::Event::AboutToWait => {
                         if events.is_empty() && messages.is_empty() {
                             continue;
@@ -1218,6 +1386,8 @@ fn run_action<P, C>(
     P::Theme: DefaultStyle,
 {
     use crate::runtime::clipboard;
+    use crate::runtime::font;
+    use crate::runtime::image;
     use crate::runtime::system;
     use crate::runtime::window;
 
@@ -1254,6 +1424,9 @@ fn run_action<P, C>(
                 let _ = interfaces.remove(&id);
 
                 if let Some(window) = window_manager.remove(id) {
+                    #[cfg(feature = "persistence")]
+                    window.persist();
+
                     if clipboard.window_id() == Some(window.raw.id()) {
                         *clipboard = window_manager
                             .first()
@@ -1383,6 +1556,11 @@ fn run_action<P, C>(
                     let _ = channel.send(mode);
                 }
             }
+            window::Action::GetSnapshot(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let _ = channel.send(window.snapshot());
+                }
+            }
             window::Action::ToggleMaximize(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_maximized(!window.raw.is_maximized());
@@ -1468,6 +1646,29 @@ fn run_action<P, C>(
                     let _ = window.raw.set_cursor_hittest(true);
                 }
             }
+            window::Action::ChangeCursorGrab(id, grab) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let result = window
+                        .raw
+                        .set_cursor_grab(conversion::cursor_grab(grab));
+
+                    // Not every platform supports locking the cursor
+                    if result.is_err()
+                        && grab == core::window::CursorGrab::Locked
+                    {
+                        let _ = window.raw.set_cursor_grab(
+                            winit::window::CursorGrabMode::Confined,
+                        );
+                    }
+
+                    window.cursor_grab = grab;
+                }
+            }
+            window::Action::RedrawAll => {
+                for (_id, window) in window_manager.iter_mut() {
+                    window.raw.request_redraw();
+                }
+            }
         },
         Action::System(action) => match action {
             system::Action::QueryInformation(_channel) => {
@@ -1484,6 +1685,23 @@ fn run_action<P, C>(
                 }
             }
         },
+        Action::Image(action) => match action {
+            image::Action::Evict(handle) => {
+                for (_id, window) in window_manager.iter_mut() {
+                    compositor.evict_image(&mut window.renderer, &handle);
+                }
+            }
+            image::Action::FetchMetrics(channel) => {
+                let metrics = window_manager.iter_mut().fold(
+                    core::image::Metrics::default(),
+                    |metrics, (_id, window)| {
+                        metrics + compositor.image_metrics(&window.renderer)
+                    },
+                );
+
+                let _ = channel.send(metrics);
+            }
+        },
         Action::Widget(operation) => {
             let mut current_operation = Some(operation);
 
@@ -1503,13 +1721,35 @@ fn run_action<P, C>(
                 }
             }
         }
-        Action::LoadFont { bytes, channel } => {
-            // TODO: Error handling (?)
-            compositor.load_font(bytes.clone());
-
-            let _ = channel.send(Ok(()));
-        }
+        Action::Font(action) => match action {
+            font::Action::Load { bytes, channel } => {
+                let _ = channel.send(compositor.load_font(bytes));
+            }
+            font::Action::ListFamilies(channel) => {
+                let _ = channel.send(compositor.font_families());
+            }
+            font::Action::IsAvailable(font, channel) => {
+                let _ = channel.send(compositor.is_font_available(font));
+            }
+            font::Action::SetFamily { generic, name } => {
+                compositor.set_font_family(generic, name);
+                relayout_user_interfaces(interfaces, window_manager);
+            }
+            font::Action::SetFallback { script, families } => {
+                compositor.set_font_fallback(script, families);
+                relayout_user_interfaces(interfaces, window_manager);
+            }
+            font::Action::UseSystemFonts(enabled) => {
+                compositor.use_system_fonts(enabled);
+                relayout_user_interfaces(interfaces, window_manager);
+            }
+        },
         Action::Exit => {
+            #[cfg(feature = "persistence")]
+            for (_id, window) in window_manager.iter() {
+                window.persist();
+            }
+
             control_sender
                 .start_send(Control::Exit)
                 .expect("Send control action");
@@ -1517,6 +1757,92 @@ fn run_action<P, C>(
     }
 }
 
+/// Applies the persisted [`window::Snapshot`] of a window to its settings,
+/// if the window has a persistence key and there is some storage.
+#[cfg(feature = "persistence")]
+fn restore_snapshot(
+    settings: window::Settings,
+    storage: Option<&runtime::persistence::Storage>,
+) -> (
+    window::Settings,
+    Option<(runtime::persistence::Storage, String)>,
+) {
+    let Some((storage, key)) = storage.zip(settings.persistence_key.clone())
+    else {
+        return (settings, None);
+    };
+
+    let settings = match storage.load::<window::Snapshot>(&key) {
+        Ok(Some(snapshot)) => snapshot.apply(settings),
+        Ok(None) => settings,
+        Err(error) => {
+            log::warn!("Failed to restore window `{key}`: {error}");
+            settings
+        }
+    };
+
+    (settings, Some((storage.clone(), key)))
+}
+
+/// Lays out the user interface of every window again and redraws it, after
+/// the fonts it uses changed.
+fn relayout_user_interfaces<P, C>(
+    interfaces: &mut FxHashMap<
+        window::Id,
+        UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    >,
+    window_manager: &mut WindowManager<P, C>,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: DefaultStyle,
+{
+    for (id, window) in window_manager.iter_mut() {
+        if let Some(ui) = interfaces.remove(&id) {
+            let _ = interfaces.insert(
+                id,
+                ui.relayout(window.state.logical_size(), &mut window.renderer),
+            );
+        }
+
+        window.raw.request_redraw();
+    }
+}
+
+/// Updates the mouse cursor of a window, if its [`mouse::Interaction`] changed.
+fn update_mouse_interaction<P, C>(
+    window: &mut window_manager::Window<P, C>,
+    interaction: mouse::Interaction,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: DefaultStyle,
+{
+    if interaction == window.mouse_interaction {
+        return;
+    }
+
+    let is_hidden = interaction == mouse::Interaction::Hidden;
+
+    if is_hidden != (window.mouse_interaction == mouse::Interaction::Hidden) {
+        window.raw.set_cursor_visible(!is_hidden);
+    }
+
+    if let mouse::Interaction::Custom(cursor) = &interaction {
+        let _ = control_sender.start_send(Control::SetCustomCursor {
+            window: window.raw.clone(),
+            cursor: cursor.clone(),
+        });
+    } else {
+        window
+            .raw
+            .set_cursor(conversion::mouse_interaction(interaction.clone()));
+    }
+
+    window.mouse_interaction = interaction;
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, P: Program, C>(
     program: &'a P,
@@ -1,5 +1,6 @@
+use crate::conversion;
 use crate::core::mouse;
-use crate::core::window::Id;
+use crate::core::window::{self, CursorGrab, Id};
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
@@ -39,6 +40,7 @@ where
         application: &P,
         compositor: &mut C,
         exit_on_close_request: bool,
+        restored: Restored,
     ) -> &mut Window<P, C> {
         let state = State::new(application, id, &window);
         let viewport_version = state.viewport_version();
@@ -62,12 +64,20 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                cursor_grab: CursorGrab::None,
+                restored,
+                #[cfg(feature = "persistence")]
+                persistence: None,
             },
         );
 
-        self.entries
+        let window = self
+            .entries
             .get_mut(&id)
-            .expect("Get window that was just inserted")
+            .expect("Get window that was just inserted");
+
+        window.update_restored();
+        window
     }
 
     pub fn is_empty(&self) -> bool {
@@ -78,6 +88,10 @@ where
         self.entries.first_key_value().map(|(_id, window)| window)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &Window<P, C>)> {
+        self.entries.iter().map(|(k, v)| (*k, v))
+    }
+
     pub fn iter_mut(
         &mut self,
     ) -> impl Iterator<Item = (Id, &mut Window<P, C>)> {
@@ -136,8 +150,12 @@ where
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
+    pub cursor_grab: CursorGrab,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
+    pub restored: Restored,
+    #[cfg(feature = "persistence")]
+    pub persistence: Option<(crate::runtime::persistence::Storage, String)>,
 }
 
 impl<P, C> Window<P, C>
@@ -162,4 +180,77 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    /// Records the current geometry of the window as its [`Restored`]
+    /// geometry, unless it is maximized or in fullscreen.
+    pub fn update_restored(&mut self) {
+        if !self.is_restored() {
+            return;
+        }
+
+        self.restored = Restored {
+            position: self.outer_position(),
+            size: self.size(),
+        };
+    }
+
+    /// Takes a [`window::Snapshot`] of the window.
+    ///
+    /// The geometry of a maximized or fullscreen window is its [`Restored`]
+    /// geometry; so it can be restored unmaximized later on.
+    pub fn snapshot(&self) -> window::Snapshot {
+        let Restored { position, size } = if self.is_restored() {
+            Restored {
+                position: self.outer_position(),
+                size: self.size(),
+            }
+        } else {
+            self.restored
+        };
+
+        window::Snapshot {
+            position,
+            size,
+            maximized: self.raw.is_maximized(),
+            mode: conversion::mode(self.raw.fullscreen()),
+        }
+    }
+
+    /// Saves a [`window::Snapshot`] of the window in its persistence
+    /// storage, if any.
+    #[cfg(feature = "persistence")]
+    pub fn persist(&self) {
+        let Some((storage, key)) = &self.persistence else {
+            return;
+        };
+
+        if let Err(error) = storage.save(key, &self.snapshot()) {
+            log::warn!("Failed to persist window `{key}`: {error}");
+        }
+    }
+
+    fn is_restored(&self) -> bool {
+        !self.raw.is_maximized() && self.raw.fullscreen().is_none()
+    }
+
+    fn outer_position(&self) -> Option<Point> {
+        self.raw
+            .outer_position()
+            .ok()
+            .map(|position| position.to_logical(self.raw.scale_factor()))
+            .map(|position| Point {
+                x: position.x,
+                y: position.y,
+            })
+    }
+}
+
+/// The geometry of a window while it is neither maximized nor in
+/// fullscreen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Restored {
+    /// The logical position of the window, if known.
+    pub position: Option<Point>,
+    /// The logical size of the window.
+    pub size: Size,
 }
@@ -12,4 +12,13 @@ pub struct Settings {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// The [`Storage`] used to persist the [`Snapshot`] of any window with a
+    /// [`persistence_key`].
+    ///
+    /// [`Storage`]: crate::runtime::persistence::Storage
+    /// [`Snapshot`]: crate::core::window::Snapshot
+    /// [`persistence_key`]: crate::core::window::Settings::persistence_key
+    #[cfg(feature = "persistence")]
+    pub storage: Option<crate::runtime::persistence::Storage>,
 }